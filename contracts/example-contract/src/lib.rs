@@ -2,12 +2,17 @@
 //!
 //! `deploy` exercises the wallet authorizing a `CreateContract*` context;
 //! `call` exercises a root invocation fanning out into multiple `transfer`
-//! sub-invocations under a single wallet authorization.
+//! sub-invocations under a single wallet authorization. `WebAuthContract`
+//! stands in for a SEP-45 web-auth contract.
 
 #![no_std]
 
 use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env};
 
+mod web_auth;
+
+pub use web_auth::{WebAuthContract, WebAuthContractClient};
+
 #[contract]
 pub struct Contract;
 
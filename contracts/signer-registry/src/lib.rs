@@ -0,0 +1,203 @@
+//! Signer registry: an on-chain reverse lookup from signer key to the
+//! wallets holding it.
+//!
+//! Deterministic derivation only finds a wallet from the passkey it was
+//! deployed with; every later signer otherwise needs an indexer. Wallets that
+//! opt in (`set_signer_registry`) report each added and removed signer here,
+//! and `lookup` answers "which wallets is this key a signer of?".
+//!
+//! - **Wallet-authenticated writes.** `register`/`unregister` require the
+//!   wallet's auth, satisfied by invoker auth when the wallet calls them from
+//!   its own signer management.
+//! - **Permissionless sync.** `sync` reconciles one key against the wallet's
+//!   own `get_signer`, for signers the wallet held before opting in and for
+//!   entries left stale because a report failed or the wallet opted out.
+//! - **One entry per (key, wallet).** Anyone can deploy wallets naming a
+//!   public key, so a key's wallets are not one shared list that could be
+//!   padded until it is full or too large to write: each wallet is its own
+//!   entry, indexed by position, so registering costs the same however many
+//!   wallets claim the key, and no number of squatters can keep a genuine
+//!   wallet out. `lookup` pages through them (`count` gives the total).
+//!
+//! Results are claims: confirm with the wallet's `get_signer` before trusting
+//! one.
+
+#![no_std]
+
+use smart_wallet_interface::{types::SignerKey, SignerRegistryInterface, SmartWalletClient};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec};
+
+/// Most wallets one `lookup` returns.
+pub const MAX_PAGE: u32 = 50;
+
+/// TTL renewal parameters (in ledgers at the historical 5s close time): bump
+/// to ~30 days whenever remaining TTL drops below ~1 week. Both are well under
+/// any real network's `max_ttl`.
+const RENEW_THRESHOLD: u32 = 60 * 60 * 24 / 5 * 7;
+const RENEW_TO: u32 = 60 * 60 * 24 / 5 * 30;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StorageKey {
+    /// How many wallets are registered under the key.
+    Count(SignerKey),
+    /// The wallet at a position in `[0, Count)`.
+    Wallet(SignerKey, u32),
+    /// A registered wallet's position.
+    Position(SignerKey, Address),
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl SignerRegistryInterface for Contract {
+    fn register(env: Env, wallet: Address, signer_key: SignerKey) {
+        wallet.require_auth();
+
+        add_wallet(&env, &wallet, &signer_key);
+    }
+
+    fn unregister(env: Env, wallet: Address, signer_key: SignerKey) {
+        wallet.require_auth();
+
+        remove_wallet(&env, &wallet, &signer_key);
+    }
+
+    fn lookup(env: Env, signer_key: SignerKey, start: u32, limit: u32) -> Vec<Address> {
+        let end = count(&env, &signer_key).min(start.saturating_add(limit.min(MAX_PAGE)));
+        let mut wallets = Vec::new(&env);
+
+        for position in start..end {
+            if let Some(wallet) = env
+                .storage()
+                .persistent()
+                .get::<StorageKey, Address>(&StorageKey::Wallet(signer_key.clone(), position))
+            {
+                wallets.push_back(wallet);
+            }
+        }
+
+        wallets
+    }
+
+    fn count(env: Env, signer_key: SignerKey) -> u32 {
+        count(&env, &signer_key)
+    }
+}
+
+#[contractimpl]
+impl Contract {
+    /// Permissionless: record `wallet` under `signer_key` if it holds that
+    /// signer and reports to this registry, drop it otherwise. The wallet's
+    /// views are read-only, so a caller can only make the entry truthful.
+    pub fn sync(env: Env, wallet: Address, signer_key: SignerKey) {
+        let client = SmartWalletClient::new(&env, &wallet);
+        let reports_here = client.get_signer_registry() == Some(env.current_contract_address());
+
+        if reports_here && client.get_signer(&signer_key).is_some() {
+            add_wallet(&env, &wallet, &signer_key);
+        } else {
+            remove_wallet(&env, &wallet, &signer_key);
+        }
+    }
+}
+
+fn count(env: &Env, signer_key: &SignerKey) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<StorageKey, u32>(&StorageKey::Count(signer_key.clone()))
+        .unwrap_or(0)
+}
+
+fn add_wallet(env: &Env, wallet: &Address, signer_key: &SignerKey) {
+    let position_key = StorageKey::Position(signer_key.clone(), wallet.clone());
+
+    if let Some(position) = env
+        .storage()
+        .persistent()
+        .get::<StorageKey, u32>(&position_key)
+    {
+        renew(env, &position_key);
+        renew(env, &StorageKey::Wallet(signer_key.clone(), position));
+        renew(env, &StorageKey::Count(signer_key.clone()));
+        return;
+    }
+
+    let position = count(env, signer_key);
+    let wallet_key = StorageKey::Wallet(signer_key.clone(), position);
+    let count_key = StorageKey::Count(signer_key.clone());
+
+    env.storage()
+        .persistent()
+        .set::<StorageKey, Address>(&wallet_key, wallet);
+    env.storage()
+        .persistent()
+        .set::<StorageKey, u32>(&position_key, &position);
+    env.storage()
+        .persistent()
+        .set::<StorageKey, u32>(&count_key, &(position + 1));
+
+    renew(env, &wallet_key);
+    renew(env, &position_key);
+    renew(env, &count_key);
+}
+
+/// Drop `wallet`, moving the last wallet into its position so positions stay
+/// dense.
+fn remove_wallet(env: &Env, wallet: &Address, signer_key: &SignerKey) {
+    let position_key = StorageKey::Position(signer_key.clone(), wallet.clone());
+    let Some(position) = env
+        .storage()
+        .persistent()
+        .get::<StorageKey, u32>(&position_key)
+    else {
+        return;
+    };
+
+    let last = count(env, signer_key) - 1;
+    let count_key = StorageKey::Count(signer_key.clone());
+    let last_key = StorageKey::Wallet(signer_key.clone(), last);
+
+    if position != last {
+        let moved = env
+            .storage()
+            .persistent()
+            .get::<StorageKey, Address>(&last_key)
+            .unwrap();
+        let moved_key = StorageKey::Wallet(signer_key.clone(), position);
+        let moved_position_key = StorageKey::Position(signer_key.clone(), moved.clone());
+
+        env.storage()
+            .persistent()
+            .set::<StorageKey, Address>(&moved_key, &moved);
+        env.storage()
+            .persistent()
+            .set::<StorageKey, u32>(&moved_position_key, &position);
+        renew(env, &moved_key);
+        renew(env, &moved_position_key);
+    }
+
+    env.storage().persistent().remove::<StorageKey>(&last_key);
+    env.storage()
+        .persistent()
+        .remove::<StorageKey>(&position_key);
+
+    if last == 0 {
+        env.storage().persistent().remove::<StorageKey>(&count_key);
+    } else {
+        env.storage()
+            .persistent()
+            .set::<StorageKey, u32>(&count_key, &last);
+        renew(env, &count_key);
+    }
+}
+
+fn renew(env: &Env, key: &StorageKey) {
+    env.storage()
+        .instance()
+        .extend_ttl(RENEW_THRESHOLD, RENEW_TO);
+    env.storage()
+        .persistent()
+        .extend_ttl::<StorageKey>(key, RENEW_THRESHOLD, RENEW_TO);
+}
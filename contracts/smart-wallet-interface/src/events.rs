@@ -4,18 +4,32 @@
 //! event is a `#[contractevent]` struct, so its full schema is embedded in the
 //! contract spec (SEP-48) and consumed by indexers from the wasm itself. The
 //! first topic is the snake_case struct name (`signer_added`, `signer_updated`,
-//! `signer_removed`, `upgraded`), which is the version marker for this scheme.
+//! `signer_removed`, `signer_suspended`, `signer_resumed`, `sessions_revoked`,
+//! `inheritance_updated`, `wallet_frozen`, `wallet_unfrozen`,
+//! `counters_reconciled`, `upgraded`, `upgrade_confirmed`,
+//! `upgrade_rolled_back`, `schema_migrated`, `intent_executed`,
+//! `intent_cancelled`, `fee_paid`, `subaccount_created`,
+//! `signer_registry_failed`), which is the
+//! version marker for this scheme.
 //!
 //! Events are a complete mirror of signer-storage transitions: the full new
 //! state is always emitted, plus the old storage class on updates so indexers
 //! can detect durability moves (an update that flips durability tombstones the
 //! entry in one durability while a live twin appears in the other).
+//!
+//! Schema evolution is additive: data fields are a map keyed by field name,
+//! so new fields are appended under new names and existing ones are never
+//! renamed or retyped — a decoder that ignores unknown map keys keeps working.
+//! `SignerAdded`/`SignerUpdated` gained `options` (the signer's
+//! `SignerOptions`, including its `SignerMetadata`) this way; payloads
+//! without it predate `SignerOptions` and mean `SignerOptions::default()`.
 
-use soroban_sdk::{contractevent, BytesN};
+use soroban_sdk::{contractevent, Address, BytesN, Symbol};
 
-use crate::types::{SignerKey, SignerStorage, SignerVal};
+use crate::types::{InheritanceConfig, SignerKey, SignerOptions, SignerStorage, SignerVal};
 
-/// A signer was added (via `__constructor` or `add_signer`).
+/// A signer was added (via `__constructor`, `add_signer` or an
+/// `apply_signer_changes` batch).
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SignerAdded {
@@ -23,9 +37,11 @@ pub struct SignerAdded {
     pub key: SignerKey,
     pub val: SignerVal,
     pub storage: SignerStorage,
+    pub options: SignerOptions,
 }
 
-/// An existing signer was modified via `update_signer`.
+/// An existing signer was modified (via `update_signer` or an
+/// `apply_signer_changes` batch).
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SignerUpdated {
@@ -34,10 +50,11 @@ pub struct SignerUpdated {
     pub val: SignerVal,
     pub storage: SignerStorage,
     pub old_storage: SignerStorage,
+    pub options: SignerOptions,
 }
 
-/// A signer was removed via `remove_signer`. `storage` is the durability the
-/// entry was removed from.
+/// A signer was removed (via `remove_signer` or an `apply_signer_changes`
+/// batch). `storage` is the durability the entry was removed from.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SignerRemoved {
@@ -46,13 +63,140 @@ pub struct SignerRemoved {
     pub storage: SignerStorage,
 }
 
-/// The contract's wasm was replaced via `upgrade`. `old_hash` is `None` on a
-/// wallet's first-ever upgrade: the host exposes no way for a contract to
-/// read its own executable hash, so the wallet caches the hash in instance
-/// storage at each upgrade and the genesis hash is unknowable in-contract.
+/// A stored signer was suspended via `suspend_signer`: it stays stored with
+/// its configuration intact but cannot sign until resumed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerSuspended {
+    #[topic]
+    pub key: SignerKey,
+}
+
+/// A suspended signer was reinstated via `resume_signer`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerResumed {
+    #[topic]
+    pub key: SignerKey,
+}
+
+/// Every session signer added before this call was revoked via
+/// `revoke_sessions`. `generation` is the wallet's new session generation.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionsRevoked {
+    pub generation: u32,
+}
+
+/// The wallet's inheritance configuration was set, replaced or (`None`)
+/// cleared via `set_inheritance`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InheritanceUpdated {
+    pub config: Option<InheritanceConfig>,
+}
+
+/// The wallet was frozen via `freeze`: until `unfreeze`, only unfreezing and
+/// signer management can be authorized, and only by the freeze quorum.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WalletFrozen {}
+
+/// The wallet was unfrozen via `unfreeze`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WalletUnfrozen {}
+
+/// The wallet's admin/durable signer counters were reconciled via
+/// `reconcile_counters`. Carries the resulting counts.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CountersReconciled {
+    pub admin_count: u32,
+    pub durable_count: u32,
+}
+
+/// The contract's wasm was replaced via `upgrade`. The wallet caches its
+/// hash in instance storage at construction and at each upgrade; `old_hash`
+/// is `None` only on the first upgrade of a wallet deployed by older code,
+/// which never recorded its genesis hash.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Upgraded {
     pub old_hash: Option<BytesN<32>>,
     pub new_hash: BytesN<32>,
 }
+
+/// The pending upgrade to `hash` passed its self-test and was confirmed via
+/// `confirm_upgrade`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeConfirmed {
+    pub hash: BytesN<32>,
+}
+
+/// A pending upgrade was reverted via `rollback_upgrade`: the wallet runs
+/// `to_hash` again instead of `from_hash`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeRolledBack {
+    pub from_hash: BytesN<32>,
+    pub to_hash: BytesN<32>,
+}
+
+/// The wallet's storage was migrated one schema version forward via
+/// `migrate`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+/// A signed intent was executed via `execute_intent`, consuming `nonce`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntentExecuted {
+    pub nonce: u64,
+    pub contract: Address,
+    pub fn_name: Symbol,
+}
+
+/// An intent nonce was consumed unused via `cancel_intent`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntentCancelled {
+    pub nonce: u64,
+}
+
+/// An intent's fee was paid to its recipient by `execute_intent`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeePaid {
+    pub nonce: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+/// A subaccount was deployed by `create_subaccount`. The subaccount itself
+/// announces its signers, the parent included, with `SignerAdded`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubaccountCreated {
+    #[topic]
+    pub subaccount: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// The wallet's signer registry rejected a report of `key`: its
+/// registration (`registered`) or unregistration. The signer change itself
+/// went through; the registry entry is stale until synced.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerRegistryFailed {
+    #[topic]
+    pub key: SignerKey,
+    pub registry: Address,
+    pub registered: bool,
+}
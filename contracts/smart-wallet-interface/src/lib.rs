@@ -1,22 +1,42 @@
 #![no_std]
 
-use soroban_sdk::{auth::Context, contractclient, Address, BytesN, Env, Vec};
-use types::{Error, Signer, SignerKey, SignerVal};
+use soroban_sdk::{auth::Context, contractclient, Address, BytesN, Env, Symbol, Val, Vec};
+use types::{
+    Error, FeeConfig, InheritanceConfig, Intent, LedgerReference, PendingUpgrade, Signatures,
+    Signer, SignerActivity, SignerKey, SignerOp, SignerOptions, SignerVal, SubaccountConfig,
+    TtlConfig, WalletSettings, WebAuthConfig,
+};
 
 pub mod events;
 pub mod types;
 
 #[contractclient(name = "SmartWalletClient")]
 pub trait SmartWalletInterface {
-    /// Initialize the wallet with its first signer. Deploy-time only
+    /// Initialize the wallet with its initial signer set. Deploy-time only
     /// (CAP-0058); there is no other initialization path and no
     /// un-authenticated `add_signer` window.
     ///
-    /// The first signer MUST be durable — stored `Persistent` with
-    /// `SignerExpiration(None)` (any limits) — or the constructor fails with
-    /// `Error::LastSigner`: a wallet born with only a Temporary or expiring
-    /// signer could reach zero live signers with no contract call to stop it.
-    fn __constructor(env: Env, signer: Signer);
+    /// Each signer is added exactly as by `add_signer`, in order: validated,
+    /// stored, announced with `SignerAdded`, and — for policy signers — its
+    /// `install` hook invoked. Duplicate keys fail with
+    /// `Error::SignerAlreadyExists`. Adding recovery keys and policies here
+    /// saves the separately authorized follow-up transactions a single-signer
+    /// deploy needs.
+    ///
+    /// The set is validated as a whole against the durable-signer
+    /// requirement: it MUST contain at least one durable signer — stored
+    /// `Persistent` with `SignerExpiration(None)` (any limits) — or the
+    /// constructor fails with `Error::LastSigner` (as does an empty set): a
+    /// wallet born with only Temporary or expiring signers could reach zero
+    /// live signers with no contract call to stop it. Non-durable signers
+    /// are fine alongside a durable one.
+    ///
+    /// `settings` configures the wallet in the same call (see
+    /// `WalletSettings`), each setting validated and announced as by its
+    /// setter. A signer registry is set before the signers are added, so the
+    /// initial set is reported to it; every other setting is applied after,
+    /// so the inheritance beneficiary can be one of `signers`.
+    fn __constructor(env: Env, signers: Vec<Signer>, settings: Option<WalletSettings>);
     /// Add a new signer. Requires wallet auth. Fails if the signer key
     /// already exists. Policy signers get their `install` hook invoked.
     fn add_signer(env: Env, signer: Signer) -> Result<(), Error>;
@@ -49,10 +69,105 @@ pub trait SmartWalletInterface {
     /// one (`__constructor` requires it) and every durable one-to-zero
     /// transition is rejected, making a zero-live-signer wallet unreachable.
     /// Rotation is unaffected: add the durable replacement first, then
-    /// remove the old signer.
+    /// remove the old signer (or do both in one `apply_signer_changes`).
     fn remove_signer(env: Env, signer_key: SignerKey) -> Result<(), Error>;
+    /// Apply a batch of signer changes atomically under ONE wallet auth.
+    /// Requires wallet auth. Each `SignerOp` behaves exactly like its
+    /// single-signer entrypoint (same errors, same events, same policy
+    /// `install` hook on adds) and sees the state left by the ops before it;
+    /// any failing op rolls back the whole batch.
+    ///
+    /// The `Error::LastAdminSigner`/`Error::LastSigner` guards are checked
+    /// against the batch's FINAL state rather than after every step, so an
+    /// intermediate state with no durable admin is fine as long as the batch
+    /// ends with one — e.g. `[Remove(old admin), Add(new admin)]` rotates a
+    /// wallet's only admin in a single call. An empty batch is a no-op.
+    ///
+    /// Authorization is the wallet-self `apply_signer_changes` context: the
+    /// self-removal exception of `remove_signer` does NOT extend to a batch,
+    /// even one whose only op removes the authorizing signer.
+    fn apply_signer_changes(env: Env, ops: Vec<SignerOp>) -> Result<(), Error>;
+    /// Suspend a stored signer without removing it. Requires wallet auth.
+    /// Fails with `Error::SignerNotFound` if the key is not stored; suspending
+    /// an already-suspended signer is a no-op (no event).
+    ///
+    /// A suspended signer keeps its value, options and storage (a policy
+    /// signer stays installed) but cannot sign: it is never a pass-1
+    /// candidate, fails the whole authorization with
+    /// `Error::SignerSuspended` if it appears in a signatures map, and fails
+    /// any limits entry that requires it as a co-signer policy. It is NOT
+    /// counted as a durable admin, so suspending the wallet's last durable
+    /// admin fails with `Error::LastAdminSigner`; it stays counted as a
+    /// durable signer. `update_signer` keeps the suspension; only
+    /// `resume_signer` or removal clears it. Emits `SignerSuspended`.
+    fn suspend_signer(env: Env, signer_key: SignerKey) -> Result<(), Error>;
+    /// Reinstate a suspended signer. Requires wallet auth. Fails with
+    /// `Error::SignerNotFound` if the key is not stored; resuming a signer
+    /// that is not suspended is a no-op (no event). Emits `SignerResumed`.
+    fn resume_signer(env: Env, signer_key: SignerKey) -> Result<(), Error>;
+    /// Revoke every session signer at once. Requires wallet auth (permitted
+    /// while frozen). A session signer is any non-durable one — Temporary,
+    /// or with a `SignerExpiration` — and records the wallet's session
+    /// generation when it is added; this call bumps the generation, after
+    /// which every session signer from an older generation is treated as
+    /// expired (`Error::SignerExpired`). O(1): no signer entry is touched.
+    /// Durable signers are unaffected.
+    ///
+    /// Revocation is permanent for the revoked entries: `update_signer` keeps
+    /// a session's original generation, so re-grant a revoked session by
+    /// removing and re-adding it. Emits `SessionsRevoked`.
+    fn revoke_sessions(env: Env) -> Result<(), Error>;
+    /// Name (`Some`) or clear (`None`) the wallet's inheritance beneficiary
+    /// — see `InheritanceConfig`. Requires wallet auth. The beneficiary must
+    /// be a stored signer (`Error::SignerNotFound`) and the period nonzero
+    /// (`Error::InvalidInactivityPeriod`). Setting a configuration restarts
+    /// the inactivity clock.
+    ///
+    /// The beneficiary stops counting as a durable admin while configured
+    /// (and counts again once cleared or replaced), so naming the wallet's
+    /// last durable admin fails with `Error::LastAdminSigner`. Emits
+    /// `InheritanceUpdated`.
+    fn set_inheritance(env: Env, config: Option<InheritanceConfig>) -> Result<(), Error>;
+    /// Freeze the wallet. Requires wallet auth, which any active signer with
+    /// `SignerOptions::can_freeze` can provide on its own (as can any
+    /// signer covering wallet-self calls). Freezing a frozen wallet is a
+    /// no-op (no event). Emits `WalletFrozen`.
+    ///
+    /// While frozen, `__check_auth` rejects (`Error::WalletFrozen`) every
+    /// authorization that includes a context other than this wallet's own
+    /// `unfreeze`, `add_signer`, `update_signer`, `remove_signer`,
+    /// `apply_signer_changes`, `suspend_signer`, `resume_signer` or
+    /// `revoke_sessions`, and
+    /// requires those to be signed by at least the freeze quorum of active
+    /// durable admin signers (`Error::FreezeQuorumNotMet`) — on top of the
+    /// usual coverage and signature checks. A wallet with fewer durable
+    /// admins than its quorum needs all of them (at least one), and only
+    /// once `FREEZE_TIMELOCK` seconds have passed since the freeze
+    /// (`Error::FreezeTimelockActive`): a single stolen admin key cannot
+    /// undo a freeze at once. `rollback_upgrade` is refused while frozen,
+    /// even for the upgrade guardian.
+    fn freeze(env: Env) -> Result<(), Error>;
+    /// Unfreeze the wallet. Requires wallet auth (while frozen: the freeze
+    /// quorum). Unfreezing a wallet that is not frozen is a no-op (no
+    /// event). Emits `WalletUnfrozen`.
+    fn unfreeze(env: Env) -> Result<(), Error>;
+    /// Set how many active durable admin signers must sign while the wallet
+    /// is frozen. Requires wallet auth; cannot be called while frozen. Fails
+    /// with `Error::InvalidFreezeQuorum` for zero. Defaults to 2. A wallet
+    /// with fewer durable admins than the quorum is never locked out by its
+    /// own setting: all of its admins can act once `FREEZE_TIMELOCK` has
+    /// passed since the freeze.
+    fn set_freeze_quorum(env: Env, quorum: u32) -> Result<(), Error>;
     /// Replace the contract's wasm. Requires wallet auth. Emits `Upgraded`.
     ///
+    /// The upgrade is STAGED: it stays pending (`get_pending_upgrade`) until
+    /// the new code passes `confirm_upgrade`, and until then
+    /// `rollback_upgrade` can revert to the last confirmed wasm. It must be
+    /// confirmed within `UPGRADE_CONFIRM_WINDOW` seconds, after which it can
+    /// only be rolled back or upgraded again. Upgrading again while pending
+    /// keeps the rollback target and restarts the window, which is also how
+    /// an expired upgrade without a rollback target is confirmed.
+    ///
     /// The host verifies the wasm EXISTS (an unknown hash rolls the whole
     /// transaction back), but cannot verify it is COMPATIBLE: upgrading to an
     /// uploaded-but-incompatible wasm still takes effect. An in-contract
@@ -60,10 +175,270 @@ pub trait SmartWalletInterface {
     /// the current invocation completes — so clients MUST verify the target
     /// hash out-of-band (e.g. against a published manifest) before invoking.
     fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error>;
+    /// Confirm the pending upgrade once the new code passes a self-test.
+    /// It requires wallet auth, which the host checks through the new
+    /// `__check_auth`: a real signer must be read, verified and pass its
+    /// limits under the new code. It then checks the signer counters are
+    /// consistent and storage is at its schema version — so `migrate` first
+    /// if the upgrade needs it. A failed check is
+    /// `Error::UpgradeSelfTestFailed`. Fails with `Error::UpgradeNotPending`
+    /// if no upgrade is pending and `Error::UpgradeExpired` past its
+    /// `deadline`. Emits `UpgradeConfirmed`.
+    fn confirm_upgrade(env: Env) -> Result<(), Error>;
+    /// Revert the pending upgrade to its rollback target and emit
+    /// `UpgradeRolledBack`. Fails with `Error::UpgradeNotPending` if no
+    /// upgrade is pending, `Error::NoRollbackTarget` if the previous hash
+    /// is unknown (see `PendingUpgrade`), and
+    /// `Error::RollbackAfterMigration` once `migrate` has moved storage past
+    /// the version the previous code runs on. Still allowed past the
+    /// upgrade's `deadline`.
+    ///
+    /// Until the `deadline`, requires the upgrade guardian's auth
+    /// (`set_upgrade_guardian`) if one is set, and wallet auth otherwise;
+    /// past it, always wallet auth. A guardian is the recovery path
+    /// for an upgrade that breaks the wallet's own `__check_auth`, since its
+    /// auth is checked by the host rather than by the new code. Either way
+    /// this entrypoint is the new code's, so it only helps if the new wasm
+    /// still exposes it. Refused while the wallet is frozen
+    /// (`Error::WalletFrozen`), so a guardian cannot swap the code of a
+    /// wallet its owner has locked.
+    fn rollback_upgrade(env: Env) -> Result<(), Error>;
+    /// Set (`Some`) or clear (`None`) the address that authorizes
+    /// `rollback_upgrade`: an account or contract whose auth does not depend
+    /// on this wallet's code (e.g. a second wallet or a recovery service).
+    /// Requires wallet auth.
+    fn set_upgrade_guardian(env: Env, guardian: Option<Address>) -> Result<(), Error>;
+    /// Migrate the wallet's storage one schema version forward, to the
+    /// layout the running code expects. Requires wallet auth. `upgrade`
+    /// cannot do this itself — the new wasm only takes effect after the
+    /// upgrading invocation completes — so call `migrate` after an upgrade,
+    /// once per version step, until it fails with `Error::AlreadyMigrated`
+    /// (at the running code's version). Emits `SchemaMigrated`.
+    fn migrate(env: Env) -> Result<(), Error>;
+    /// Extend the TTL of the wallet instance and of each listed signer entry
+    /// (with its companion entries) exactly as a successful authorization
+    /// would. PERMISSIONLESS: anyone — a relayer, a keeper bot, a custody
+    /// service — may pay to keep a dormant wallet and its rarely used
+    /// recovery signers from archiving or evicting. It changes nothing but
+    /// TTLs: no signer state is written, no activity is recorded and no
+    /// event is emitted. Keys that are not stored are skipped.
+    fn bump(env: Env, signer_keys: Vec<SignerKey>);
+    /// Remove every listed signer that is expired — its `SignerExpiration`
+    /// has passed, or it is a session revoked by `revoke_sessions` — and
+    /// emit `SignerRemoved` for each. PERMISSIONLESS: such a signer has no
+    /// authority left, so anyone may reclaim its storage. Expired signers
+    /// are never durable, so the admin/durable counters are unaffected.
+    /// Keys that are not stored, not expired, or listed twice are skipped.
+    ///
+    /// Expired policy signers are pruned too. While stored, an expired
+    /// policy rejects every candidate that requires it as a co-signer; once
+    /// pruned it is consulted directly like any unstored policy, so its
+    /// `policy__` decides again. To retire such a requirement for good,
+    /// `update_signer` the signers that name it.
+    fn prune_expired(env: Env, signer_keys: Vec<SignerKey>);
+    /// Set (`Some`) or reset to the default (`None`) the wallet's rent
+    /// strategy — see `TtlConfig`. Requires wallet auth. Every rule's
+    /// `threshold` must not exceed its `extend_to`
+    /// (`Error::InvalidTtlConfig`). Takes effect from this call's own
+    /// instance extension on.
+    fn set_ttl_config(env: Env, config: Option<TtlConfig>) -> Result<(), Error>;
+    /// Raise the wallet's admin/durable signer counters (see
+    /// `Error::LastAdminSigner` / `Error::LastSigner`) to what the listed
+    /// signers prove. Requires wallet auth. A wallet upgraded from a pre-1.0
+    /// wasm starts both counters at zero and never learns its pre-existing
+    /// signers (storage cannot be enumerated), which pins its original
+    /// admins: this lets the owner name them.
+    ///
+    /// Every key must be stored (`Error::SignerNotFound`) and listed once
+    /// (`Error::DuplicateSignerKey`). Each is classified exactly as the
+    /// counters classify it — durable, and a durable admin unless suspended
+    /// or the inheritance beneficiary — and each counter becomes the larger
+    /// of its current value and the listed signers' count. Both are lower
+    /// bounds of the true count, so no key set can make a counter overcount
+    /// and weaken a guard; listing too few signers changes nothing. Emits
+    /// `CountersReconciled` with the resulting counts.
+    fn reconcile_counters(env: Env, signer_keys: Vec<SignerKey>) -> Result<(), Error>;
+    /// Rewrite the listed signers stored by a pre-1.0 wallet to v1
+    /// semantics, and emit `SignerUpdated` for each one rewritten. Requires
+    /// wallet auth. Every key must be stored (`Error::SignerNotFound`).
+    ///
+    /// - A legacy ledger-sequence expiration becomes a UNIX timestamp,
+    ///   extrapolated from `reference`. Such an entry cannot be read by v1
+    ///   code at all (it traps) until it is migrated.
+    /// - Legacy empty limits (`Some(empty map)`, which meant unlimited)
+    ///   become `SignerLimits(None)`.
+    ///
+    /// A non-expiring entry is encoded identically by both versions, so its
+    /// empty limits cannot be told apart from v1's fail-closed encoding. It
+    /// fails closed: listing it fails with `Error::AmbiguousLegacyLimits`
+    /// and it keeps no permissions until `update_signer` (or, before the
+    /// upgrade, the legacy contract) gives it explicit limits. An entry
+    /// neither version can decode fails with `Error::InvalidLegacySigner`.
+    /// Entries with nothing to rewrite are skipped. The authorization itself
+    /// must come from a signer v1 already accepts — a non-expiring legacy
+    /// signer with `SignerLimits(None)` qualifies.
+    fn migrate_legacy_signers(
+        env: Env,
+        signer_keys: Vec<SignerKey>,
+        reference: LedgerReference,
+    ) -> Result<(), Error>;
+    /// Invoke each `(contract, fn_name, args)` call in order as the wallet
+    /// and return their results. Requires wallet auth, given ONCE for the
+    /// whole batch (e.g. approve + swap, or a run of payroll transfers).
+    ///
+    /// The wallet is the direct invoker of every call, so each call's own
+    /// `require_auth` of the wallet is satisfied without a further
+    /// signature; authorizations required deeper in a call's tree are not.
+    /// In `__check_auth` the `execute` context is replaced by one context
+    /// per call, so every call is checked against signer limits (and seen
+    /// by policies) exactly as if it had been authorized on its own. A call
+    /// targeting the wallet itself fails with `Error::ExecuteSelfCall`.
+    fn execute(env: Env, calls: Vec<(Address, Symbol, Vec<Val>)>) -> Result<Vec<Val>, Error>;
+    /// Execute an intent signed off-chain (see `Intent`) and return the
+    /// call's result. PERMISSIONLESS: validity is enforced here, not by who
+    /// submits it. `signatures` must authorize the intent's call exactly as
+    /// `__check_auth` would authorize it on its own, over
+    /// `get_intent_hash(intent)` — same signer limits, policies, frozen mode
+    /// and expiration. The nonce is consumed before the call is made.
+    ///
+    /// Fails with `Error::IntentExpired` after the deadline,
+    /// `Error::IntentDeadlineTooFar` if the deadline is too far ahead (see
+    /// `Intent`), `Error::IntentNonceUsed` if the nonce was consumed, and
+    /// `Error::ExecuteSelfCall` if the intent targets the wallet. The wallet
+    /// invokes the call as in `execute`. Emits `IntentExecuted`.
+    ///
+    /// An intent's `IntentFee::Token` fee is paid to its `recipient` after
+    /// the call, and emits `FeePaid`: the signers name the relayer, so it
+    /// can submit the intent as a meta-transaction and be reimbursed in the
+    /// same call, and nobody else can claim the fee. The fee must be allowed
+    /// by the wallet's `FeeConfig` (`Error::FeeTokenNotAllowed`,
+    /// `Error::FeeTooHigh`), and its transfer is authorized together with
+    /// the call.
+    fn execute_intent(env: Env, intent: Intent, signatures: Signatures) -> Result<Val, Error>;
+    /// Set (`Some`) or clear (`None`) the tokens and maximum amounts the
+    /// wallet pays intent fees in — see `FeeConfig`. Requires wallet auth.
+    /// Fails with `Error::InvalidFeeConfig` for a negative maximum.
+    fn set_fee_config(env: Env, config: Option<FeeConfig>) -> Result<(), Error>;
+    /// Set (`Some`) or clear (`None`) the web-auth contracts `can_web_auth`
+    /// signers may log in to — see `WebAuthConfig`. Requires wallet auth.
+    fn set_web_auth_config(env: Env, config: Option<WebAuthConfig>) -> Result<(), Error>;
+    /// Consume an intent nonce unused until `deadline`, so an intent signed
+    /// with it and a deadline no later can never execute, and emit
+    /// `IntentCancelled`. Requires wallet auth. Pass the signed intent's
+    /// deadline (or a later one). Fails with `Error::IntentNonceUsed` if it
+    /// was consumed already and `Error::IntentDeadlineTooFar` as
+    /// `execute_intent` does.
+    fn cancel_intent(env: Env, nonce: u64, deadline: u64) -> Result<(), Error>;
+    /// Deploy a subaccount — a wallet running `config.wasm_hash` — at the
+    /// address derived from this wallet and `salt`, and return it. Requires
+    /// wallet auth. The subaccount's first signer is this wallet, as an
+    /// unlimited durable `Address` signer, followed by `config.signers`: the
+    /// parent's signers can administer and recover it, while the
+    /// subaccount's own signers keep their own limits. The link is recorded
+    /// here (`is_subaccount`) and announced with `SubaccountCreated`.
+    ///
+    /// The subaccount is an ordinary wallet: its signers can remove the
+    /// parent like any other signer, within the usual last-admin guards.
+    fn create_subaccount(
+        env: Env,
+        salt: BytesN<32>,
+        config: SubaccountConfig,
+    ) -> Result<Address, Error>;
+    /// Set (`Some`) or clear (`None`) the signer registry this wallet reports
+    /// to — see `SignerRegistryInterface`. Requires wallet auth. While set,
+    /// every added signer is registered and every removed (or pruned) signer
+    /// unregistered. Signers present when the registry is set are not: sync
+    /// them through the registry itself.
+    ///
+    /// Reporting is best-effort: a registry call that fails does not block
+    /// signer management, and emits `SignerRegistryFailed` instead, so the
+    /// entry can be repaired with the registry's `sync`.
+    fn set_signer_registry(env: Env, registry: Option<Address>) -> Result<(), Error>;
     /// Return the stored signer value for a key, or `None` if not stored.
     /// Returns the raw stored value — expiration is NOT filtered; check
     /// `SignerExpiration` client-side.
     fn get_signer(env: Env, signer_key: SignerKey) -> Option<SignerVal>;
+    /// Return the stored `SignerOptions` for a key, or `None` if the signer
+    /// is not stored. A stored signer without options returns
+    /// `SignerOptions::default()`. Like `get_signer`, validity is NOT
+    /// evaluated — compare `valid_after` to the ledger time client-side.
+    fn get_signer_options(env: Env, signer_key: SignerKey) -> Option<SignerOptions>;
+    /// Return whether a stored signer is suspended, or `None` if the signer
+    /// is not stored.
+    fn is_signer_suspended(env: Env, signer_key: SignerKey) -> Option<bool>;
+    /// Return the wallet's current session generation (0 until the first
+    /// `revoke_sessions`).
+    fn get_session_generation(env: Env) -> u32;
+    /// Return the wallet's inheritance configuration, if any.
+    fn get_inheritance(env: Env) -> Option<InheritanceConfig>;
+    /// Return the timestamp of the last recorded owner activity — the
+    /// inheritance clock — or `None` if inheritance was never configured.
+    fn get_last_owner_activity(env: Env) -> Option<u64>;
+    /// Return a stored signer's usage record, or `None` if the signer is not
+    /// stored. A signer that never took part in a successful authorization
+    /// returns `SignerActivity::default()`.
+    fn get_signer_activity(env: Env, signer_key: SignerKey) -> Option<SignerActivity>;
+    /// Return the timestamp of the wallet's last successful authorization,
+    /// at most `ACTIVITY_INTERVAL` behind, or `None` if it has never
+    /// authorized anything.
+    fn get_last_active(env: Env) -> Option<u64>;
+    /// Return the wallet's `TtlConfig`, or `None` if it uses the default.
+    fn get_ttl_config(env: Env) -> Option<TtlConfig>;
+    /// Return the wallet's storage schema version: 0 for a wallet deployed
+    /// before schema versioning that has not been migrated yet.
+    fn get_schema_version(env: Env) -> u32;
+    /// Return the upgrade awaiting `confirm_upgrade`, if any.
+    fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade>;
+    /// Return the wallet's upgrade guardian, if any.
+    fn get_upgrade_guardian(env: Env) -> Option<Address>;
+    /// Return the digest the wallet's signers sign for `intent`: the
+    /// SHA-256 of a domain-separated preimage binding the intent to this
+    /// network and this wallet, which can never collide with a Soroban
+    /// authorization payload.
+    fn get_intent_hash(env: Env, intent: Intent) -> BytesN<32>;
+    /// Return the digest the wallet's signers sign for
+    /// `is_valid_signature(hash)`: the SHA-256 of the XDR of
+    /// `("sw_1271", network id, this wallet, hash)`. The domain tag, the
+    /// network and the wallet bind the signature to this wallet's message
+    /// check, so it can never be replayed as a Soroban authorization, an
+    /// intent, or another wallet's signature.
+    fn get_signature_hash(env: Env, hash: BytesN<32>) -> BytesN<32>;
+    /// Return whether `signatures` is a valid wallet signature over `hash`
+    /// (ERC-1271 style), for signed messages, logins and off-chain orders.
+    /// Signers sign `get_signature_hash(hash)`, never `hash` itself (a
+    /// WebAuthn challenge must encode that digest). The entries are checked
+    /// as pass 2 of `__check_auth` checks them, and must cover a call of
+    /// this very function on the wallet: only a signer that may act as the
+    /// wallet signs messages for it, and a frozen wallet signs nothing.
+    ///
+    /// Returns `false` for a wallet-level failure (unknown, expired or
+    /// inactive signer, uncovered, malformed WebAuthn data). A signature the
+    /// host itself rejects traps instead: the Ed25519 and secp256r1 checks
+    /// are the host's `ed25519_verify` and `secp256r1_verify`, exactly as in
+    /// `__check_auth`, and so are a rejecting policy and a missing `Address`
+    /// authorization. Treat a failed call as invalid too (`try_` from a
+    /// contract). A policy entry sees the `is_valid_signature` context in
+    /// `policy__` and may commit state as in any authorization. No usage
+    /// record, activity or TTL is written.
+    fn is_valid_signature(env: Env, hash: BytesN<32>, signatures: Signatures) -> bool;
+    /// Return the wallet's `FeeConfig`, or `None` if it pays no fees.
+    fn get_fee_config(env: Env) -> Option<FeeConfig>;
+    /// Return the wallet's `WebAuthConfig`, or `None` if `can_web_auth`
+    /// signers cover no logins.
+    fn get_web_auth_config(env: Env) -> Option<WebAuthConfig>;
+    /// Return whether `address` was deployed by this wallet's
+    /// `create_subaccount`.
+    fn is_subaccount(env: Env, address: Address) -> bool;
+    /// Return the signer registry this wallet reports to, if any.
+    fn get_signer_registry(env: Env) -> Option<Address>;
+    /// Return whether an intent nonce has been consumed and is still tracked
+    /// (until the deadline it was consumed with).
+    fn is_intent_nonce_used(env: Env, nonce: u64) -> bool;
+    /// Return whether the wallet is frozen.
+    fn is_frozen(env: Env) -> bool;
+    /// Return the configured freeze quorum (a wallet with fewer durable
+    /// admins needs all of them after `FREEZE_TIMELOCK` instead).
+    fn get_freeze_quorum(env: Env) -> u32;
 }
 
 #[contractclient(name = "PolicyClient")]
@@ -171,3 +546,29 @@ pub trait PolicyInterface {
     /// clear state for a wallet where the policy is still installed.
     fn uninstall(env: Env, wallet: Address);
 }
+
+/// A reverse-lookup registry from signer key to the wallets that hold it, for
+/// discovering a wallet from any of its signers without an indexer. Wallets
+/// opt in with `set_signer_registry` and then call `register`/`unregister`
+/// as the direct invoker, so `wallet.require_auth()` in either is satisfied
+/// by invoker auth.
+///
+/// A lookup result is a claim, not proof: anyone can deploy a wallet naming
+/// a public key as its signer. Confirm with the wallet's `get_signer` before
+/// trusting it. Such wallets can pad a key's results but never crowd out a
+/// genuine wallet: registration has no per-key capacity.
+#[contractclient(name = "SignerRegistryClient")]
+pub trait SignerRegistryInterface {
+    /// Record `wallet` under `signer_key`. Requires `wallet`'s auth.
+    /// Idempotent.
+    fn register(env: Env, wallet: Address, signer_key: SignerKey);
+    /// Drop `wallet` from `signer_key`. Requires `wallet`'s auth. Idempotent.
+    fn unregister(env: Env, wallet: Address, signer_key: SignerKey);
+    /// Return up to `limit` of the wallets registered under `signer_key`,
+    /// from position `start` (an implementation may cap the page size).
+    /// Positions are not stable: an unregistration moves the last wallet
+    /// into the freed position.
+    fn lookup(env: Env, signer_key: SignerKey, start: u32, limit: u32) -> Vec<Address>;
+    /// Return how many wallets are registered under `signer_key`.
+    fn count(env: Env, signer_key: SignerKey) -> u32;
+}
@@ -1,4 +1,11 @@
-use soroban_sdk::{contracterror, contracttype, Address, Bytes, BytesN, Map, Vec};
+// Doc comments on contract types ship in the wasm's contract spec (and the
+// generated bindings), which counts against the contract size limit, so
+// they stay a short summary. The rationale behind a type, field or error is
+// kept in plain comments below its doc comment.
+
+use soroban_sdk::{
+    contracterror, contracttype, Address, Bytes, BytesN, Map, String, Symbol, Val, Vec,
+};
 
 /// Contract errors.
 ///
@@ -8,8 +15,14 @@ use soroban_sdk::{contracterror, contracttype, Address, Bytes, BytesN, Map, Vec}
 ///
 /// Ranges:
 /// - 100-109: signer storage / management
-/// - 110-119: auth (`__check_auth`)
+/// - 110-119: auth (`__check_auth`) and wallet settings
 /// - 120-129: WebAuthn (secp256r1) verification
+/// - 130-139: upgrades
+/// - 140-149: execution (`execute`, intents, fees)
+/// - 150-159: freeze timelock and storage migration, added once 110-119 was
+///   full
+///
+/// A code is never reassigned once released.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -18,84 +31,186 @@ pub enum Error {
     SignerNotFound = 100,
     /// `add_signer` was called with a signer key that already exists.
     SignerAlreadyExists = 101,
-    /// The signer's expiration timestamp is in the past.
+    /// The signer has expired, or is a session signer revoked by
+    /// `revoke_sessions`.
     SignerExpired = 102,
-    /// The operation would remove — or demote via `update_signer` — the
-    /// wallet's LAST durable admin signer: a signer stored `Persistent`,
-    /// non-expiring (`SignerExpiration(None)`), and independently
-    /// admin-capable — either unlimited (`SignerLimits(None)`) or holding a
-    /// limits entry for the wallet's own address with no required co-signers
-    /// (`None` or an empty list). With zero such signers no `add_signer` or
-    /// `upgrade` could ever be authorized again, permanently locking the
-    /// wallet on an immutable network, so the transition is rejected.
-    /// To retire the last admin signer, add (or promote) a replacement
-    /// durable admin signer first.
-    ///
-    /// Case this guard CANNOT catch (statically undecidable): a POLICY
-    /// signer with an admin-shaped grant counts as an admin even if its
-    /// `policy__` rejects every request. If such a policy is your only
-    /// remaining admin, the wallet's admin surface is unrecoverable even
-    /// though the signer still exists. Keep a non-policy admin (or a second
-    /// admin) at all times.
+    /// The operation would remove or demote the wallet's last durable admin
+    /// signer. Add or promote a replacement first, or in the same
+    /// `apply_signer_changes` batch.
+    //
+    // A durable admin is stored `Persistent`, non-expiring
+    // (`SignerExpiration(None)`), and independently admin-capable — either
+    // unlimited (`SignerLimits(None)`) or holding a limits entry for the
+    // wallet's own address with no required co-signers (`None` or an empty
+    // list). With zero such signers no `add_signer` or `upgrade` could ever
+    // be authorized again, permanently locking the wallet on an immutable
+    // network, so the transition is rejected. A batch is checked against its
+    // final state.
+    //
+    // Case this guard CANNOT catch (statically undecidable): a POLICY
+    // signer with an admin-shaped grant counts as an admin even if its
+    // `policy__` rejects every request. If such a policy is your only
+    // remaining admin, the wallet's admin surface is unrecoverable even
+    // though the signer still exists. Keep a non-policy admin (or a second
+    // admin) at all times.
     LastAdminSigner = 103,
-    /// The operation would leave the wallet without any DURABLE signer — one
-    /// stored `Persistent` with `SignerExpiration(None)`, any limits. Fired
-    /// by `remove_signer` (removing the last durable signer), `update_signer`
-    /// (demoting it to `Temporary` storage or to an expiring value), and
-    /// `__constructor` (the wallet's first signer must be durable).
-    /// Non-durable signers can evict or expire with NO contract
-    /// call, so only a durable signer guarantees the wallet always keeps at
-    /// least one live signer; with zero live signers nothing — not even
-    /// `add_signer` — can ever be authorized again. This is the
-    /// classification-independent backstop beneath `LastAdminSigner`. To
-    /// retire the last durable signer, add a durable replacement first.
+    /// The operation would leave the wallet without a durable signer
+    /// (`Persistent`, `SignerExpiration(None)`, any limits). Add a durable
+    /// replacement first.
+    //
+    // Fired by `remove_signer` (removing the last durable signer),
+    // `update_signer` (demoting it to `Temporary` storage or to an expiring
+    // value), and `__constructor` (the initial signer set must contain a
+    // durable signer).
+    // Non-durable signers can evict or expire with NO contract
+    // call, so only a durable signer guarantees the wallet always keeps at
+    // least one live signer; with zero live signers nothing — not even
+    // `add_signer` — can ever be authorized again. This is the
+    // classification-independent backstop beneath `LastAdminSigner`. The
+    // replacement may be added in the same `apply_signer_changes` batch.
     LastSigner = 104,
+    /// A Secp256r1 signer's public key is not a valid SEC-1 encoded P-256
+    /// point, compressed or uncompressed.
+    //
+    // Wrong length or tag, a non-canonical coordinate, an `x` with no curve
+    // point (compressed), or an `(x, y)` off the curve (uncompressed).
+    // Rejected at registration so a key that could never verify a signature
+    // is never stored.
+    InvalidPublicKey = 105,
+    /// The signer's `SignerOptions::valid_after` has not been reached yet.
+    SignerNotYetValid = 106,
+    /// The signer is suspended (`suspend_signer`) until it is resumed.
+    SignerSuspended = 107,
+    /// The signer is the inheritance beneficiary and the owner has not been
+    /// inactive for the configured period yet.
+    BeneficiaryDormant = 108,
+    /// `set_inheritance` was called with an inactivity period of zero.
+    InvalidInactivityPeriod = 109,
 
-    /// No signer in the signatures map is permitted to authorize one of the
-    /// requested auth contexts.
+    /// No signer in the signatures map may authorize one of the requested
+    /// auth contexts.
     MissingContext = 110,
-    /// A signature's variant does not match the stored signer it claims to be
-    /// for (e.g. an Ed25519 signature submitted for a Policy signer key).
+    /// A signature's variant does not match the stored signer it is for.
+    // E.g. an Ed25519 signature submitted for a Policy signer key.
     SignatureKeyValueMismatch = 111,
+    /// The wallet is frozen and an auth context is neither `unfreeze` nor
+    /// signer management on the wallet itself.
+    WalletFrozen = 112,
+    /// The wallet is frozen and fewer active durable admins signed than the
+    /// freeze quorum.
+    FreezeQuorumNotMet = 113,
+    /// `set_freeze_quorum` was called with a quorum of zero.
+    InvalidFreezeQuorum = 114,
+    /// A signer's metadata label is longer than `MAX_SIGNER_LABEL_LEN`.
+    InvalidSignerMetadata = 115,
+    /// A `TtlConfig` rule has a `threshold` above its `extend_to`.
+    InvalidTtlConfig = 116,
+    /// `reconcile_counters` was given the same signer key more than once.
+    DuplicateSignerKey = 117,
+    /// `migrate` was called on storage already at the code's schema version.
+    AlreadyMigrated = 118,
+    /// An `Address` signer names the wallet itself.
+    //
+    // It could never authorize anything (a contract cannot re-enter itself),
+    // yet would count as an admin, so it is rejected at registration.
+    SelfAddressSigner = 119,
 
     /// clientDataJSON exceeds the 1024 byte parse buffer.
     ClientDataJsonTooLarge = 120,
-    /// clientDataJSON is not parseable JSON (or is missing required fields).
+    /// clientDataJSON is not parseable JSON or lacks a required field.
     ClientDataJsonParseError = 121,
-    /// The challenge in clientDataJSON does not match the base64url-encoded
-    /// signature payload. This binds the WebAuthn assertion to the Soroban
-    /// authorization entry and MUST NOT be weakened.
+    /// The clientDataJSON challenge is not the base64url signature payload.
+    //
+    // This binds the WebAuthn assertion to the Soroban authorization entry
+    // and MUST NOT be weakened.
     ClientDataJsonChallengeIncorrect = 122,
     /// clientDataJSON `type` is not "webauthn.get".
     InvalidWebAuthnType = 123,
-    /// authenticatorData is shorter than the WebAuthn minimum of 37 bytes
-    /// (rpIdHash 32 + flags 1 + signCount 4).
+    /// authenticatorData is shorter than the WebAuthn minimum of 37 bytes.
+    // rpIdHash 32 + flags 1 + signCount 4.
     InvalidAuthenticatorData = 124,
     /// The authenticator did not set the User Present (UP) flag.
-    ///
-    /// UP-only is the deliberate default. Requiring UP keeps
-    /// silent, non-interactive assertions out while staying compatible with
-    /// authenticators that cannot do User Verification (UV — biometric/PIN).
-    /// UV is therefore NOT required by this contract. A deployment that wants
-    /// UV-required assertions should enforce it at the client/relayer layer,
-    /// or via a future per-signer flag (which would be a signer-model change,
-    /// not a change to this check); the contract cannot upgrade UP-only
-    /// signers to UV-required retroactively without such a flag.
+    //
+    // UP-only is the deliberate default. Requiring UP keeps
+    // silent, non-interactive assertions out while staying compatible with
+    // authenticators that cannot do User Verification (UV — biometric/PIN).
+    // UV is therefore NOT required by this contract. A deployment that wants
+    // UV-required assertions should enforce it at the client/relayer layer,
+    // or via a future per-signer flag (which would be a signer-model change,
+    // not a change to this check); the contract cannot upgrade UP-only
+    // signers to UV-required retroactively without such a flag.
     UserPresenceRequired = 125,
-    /// authenticatorData exceeds the 1024 byte cap (symmetric with
-    /// `ClientDataJsonTooLarge`). Real assertions are ~37 bytes; the cap
-    /// rejects oversized input BEFORE it is hashed, since this path is
-    /// reachable without a valid signature.
+    /// authenticatorData exceeds the 1024 byte cap.
+    //
+    // Symmetric with `ClientDataJsonTooLarge`. Real assertions are ~37
+    // bytes; the cap rejects oversized input BEFORE it is hashed, since this
+    // path is reachable without a valid signature.
     AuthenticatorDataTooLarge = 126,
+
+    /// No upgrade is awaiting confirmation.
+    UpgradeNotPending = 130,
+    /// The pending upgrade has no rollback target (see `PendingUpgrade`).
+    NoRollbackTarget = 131,
+    /// The new code's `confirm_upgrade` self-test failed.
+    //
+    // The signer counters are inconsistent, or storage is not at the code's
+    // schema version (run `migrate` first).
+    UpgradeSelfTestFailed = 132,
+    /// `confirm_upgrade` was called after the pending upgrade's `deadline`.
+    /// Roll it back, or upgrade again to restart the confirm window.
+    UpgradeExpired = 133,
+    /// `rollback_upgrade` was called after `migrate` moved storage past the
+    /// rollback target's schema version.
+    RollbackAfterMigration = 134,
+
+    /// An `execute` call targets the wallet itself.
+    // The wallet's own functions are invoked directly, never through
+    // `execute`.
+    ExecuteSelfCall = 140,
+    /// `execute_intent` was called after the intent's `deadline`.
+    IntentExpired = 141,
+    /// The intent's nonce was already consumed.
+    // By `execute_intent` or `cancel_intent`.
+    IntentNonceUsed = 142,
+    /// The fee token is not allowed by the wallet's `FeeConfig`, or it has
+    /// none.
+    FeeTokenNotAllowed = 143,
+    /// The fee is negative or above the wallet's maximum for its token.
+    FeeTooHigh = 144,
+    /// A `FeeConfig` maximum is negative.
+    InvalidFeeConfig = 145,
+    /// The deadline is further ahead than its nonce can be tracked.
+    //
+    // One second per ledger of the network's maximum entry TTL; applies to
+    // intents and `cancel_intent`.
+    IntentDeadlineTooFar = 146,
+
+    /// The wallet lost its freeze quorum and was frozen less than
+    /// `FREEZE_TIMELOCK` seconds ago.
+    //
+    // I.e. it is frozen and has fewer durable admins than its freeze quorum.
+    FreezeTimelockActive = 150,
+    /// `migrate` has no step for the stored schema version.
+    UnsupportedSchemaVersion = 155,
+    /// A legacy entry is neither a v1 nor a pre-1.0 signer value.
+    // Raised by `migrate_legacy_signers`.
+    InvalidLegacySigner = 156,
+    /// A legacy non-expiring entry has empty limits. Set its limits with
+    /// `update_signer` instead.
+    //
+    // Raised by `migrate_legacy_signers`. Both versions encode it
+    // identically, so it cannot be told apart from a v1 signer with no
+    // permissions, and it is not lifted to unlimited.
+    AmbiguousLegacyLimits = 157,
 }
 
 /// Optional expiration for a signer as a UNIX timestamp in seconds, INCLUSIVE:
 /// the signer is valid while `ledger timestamp <= expiration` and expired once
 /// `ledger timestamp > expiration`. `None` never expires.
-///
-/// v1 breaking change: this was a ledger sequence number pre-1.0. Timestamps
-/// don't drift with changes to ledger close time (e.g. CAP-0070 dynamic
-/// timing), which ledger-sequence expirations did.
+//
+// v1 breaking change: this was a ledger sequence number pre-1.0. Timestamps
+// don't drift with changes to ledger close time (e.g. CAP-0070 dynamic
+// timing), which ledger-sequence expirations did.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SignerExpiration(pub Option<u64>);
@@ -114,44 +229,366 @@ pub struct SignerExpiration(pub Option<u64>);
 /// - `Some({address -> Some([keys])})`: the signer may authorize invocations
 ///   of contract `address` only if every listed key also APPROVES. The listed
 ///   keys are required CO-SIGNERS.
-///
-/// ## Required co-signers are scope-independent approvers
-///
-/// A required co-signer's OWN `SignerLimits` do NOT constrain its co-signer
-/// role — a key's limits govern only its INDEPENDENT authority (whether it can
-/// cover a context on its own). This is symmetric across key kinds:
-///
-/// - A non-policy required key must be present in the transaction's signatures
-///   map (and is therefore fully verified — stored, unexpired, crypto-valid —
-///   in pass 2 of `__check_auth`). Its own limits are not consulted.
-/// - A policy required key must APPROVE the specific context via `policy__`
-///   (it need not appear in the signatures map). If the policy key is also
-///   stored on this wallet it must be unexpired, but its own stored limits are
-///   NOT recursively enforced.
-///
-/// Consequence: `Some(empty map)` on a key disables that key's INDEPENDENT
-/// coverage only. A key with empty limits can still serve as a required
-/// co-signer for another signer. Because no stored policy's limits are
-/// re-entered, there is no policy-limit recursion (and thus no cycle to
-/// guard against).
-///
-/// Notes:
-/// - Deploy permission is NOT grantable through limits: `CreateContract*`
-///   contexts require an unlimited (`None`) signer. (Pre-1.0 a limits entry
-///   for the wallet's own address doubled as deploy permission.)
-/// - A limited signer may ALWAYS authorize `remove_signer(its own key)` on
-///   this wallet, regardless of its limits map, and without co-signer
-///   requirements. Self-removal is never escalation. (Execution still
-///   rejects removing the wallet's last durable admin signer — see
-///   `Error::LastAdminSigner`.)
-/// - Granting a limits entry for the wallet's own address grants the wallet's
-///   admin surface (`add_signer`, `update_signer`, `remove_signer`,
-///   `upgrade`). A signer that can add signers can add an unlimited signer,
-///   so treat such a grant as equivalent to full control of the wallet.
+//
+// ## Required co-signers are scope-independent approvers
+//
+// A required co-signer's OWN `SignerLimits` do NOT constrain its co-signer
+// role — a key's limits govern only its INDEPENDENT authority (whether it can
+// cover a context on its own). This is symmetric across key kinds:
+//
+// - A non-policy required key must be present in the transaction's signatures
+//   map (and is therefore fully verified — stored, unexpired, crypto-valid —
+//   in pass 2 of `__check_auth`). Its own limits are not consulted.
+// - A policy required key must APPROVE the specific context via `policy__`
+//   (it need not appear in the signatures map). If the policy key is also
+//   stored on this wallet it must be unexpired, but its own stored limits are
+//   NOT recursively enforced.
+//
+// Consequence: `Some(empty map)` on a key disables that key's INDEPENDENT
+// coverage only. A key with empty limits can still serve as a required
+// co-signer for another signer. Because no stored policy's limits are
+// re-entered, there is no policy-limit recursion (and thus no cycle to
+// guard against).
+//
+// Notes:
+// - Deploy permission is NOT grantable through limits: `CreateContract*`
+//   contexts require an unlimited (`None`) signer. (Pre-1.0 a limits entry
+//   for the wallet's own address doubled as deploy permission.)
+// - A limited signer may ALWAYS authorize `remove_signer(its own key)` on
+//   this wallet, regardless of its limits map, and without co-signer
+//   requirements. Self-removal is never escalation. (Execution still
+//   rejects removing the wallet's last durable admin signer — see
+//   `Error::LastAdminSigner`.)
+// - Granting a limits entry for the wallet's own address grants the wallet's
+//   admin surface (`add_signer`, `update_signer`, `remove_signer`,
+//   `apply_signer_changes`, `suspend_signer`, `resume_signer`,
+//   `revoke_sessions`, `freeze`, `unfreeze`, `set_freeze_quorum`,
+//   `set_inheritance`, `set_ttl_config`, `upgrade`). A signer that can add
+//   signers can add an unlimited signer, so treat such a grant as
+//   equivalent to full control of the wallet.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SignerLimits(pub Option<Map<Address, Option<Vec<SignerKey>>>>);
 
+/// Per-signer settings beyond the stored `SignerVal`, supplied as the last
+/// field of every `Signer`. The default is not stored.
+//
+// Stored alongside the signer entry (same durability, same TTL).
+// `SignerOptions::default()` — every field unset — is the behaviour of a
+// signer without options. `update_signer` replaces a signer's options
+// together with its value.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SignerOptions {
+    /// Optional validity start as a UNIX timestamp in seconds, inclusive.
+    /// Until then the signer is skipped for coverage and rejected
+    /// (`Error::SignerNotYetValid`) in a signatures map.
+    //
+    // This is the "security delay" primitive: an admin added with
+    // `valid_after = now + 48h` leaves its owner a window to spot and remove
+    // a malicious addition before it can authorize anything.
+    //
+    // A not-yet-valid signer still COUNTS toward the durable / durable-admin
+    // guards (`Error::LastSigner`/`Error::LastAdminSigner`) when it is
+    // otherwise durable: it becomes usable by the passage of time alone, so
+    // it can never leave the wallet permanently without a signer — only
+    // temporarily without a usable one. Keep that in mind before removing
+    // the last currently-valid admin in favour of a delayed one.
+    pub valid_after: Option<u64>,
+    /// The signer may authorize the wallet's own `freeze` call by itself,
+    /// whatever its `SignerLimits`, while it is active.
+    //
+    // It grants nothing else — a panic key with an empty limits map and
+    // `can_freeze` can lock the wallet but cannot unfreeze it or touch its
+    // signers.
+    pub can_freeze: bool,
+    /// The signer may authorize a SEP-45 `web_auth_verify(args)` call for
+    /// this wallet by itself, whatever its `SignerLimits`, if the wallet's
+    /// `WebAuthConfig` lists the web-auth contract for its home domain.
+    //
+    // A device key with no payment authority can thus log in to an anchor.
+    // It grants nothing else: any call the web-auth contract makes on the
+    // wallet's behalf is a context of its own that still needs coverage. A
+    // wallet without a `WebAuthConfig` accepts no logins through this
+    // permission.
+    pub can_web_auth: bool,
+    /// Display metadata for wallets and indexers (every field optional).
+    /// Never evaluated by the contract.
+    pub metadata: SignerMetadata,
+}
+
+/// Maximum length of `SignerMetadata::label`, in bytes.
+pub const MAX_SIGNER_LABEL_LEN: u32 = 64;
+
+/// Human-readable description of a signer (`SignerOptions::metadata`), so
+/// clients and indexers can render "Alice's iPhone" instead of a credential
+/// id. Only the label's length is checked.
+//
+// It is stored with the signer's options and emitted in
+// `SignerAdded`/`SignerUpdated`, so the chain is the single source of
+// truth. All fields are supplied by the client and purely informational
+// (`Error::InvalidSignerMetadata` is the only check).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SignerMetadata {
+    /// Display name, at most `MAX_SIGNER_LABEL_LEN` bytes (UTF-8 by
+    /// convention).
+    pub label: Option<String>,
+    /// When the credential was created, as a UNIX timestamp in seconds.
+    pub created_at: Option<u64>,
+    /// Device-type tag, e.g. `iphone`, `android`, `yubikey` or `ledger`.
+    pub device: Option<Symbol>,
+}
+
+/// Inheritance ("dead man's switch") configuration, set with
+/// `set_inheritance`.
+///
+/// `beneficiary` is a stored signer that stays dormant while the owner is
+/// active, and becomes an unlimited admin once no owner activity has been
+/// recorded for more than `inactivity_period` seconds.
+//
+// A dormant beneficiary cannot sign anything (`Error::BeneficiaryDormant`);
+// an active one can authorize any context, whatever its own `SignerLimits`.
+// Owner activity is any successful authorization signed by an Ed25519,
+// Secp256r1 or Address signer other than the beneficiary; it resets the
+// clock (and puts an activated beneficiary back to sleep). Policy signatures
+// carry no secret and never count as activity, so nobody can hold the
+// switch open by submitting them.
+//
+// The beneficiary is never counted as a durable admin, so it cannot stand
+// in for the owner's last admin signer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InheritanceConfig {
+    pub beneficiary: SignerKey,
+    pub inactivity_period: u64,
+}
+
+/// An `upgrade` awaiting `confirm_upgrade` (`get_pending_upgrade`). `hash`
+/// is the wasm now running; `rollback_upgrade` reverts to `rollback_hash`,
+/// the last confirmed wasm, while storage is still at `schema_version`.
+/// `deadline` ends the confirm window; each `upgrade` restarts it.
+//
+// The wallet caches its genesis hash in `__constructor`, so `rollback_hash`
+// is `None` only for a wallet deployed by older code that has never
+// upgraded since: it cannot know the wasm it was deployed with. To get a
+// rollback target for such a wallet, first upgrade it to the wasm it
+// already runs and confirm that.
+//
+// After `deadline` the upgrade can no longer be confirmed, and the upgrade
+// guardian can no longer roll it back. `schema_version` is the storage
+// schema version the rollback target ran on: once `migrate` moves past it,
+// the rollback is refused. Like `rollback_hash`, it is set by the first
+// `upgrade` of a pending sequence and kept by later ones.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub hash: BytesN<32>,
+    pub rollback_hash: Option<BytesN<32>>,
+    pub deadline: u64,
+    pub schema_version: u32,
+}
+
+/// Seconds a frozen wallet with fewer durable admins than its freeze quorum
+/// stays locked before all of its admins together can act: three days.
+pub const FREEZE_TIMELOCK: u64 = 3 * 24 * 60 * 60;
+
+/// Seconds after its latest `upgrade` within which a pending upgrade can be
+/// confirmed (`PendingUpgrade::deadline`): seven days.
+pub const UPGRADE_CONFIRM_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// Seconds a recorded use stays current: a successful authorization
+/// rewrites a signer's `SignerActivity`, or the wallet's last-active time,
+/// only once the stored timestamp is at least this old. One hour.
+pub const ACTIVITY_INTERVAL: u64 = 60 * 60;
+
+/// Usage record of a signer (`get_signer_activity`), updated when it signs
+/// a successful `__check_auth`, at most once per `ACTIVITY_INTERVAL`.
+//
+// A failed authorization records nothing. Recording a use only once the
+// previous one is `ACTIVITY_INTERVAL` old makes a busy signer cost one
+// write per interval rather than one per authorization. It is stored
+// alongside the signer entry (same durability, same TTL), survives
+// `update_signer` and is cleared by `remove_signer`, so a re-added signer
+// starts over.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SignerActivity {
+    /// UNIX timestamp in seconds of the last recorded use, at most
+    /// `ACTIVITY_INTERVAL` behind the last successful one; `0` if the signer
+    /// has never been used.
+    pub last_used: u64,
+    /// Number of recorded uses (saturating): successful authorizations at
+    /// least `ACTIVITY_INTERVAL` after the previous recorded one.
+    pub use_count: u32,
+}
+
+/// One TTL extension rule, in ledgers: an entry whose remaining TTL is
+/// below `threshold` is extended to `extend_to` (both clamped to the
+/// network's `max_ttl`); otherwise it is left alone, so `extend_to -
+/// threshold` bounds how often the extension is actually written.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlRule {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+/// The `TtlRule`s applied on one kind of call, per class of entry.
+/// `persistent`/`temporary` cover signer entries of that durability together
+/// with their companion entries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlSchedule {
+    pub instance: TtlRule,
+    pub persistent: TtlRule,
+    pub temporary: TtlRule,
+}
+
+/// The wallet's rent strategy (`set_ttl_config`). `auth` applies to
+/// `__check_auth` and `bump`; `mutation` applies to every wallet-authorized
+/// state change.
+//
+// `auth` covers the instance and the signers that signed or were
+// consulted; `mutation` the instance and the touched signers.
+//
+// Without a configuration every rule is "extend to `max_ttl` once within
+// one week of it": an actively used wallet prepays its rent indefinitely.
+// Lower targets keep a high-volume wallet's rent down; keep in mind that a
+// Temporary signer not extended in time EVICTS for good, and a Persistent
+// one archives (restorable, at a cost).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub auth: TtlSchedule,
+    pub mutation: TtlSchedule,
+}
+
+/// A call signed off-chain by the wallet's signers, executable by anyone
+/// through `execute_intent` (e.g. a limit order or scheduled payment that a
+/// keeper submits later). The signers sign `get_intent_hash(intent)`.
+/// `nonce` is any unused value; `deadline` is the last UNIX timestamp,
+/// inclusive, at which it may execute. `fee` is signed with it.
+//
+// `get_intent_hash` is a digest bound to this network and this wallet,
+// signed exactly as a Soroban authorization payload would be.
+//
+// Nonces are a set, not a sequence, so independent intents never block each
+// other. A consumed nonce is tracked only until the deadline it was
+// consumed with, after which the intent could not execute anyway; so the
+// deadline may be at most the network's maximum entry TTL, in ledgers,
+// seconds ahead (`Error::IntentDeadlineTooFar`).
+// `fee` reimburses the submitter (see `FeeConfig`). Its token, amount and
+// recipient are part of the signed intent, so a submitter cannot redirect
+// it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Intent {
+    pub contract: Address,
+    pub fn_name: Symbol,
+    pub args: Vec<Val>,
+    pub nonce: u64,
+    pub deadline: u64,
+    pub fee: IntentFee,
+}
+
+/// The fee an `Intent` pays its submitter, if any.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IntentFee {
+    None,
+    Token(FeePayment),
+}
+
+/// A fee in a SEP-41 `token`, paid by the wallet to `recipient` (the
+/// relayer the intent is signed for) to reimburse the network fee it paid.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeePayment {
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+/// Which tokens the wallet pays intent fees in (`set_fee_config`), each
+/// with the maximum fee per intent. A wallet without one pays no fees.
+//
+// The fee is paid as a `transfer` from the wallet, authorized together
+// with the intent's call, so it is also bounded by the signer's limits:
+// the signer needs the fee token in its `SignerLimits`, and policies see
+// the transfer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub max_fees: Map<Address, i128>,
+}
+
+/// The SEP-45 web-auth contracts a `can_web_auth` signer may log in to
+/// (`set_web_auth_config`), each with the home domain of the anchor that
+/// publishes it.
+//
+// A login is covered only on a listed contract and, when its arguments
+// carry a `home_domain`, only for that contract's domain, so a device key
+// cannot be walked into another anchor's (or an attacker's)
+// `web_auth_verify`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WebAuthConfig {
+    pub contracts: Map<Address, String>,
+}
+
+/// A subaccount to deploy with `create_subaccount`: the wasm it runs and its
+/// signers besides the parent, e.g. a spending key limited to one token.
+/// The parent wallet is added first, as an unlimited durable `Address`
+/// signer, so `signers` may be empty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubaccountConfig {
+    pub wasm_hash: BytesN<32>,
+    pub signers: Vec<Signer>,
+}
+
+/// One setting `__constructor` applies, validated exactly as by its setter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WalletSetting {
+    /// As `set_freeze_quorum`.
+    FreezeQuorum(u32),
+    /// As `set_ttl_config`.
+    TtlConfig(TtlConfig),
+    /// As `set_inheritance`; the beneficiary must be an initial signer.
+    Inheritance(InheritanceConfig),
+    /// As `set_fee_config`.
+    FeeConfig(FeeConfig),
+    /// As `set_signer_registry`.
+    SignerRegistry(Address),
+    /// As `set_web_auth_config`.
+    WebAuthConfig(WebAuthConfig),
+}
+
+/// Wallet settings applied by `__constructor`, so a wallet is born
+/// configured instead of needing an authorized follow-up per setting.
+/// Settings are applied in order, as successive setter calls would be; one
+/// not listed stays at its default.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WalletSettings(pub Vec<WalletSetting>);
+
+/// A ledger and the UNIX timestamp it closed at, supplied to
+/// `migrate_legacy_signers` to convert pre-1.0 ledger-sequence expirations
+/// into v1 timestamps.
+//
+// Any ledger the caller can look up works (e.g. a recent one from RPC);
+// other ledgers are extrapolated from it at the historical 5-second close
+// time, so a reference close to the expirations being converted is the most
+// accurate.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerReference {
+    pub sequence: u32,
+    pub timestamp: u64,
+}
+
 /// Which durability a signer entry is stored under. At most one entry exists
 /// per signer key; lookups check Temporary before Persistent.
 #[contracttype]
@@ -162,18 +599,50 @@ pub enum SignerStorage {
 }
 
 /// Full signer description used by `__constructor`, `add_signer` and
-/// `update_signer`.
+/// `update_signer`. Secp256r1 carries the WebAuthn credential id (`keyId`)
+/// and a SEC-1 P-256 public key, uncompressed (65 bytes) or compressed (33
+/// bytes). Address delegates to another account, never the wallet itself.
+//
+// Uncompressed is `0x04 || x || y`, compressed `0x02`/`0x03 || x`. Every
+// key is validated to be a point on the curve before it is stored
+// (`Error::InvalidPublicKey`); compressed keys are decompressed on-chain, so
+// the stored `SignerVal` always holds the 65-byte uncompressed form.
+//
+// An Address signer is a G-account or a contract, e.g. a parent smart
+// wallet (see `create_subaccount`): it signs by authorizing the wallet's
+// `__check_auth` for the signature payload in its own authorization entry.
+// It may not be the wallet itself (`Error::SelfAddressSigner`).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Signer {
-    Policy(Address, SignerExpiration, SignerLimits, SignerStorage),
-    Ed25519(BytesN<32>, SignerExpiration, SignerLimits, SignerStorage),
+    Policy(
+        Address,
+        SignerExpiration,
+        SignerLimits,
+        SignerStorage,
+        SignerOptions,
+    ),
+    Ed25519(
+        BytesN<32>,
+        SignerExpiration,
+        SignerLimits,
+        SignerStorage,
+        SignerOptions,
+    ),
     Secp256r1(
         Bytes,
-        BytesN<65>,
+        Bytes,
+        SignerExpiration,
+        SignerLimits,
+        SignerStorage,
+        SignerOptions,
+    ),
+    Address(
+        Address,
         SignerExpiration,
         SignerLimits,
         SignerStorage,
+        SignerOptions,
     ),
 }
 
@@ -185,15 +654,30 @@ pub enum SignerKey {
     Policy(Address),
     Ed25519(BytesN<32>),
     Secp256r1(Bytes),
+    Address(Address),
+}
+
+/// One step of an `apply_signer_changes` batch. Each step has exactly the
+/// semantics of the matching single-signer entrypoint (`add_signer`,
+/// `update_signer`, `remove_signer`), applied in order against the state left
+/// by the previous step.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignerOp {
+    Add(Signer),
+    Update(Signer),
+    Remove(SignerKey),
 }
 
-/// Stored signer value. Secp256r1 carries the SEC-1 uncompressed public key.
+/// Stored signer value. Secp256r1 carries the SEC-1 uncompressed public key
+/// (validated, and decompressed if it was registered compressed).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SignerVal {
     Policy(SignerExpiration, SignerLimits),
     Ed25519(SignerExpiration, SignerLimits),
     Secp256r1(BytesN<65>, SignerExpiration, SignerLimits),
+    Address(SignerExpiration, SignerLimits),
 }
 
 /// A WebAuthn assertion over the Soroban authorization payload. The signed
@@ -209,13 +693,16 @@ pub struct Secp256r1Signature {
 
 /// A signature entry in the signatures map. `Policy` carries no signature
 /// material: inclusion of the policy key authorizes an on-chain `policy__`
-/// check instead.
+/// check instead. Nor does `Address`: the address must authorize the
+/// wallet's `__check_auth` with `args = [signature payload]` in its own
+/// authorization entry of the same transaction.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Signature {
     Policy,
     Ed25519(BytesN<64>),
     Secp256r1(Secp256r1Signature),
+    Address,
 }
 
 /// The `__check_auth` signature object: a map of signer keys to signatures.
@@ -0,0 +1,98 @@
+use smart_wallet_interface::types::{SignerActivity, SignerKey, SignerStorage, ACTIVITY_INTERVAL};
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+use crate::storage::{extend_signer_key, StorageKey, TtlTrigger};
+
+/// Instance storage key of the timestamp of the wallet's last successful
+/// authorization.
+const LAST_ACTIVE: Symbol = symbol_short!("last_act");
+
+/// The usage record of the signer entry in `signer_storage`, or the default
+/// if it has never been used.
+pub fn get_signer_activity(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_storage: &SignerStorage,
+) -> SignerActivity {
+    let activity_key = StorageKey::SignerActivity(signer_key.clone());
+
+    match signer_storage {
+        SignerStorage::Persistent => env
+            .storage()
+            .persistent()
+            .get::<StorageKey, SignerActivity>(&activity_key),
+        SignerStorage::Temporary => env
+            .storage()
+            .temporary()
+            .get::<StorageKey, SignerActivity>(&activity_key),
+    }
+    .unwrap_or_default()
+}
+
+/// Store the usage record of the signer entry in `signer_storage`. The
+/// caller extends the signer's TTL afterwards (`extend_signer_key`).
+pub fn set_signer_activity(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_storage: &SignerStorage,
+    activity: &SignerActivity,
+) {
+    let activity_key = StorageKey::SignerActivity(signer_key.clone());
+
+    match signer_storage {
+        SignerStorage::Persistent => env
+            .storage()
+            .persistent()
+            .set::<StorageKey, SignerActivity>(&activity_key, activity),
+        SignerStorage::Temporary => env
+            .storage()
+            .temporary()
+            .set::<StorageKey, SignerActivity>(&activity_key, activity),
+    }
+}
+
+/// Whether a use recorded at `last_used` is `ACTIVITY_INTERVAL` old, so a
+/// new one is worth a write.
+fn is_stale(env: &Env, last_used: u64) -> bool {
+    env.ledger().timestamp() >= last_used.saturating_add(ACTIVITY_INTERVAL)
+}
+
+/// Record a verified use of the signer entry in `signer_storage`, if its
+/// last recorded use is stale, and extend the entry with its companions.
+/// Pass 2 calls this once per signatures map entry in place of the plain
+/// TTL bump on lookup, so the bump covers a freshly written record too.
+/// Runs inside `__check_auth`, whose writes are discarded if the
+/// authorization fails.
+pub fn record_signer_use(env: &Env, signer_key: &SignerKey, signer_storage: &SignerStorage) {
+    let mut activity = get_signer_activity(env, signer_key, signer_storage);
+
+    if activity.use_count == 0 || is_stale(env, activity.last_used) {
+        activity.last_used = env.ledger().timestamp();
+        activity.use_count = activity.use_count.saturating_add(1);
+
+        set_signer_activity(env, signer_key, signer_storage, &activity);
+    }
+
+    extend_signer_key(
+        env,
+        signer_key,
+        matches!(signer_storage, SignerStorage::Persistent),
+        TtlTrigger::Auth,
+    );
+}
+
+pub fn get_last_active(env: &Env) -> Option<u64> {
+    env.storage().instance().get::<Symbol, u64>(&LAST_ACTIVE)
+}
+
+/// Record the wallet-wide last-active timestamp after a successful
+/// authorization, if the stored one is stale.
+pub fn record_wallet_activity(env: &Env) {
+    if get_last_active(env).is_some_and(|last_active| !is_stale(env, last_active)) {
+        return;
+    }
+
+    env.storage()
+        .instance()
+        .set::<Symbol, u64>(&LAST_ACTIVE, &env.ledger().timestamp());
+}
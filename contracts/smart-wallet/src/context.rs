@@ -1,13 +1,17 @@
 use smart_wallet_interface::{
-    types::{Signatures, SignerKey, SignerLimits},
+    types::{Error, Signatures, SignerKey, SignerLimits},
     PolicyClient,
 };
 use soroban_sdk::{
     auth::{Context, ContractContext},
-    vec, Env, Symbol, TryFromVal, Vec,
+    vec, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
-use crate::signer::{get_signer_val_storage, is_signer_expired, signer_expiration};
+use crate::signer::{
+    get_signer_val_storage, is_session_revoked, is_signer_expired, signer_expiration,
+    signer_inactive_error,
+};
+use crate::web_auth::get_web_auth_config;
 
 /// True iff `context` is THIS wallet's `remove_signer(signer_key)` — i.e.
 /// `signer_key` removing itself. Gated on the wallet's own address; a foreign
@@ -35,6 +39,154 @@ pub fn is_self_removal_context(env: &Env, context: &Context, signer_key: &Signer
     false
 }
 
+/// The function name of `context` if it is a call on THIS wallet.
+fn wallet_fn_name(env: &Env, context: &Context) -> Option<Symbol> {
+    match context {
+        Context::Contract(ContractContext {
+            contract, fn_name, ..
+        }) if *contract == env.current_contract_address() => Some(fn_name.clone()),
+        _ => None,
+    }
+}
+
+/// Replace each context of THIS wallet's `execute(calls)` with one contract
+/// context per call, in order; every other context is kept as is.
+///
+/// `execute` does nothing but invoke its calls as the wallet, so authorizing
+/// it is exactly authorizing each call: the expanded list is what the frozen
+/// gate, pass 1 coverage and policy signatures of `__check_auth` evaluate. A
+/// limited signer thus needs no wallet-self entry to batch calls it could
+/// authorize one by one. A call on the wallet itself is refused with
+/// `Error::ExecuteSelfCall` (so expansion never recurses). The host only
+/// asks for auth of an `execute` whose arguments decoded, so an undecodable
+/// one is left unexpanded, where it needs wallet-self coverage.
+pub fn expand_execute_contexts(env: &Env, contexts: &Vec<Context>) -> Result<Vec<Context>, Error> {
+    let execute = Symbol::new(env, "execute");
+    let mut expanded = Vec::new(env);
+
+    for context in contexts.iter() {
+        let calls = match &context {
+            Context::Contract(ContractContext { args, .. })
+                if wallet_fn_name(env, &context) == Some(execute.clone()) && args.len() == 1 =>
+            {
+                Vec::<(Address, Symbol, Vec<Val>)>::try_from_val(env, &args.get_unchecked(0)).ok()
+            }
+            _ => None,
+        };
+
+        match calls {
+            Some(calls) => {
+                for (contract, fn_name, args) in calls.iter() {
+                    if contract == env.current_contract_address() {
+                        return Err(Error::ExecuteSelfCall);
+                    }
+
+                    expanded.push_back(Context::Contract(ContractContext {
+                        contract,
+                        fn_name,
+                        args,
+                    }));
+                }
+            }
+            None => expanded.push_back(context),
+        }
+    }
+
+    Ok(expanded)
+}
+
+/// The context `is_valid_signature(hash)` authorizes: a call on THIS wallet,
+/// so only a signer that may act as the wallet itself (unlimited, or limited
+/// with a wallet-self entry) signs messages for it. A session key limited to
+/// a token cannot sign an order or a login as the wallet.
+pub fn signature_context(env: &Env, hash: &BytesN<32>) -> Context {
+    Context::Contract(ContractContext {
+        contract: env.current_contract_address(),
+        fn_name: Symbol::new(env, "is_valid_signature"),
+        args: (hash.clone(),).into_val(env),
+    })
+}
+
+/// True iff `context` is THIS wallet's `freeze()` — the one context a
+/// `SignerOptions::can_freeze` signer may authorize regardless of its limits.
+pub fn is_freeze_context(env: &Env, context: &Context) -> bool {
+    if let Context::Contract(ContractContext { args, .. }) = context {
+        return args.is_empty() && wallet_fn_name(env, context) == Some(Symbol::new(env, "freeze"));
+    }
+
+    false
+}
+
+/// True iff `context` is a SEP-45 `web_auth_verify(args)` call on a
+/// web-auth contract listed in the wallet's `WebAuthConfig`, whose `args` map
+/// names THIS wallet as its `account` and, if it carries a `home_domain`,
+/// names the domain listed for that contract — the one context a
+/// `SignerOptions::can_web_auth` signer may authorize regardless of its
+/// limits. Malformed arguments never match and never panic.
+pub fn is_web_auth_context(env: &Env, context: &Context) -> bool {
+    let Context::Contract(ContractContext {
+        contract,
+        fn_name,
+        args,
+    }) = context
+    else {
+        return false;
+    };
+
+    if *contract == env.current_contract_address()
+        || *fn_name != Symbol::new(env, "web_auth_verify")
+        || args.len() != 1
+    {
+        return false;
+    }
+
+    let Some(home_domain) =
+        get_web_auth_config(env).and_then(|config| config.contracts.get(contract.clone()))
+    else {
+        return false;
+    };
+
+    let Ok(args) = Map::<Symbol, String>::try_from_val(env, &args.get_unchecked(0)) else {
+        return false;
+    };
+
+    if !matches!(
+        args.try_get(Symbol::new(env, "account")),
+        Ok(Some(account)) if account == env.current_contract_address().to_string()
+    ) {
+        return false;
+    }
+
+    match args.try_get(Symbol::new(env, "home_domain")) {
+        Ok(Some(domain)) => domain == home_domain,
+        Ok(None) => true,
+        Err(_) => false,
+    }
+}
+
+/// True iff `context` may be authorized while the wallet is frozen: this
+/// wallet's own `unfreeze` or a signer-management call. Everything else —
+/// transfers, foreign contracts, deploys, `upgrade`, `set_freeze_quorum` —
+/// is refused until the wallet is unfrozen.
+pub fn is_frozen_permitted_context(env: &Env, context: &Context) -> bool {
+    let Some(fn_name) = wallet_fn_name(env, context) else {
+        return false;
+    };
+
+    [
+        "unfreeze",
+        "add_signer",
+        "update_signer",
+        "remove_signer",
+        "apply_signer_changes",
+        "suspend_signer",
+        "resume_signer",
+        "revoke_sessions",
+    ]
+    .iter()
+    .any(|permitted| fn_name == Symbol::new(env, permitted))
+}
+
 /// True iff `contexts` is EXACTLY ONE context and it is `signer_key`'s own
 /// self-removal on this wallet. Used by pass 2 of `__check_auth` to let a
 /// policy signer self-remove without consulting its (possibly rejecting)
@@ -63,8 +215,8 @@ pub fn is_sole_self_removal(env: &Env, contexts: &Vec<Context>, signer_key: &Sig
 /// Expiration of the candidate itself is NOT checked here — every signatures
 /// map entry gets a single-point expiration check in pass 2 of
 /// `__check_auth`. Stored policy keys referenced inside limits ARE
-/// expiration-checked here (boolean), because they need not appear in the
-/// signatures map and would otherwise never be checked.
+/// expiration- and validity-start-checked here (boolean), because they need
+/// not appear in the signatures map and would otherwise never be checked.
 pub fn verify_context(
     env: &Env,
     context: &Context,
@@ -166,12 +318,18 @@ fn verify_signer_limit_keys(
     }
 
     // Phase 2: if a required policy is stored on this wallet it must be
-    // unexpired (it need not be in the signatures map, so pass 2 would not
+    // unexpired (session not revoked) and active — already valid, not
+    // suspended (it need not be in the signatures map, so pass 2 would not
     // otherwise check it). Still no policy code runs.
     for required_key in required_keys.iter() {
         if matches!(required_key, SignerKey::Policy(_)) {
-            if let Some((signer_val, _)) = get_signer_val_storage(env, &required_key, true) {
-                if is_signer_expired(env, signer_expiration(&signer_val)) {
+            if let Some((signer_val, signer_storage)) =
+                get_signer_val_storage(env, &required_key, true)
+            {
+                if is_signer_expired(env, signer_expiration(&signer_val))
+                    || is_session_revoked(env, &required_key, &signer_val, &signer_storage)
+                    || signer_inactive_error(env, &required_key, &signer_storage).is_some()
+                {
                     return false;
                 }
             }
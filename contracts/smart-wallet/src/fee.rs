@@ -0,0 +1,61 @@
+use smart_wallet_interface::types::{Error, FeeConfig, FeePayment};
+use soroban_sdk::{
+    auth::{Context, ContractContext},
+    symbol_short, token, Env, IntoVal, Symbol,
+};
+
+/// Instance storage key of the wallet's `FeeConfig`. Absent = no fees.
+const FEE_CONFIG: Symbol = symbol_short!("fee_cfg");
+
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage()
+        .instance()
+        .get::<Symbol, FeeConfig>(&FEE_CONFIG)
+}
+
+pub fn set_fee_config(env: &Env, config: &Option<FeeConfig>) {
+    match config {
+        Some(config) => env
+            .storage()
+            .instance()
+            .set::<Symbol, FeeConfig>(&FEE_CONFIG, config),
+        None => env.storage().instance().remove(&FEE_CONFIG),
+    }
+}
+
+/// Check `fee` against the wallet's `FeeConfig`.
+pub fn check_fee(env: &Env, fee: &FeePayment) -> Result<(), Error> {
+    let max_fee = get_fee_config(env)
+        .and_then(|config| config.max_fees.get(fee.token.clone()))
+        .ok_or(Error::FeeTokenNotAllowed)?;
+
+    if fee.amount < 0 || fee.amount > max_fee {
+        return Err(Error::FeeTooHigh);
+    }
+
+    Ok(())
+}
+
+/// The auth context of paying `fee`: the token `transfer` the wallet makes,
+/// as `__check_auth` would see it.
+pub fn fee_context(env: &Env, fee: &FeePayment) -> Context {
+    Context::Contract(ContractContext {
+        contract: fee.token.clone(),
+        fn_name: Symbol::new(env, "transfer"),
+        args: (
+            env.current_contract_address(),
+            fee.recipient.clone(),
+            fee.amount,
+        )
+            .into_val(env),
+    })
+}
+
+/// Transfer `fee` from the wallet to its recipient.
+pub fn pay_fee(env: &Env, fee: &FeePayment) {
+    token::TokenClient::new(env, &fee.token).transfer(
+        &env.current_contract_address(),
+        &fee.recipient,
+        &fee.amount,
+    );
+}
@@ -0,0 +1,75 @@
+use smart_wallet_interface::types::{InheritanceConfig, Signatures, SignerKey};
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+/// Instance storage key of the `InheritanceConfig` (`set_inheritance`).
+const INHERITANCE: Symbol = symbol_short!("inherit");
+
+/// Instance storage key of the inheritance clock: the timestamp of the last
+/// recorded owner activity.
+const OWNER_ACTIVITY: Symbol = symbol_short!("owner_act");
+
+pub fn get_inheritance(env: &Env) -> Option<InheritanceConfig> {
+    env.storage()
+        .instance()
+        .get::<Symbol, InheritanceConfig>(&INHERITANCE)
+}
+
+/// Store or clear the configuration. Setting one restarts the clock.
+pub fn set_inheritance(env: &Env, config: &Option<InheritanceConfig>) {
+    match config {
+        Some(config) => {
+            env.storage()
+                .instance()
+                .set::<Symbol, InheritanceConfig>(&INHERITANCE, config);
+
+            set_last_owner_activity(env);
+        }
+        None => env.storage().instance().remove(&INHERITANCE),
+    }
+}
+
+pub fn get_last_owner_activity(env: &Env) -> Option<u64> {
+    env.storage().instance().get::<Symbol, u64>(&OWNER_ACTIVITY)
+}
+
+fn set_last_owner_activity(env: &Env) {
+    env.storage()
+        .instance()
+        .set::<Symbol, u64>(&OWNER_ACTIVITY, &env.ledger().timestamp());
+}
+
+/// Whether `signer_key` is the configured beneficiary.
+pub fn is_beneficiary(env: &Env, signer_key: &SignerKey) -> bool {
+    get_inheritance(env).is_some_and(|config| config.beneficiary == *signer_key)
+}
+
+/// Whether `signer_key` is the configured beneficiary AND the owner has not
+/// yet been inactive for MORE than the configured period.
+pub fn is_beneficiary_dormant(env: &Env, signer_key: &SignerKey) -> bool {
+    match get_inheritance(env) {
+        Some(config) if config.beneficiary == *signer_key => {
+            let last_activity = get_last_owner_activity(env).unwrap_or(0);
+
+            env.ledger().timestamp().saturating_sub(last_activity) <= config.inactivity_period
+        }
+        _ => false,
+    }
+}
+
+/// Reset the clock after a successful authorization if any entry of the
+/// signatures map is owner activity: a non-policy signer other than the
+/// beneficiary. Policy entries carry no secret — anyone can submit them —
+/// so they never count, or anybody could keep the beneficiary dormant.
+pub fn record_owner_activity(env: &Env, signatures: &Signatures) {
+    let Some(config) = get_inheritance(env) else {
+        return;
+    };
+
+    for (signer_key, _) in signatures.0.iter() {
+        if signer_key != config.beneficiary && !matches!(signer_key, SignerKey::Policy(_)) {
+            set_last_owner_activity(env);
+
+            return;
+        }
+    }
+}
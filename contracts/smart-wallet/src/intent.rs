@@ -0,0 +1,75 @@
+use smart_wallet_interface::types::{Error, Intent};
+use soroban_sdk::{
+    auth::{Context, ContractContext},
+    crypto::Hash,
+    symbol_short,
+    xdr::ToXdr,
+    Env, Symbol,
+};
+
+use crate::storage::StorageKey;
+
+/// Domain tag of the intent digest preimage. The preimage is an `ScVal`
+/// vector, whose XDR starts with a different discriminant than the
+/// `HashIdPreimage` of a Soroban authorization payload, so an intent
+/// signature can never be replayed as an authorization and vice versa.
+const INTENT_DOMAIN: Symbol = symbol_short!("sw_intent");
+
+/// The digest signed for `intent`: SHA-256 over the XDR of
+/// `(INTENT_DOMAIN, network id, this wallet, intent)`. The intent carries its
+/// fee, recipient included, so the fee is signed along with the call.
+pub fn intent_hash(env: &Env, intent: &Intent) -> Hash<32> {
+    let preimage = (
+        INTENT_DOMAIN,
+        env.ledger().network_id(),
+        env.current_contract_address(),
+        intent.clone(),
+    )
+        .to_xdr(env);
+
+    env.crypto().sha256(&preimage)
+}
+
+/// The auth context of the intent's call, as `__check_auth` would see it.
+pub fn intent_context(intent: &Intent) -> Context {
+    Context::Contract(ContractContext {
+        contract: intent.contract.clone(),
+        fn_name: intent.fn_name.clone(),
+        args: intent.args.clone(),
+    })
+}
+
+pub fn is_intent_nonce_used(env: &Env, nonce: u64) -> bool {
+    env.storage()
+        .temporary()
+        .has::<StorageKey>(&StorageKey::IntentNonce(nonce))
+}
+
+/// Mark `nonce` consumed until `deadline`, failing with
+/// `Error::IntentNonceUsed` if it is, and `Error::IntentDeadlineTooFar` if
+/// the entry cannot live that long.
+///
+/// The entry is temporary: it only has to outlive the deadline, since
+/// `execute_intent` rejects the intent after that. Every ledger closes at
+/// least one second after the previous one, so a TTL of one ledger per
+/// second until the deadline always covers it.
+pub fn consume_intent_nonce(env: &Env, nonce: u64, deadline: u64) -> Result<(), Error> {
+    let ledgers = deadline.saturating_sub(env.ledger().timestamp());
+    if ledgers > env.storage().max_ttl() as u64 {
+        return Err(Error::IntentDeadlineTooFar);
+    }
+
+    if is_intent_nonce_used(env, nonce) {
+        return Err(Error::IntentNonceUsed);
+    }
+
+    let key = StorageKey::IntentNonce(nonce);
+    env.storage()
+        .temporary()
+        .set::<StorageKey, bool>(&key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl::<StorageKey>(&key, ledgers as u32, ledgers as u32);
+
+    Ok(())
+}
@@ -1,57 +1,107 @@
 #![no_std]
 
-use context::{is_sole_self_removal, verify_context};
+use activity::{get_last_active, get_signer_activity, record_signer_use, record_wallet_activity};
+use context::{
+    expand_execute_contexts, is_freeze_context, is_frozen_permitted_context, is_sole_self_removal,
+    is_web_auth_context, signature_context, verify_context,
+};
+use fee::{check_fee, fee_context, get_fee_config, pay_fee, set_fee_config};
+use inheritance::{
+    get_inheritance, get_last_owner_activity, is_beneficiary, record_owner_activity,
+    set_inheritance,
+};
+use intent::{consume_intent_nonce, intent_context, intent_hash, is_intent_nonce_used};
+use migration::{
+    migrate_schema_step, read_legacy_signer, schema_version, set_schema_version,
+    store_migrated_signer, LegacySigner, CURRENT_SCHEMA_VERSION,
+};
+use registry::{get_signer_registry, register_signer, set_signer_registry, unregister_signer};
 use signer::{
-    get_signer_val_storage, is_durable, is_durable_admin, is_signer_expired, process_signer,
-    signer_expiration, signer_limits, store_signer,
+    get_signer_options, get_signer_val_storage, is_durable, is_durable_admin, is_session_revoked,
+    is_signer_expired, is_signer_suspended, process_signer, remove_signer_entry,
+    session_generation, set_session_generation, set_signer_suspended, signer_expiration,
+    signer_inactive_error, signer_limits, store_signer,
 };
 use smart_wallet_interface::{
-    events::{SignerAdded, SignerRemoved, SignerUpdated, Upgraded},
-    types::{Error, Signature, Signatures, Signer, SignerKey, SignerStorage, SignerVal},
+    events::{
+        CountersReconciled, FeePaid, InheritanceUpdated, IntentCancelled, IntentExecuted,
+        SchemaMigrated, SessionsRevoked, SignerAdded, SignerRemoved, SignerResumed,
+        SignerSuspended, SignerUpdated, SubaccountCreated, UpgradeConfirmed, UpgradeRolledBack,
+        Upgraded, WalletFrozen, WalletUnfrozen,
+    },
+    types::{
+        Error, FeeConfig, InheritanceConfig, Intent, IntentFee, LedgerReference, PendingUpgrade,
+        Signature, Signatures, Signer, SignerActivity, SignerExpiration, SignerKey, SignerLimits,
+        SignerOp, SignerOptions, SignerStorage, SignerVal, SubaccountConfig, TtlConfig,
+        WalletSetting, WalletSettings, WebAuthConfig, FREEZE_TIMELOCK, UPGRADE_CONFIRM_WINDOW,
+    },
     PolicyClient, SmartWalletInterface,
 };
 use soroban_sdk::{
     auth::{Context, CustomAccountInterface},
     contract, contractimpl, contractmeta,
     crypto::Hash,
-    panic_with_error, symbol_short, BytesN, Env, Symbol, Vec,
+    panic_with_error, symbol_short, vec, Address, BytesN, Env, Executable, IntoVal, Symbol, Val,
+    Vec,
+};
+use storage::{extend_instance, extend_signer_key, get_ttl_config, set_ttl_config, TtlTrigger};
+use subaccount::{is_subaccount, record_subaccount};
+use upgrade::{
+    get_pending_upgrade, get_upgrade_guardian, set_pending_upgrade, set_upgrade_guardian,
 };
-use storage::extend_instance;
-use verify::verify_secp256r1_signature;
+use verify::{signature_hash, verify_secp256r1_signature};
+use web_auth::{get_web_auth_config, set_web_auth_config};
 
+mod activity;
 mod base64_url;
 mod context;
+mod fee;
+mod inheritance;
+mod intent;
+mod migration;
+mod registry;
+mod secp256r1;
 mod signer;
 mod storage;
+mod subaccount;
+mod upgrade;
 mod verify;
+mod web_auth;
 
 #[cfg(test)]
 mod tests;
 
 contractmeta!(key = "binver", val = env!("CARGO_PKG_VERSION"));
 
-/// Instance storage key caching the wasm hash installed by the most recent
-/// `upgrade`. Absent until the first upgrade (a contract cannot read its own
-/// executable hash from the host). Sourced for `Upgraded.old_hash`.
+/// Instance storage key caching the wasm hash the wallet runs: its genesis
+/// hash from `__constructor`, then the hash installed by each `upgrade`.
+/// Absent on a wallet deployed by older code until its first upgrade.
+/// Sourced for `Upgraded.old_hash` and the rollback target.
 const WASM_HASH: Symbol = symbol_short!("wasm_hash");
 
 /// Instance storage key counting the wallet's DURABLE ADMIN signers (see
 /// `is_durable_admin`: Persistent + non-expiring + independently
 /// admin-capable). Storage cannot be enumerated, so the count is maintained
-/// across `__constructor`/`add_signer`/`update_signer`/`remove_signer` and
+/// across every signer mutation (see `SignerCounts`) and
 /// backs the `Error::LastAdminSigner` guard: the count may never go from
 /// nonzero to zero, because with zero admin-capable signers no
 /// `add_signer`/`upgrade` could ever be authorized again, and the contract
 /// code is immutable.
+/// An admin whose `SignerOptions::valid_after` lies in the future is counted:
+/// it becomes usable by the passage of time alone, so it can never leave the
+/// wallet permanently locked. A SUSPENDED admin is not: only another admin
+/// could resume it. Nor is the inheritance beneficiary: any owner activity
+/// keeps it dormant.
 ///
 /// Legacy caveat: a wallet upgraded from a pre-1.0 wasm starts at 0 and the
-/// counter PERMANENTLY undercounts its pre-existing admins — signers already
-/// in storage are never learned (no enumeration), so the count only tracks
-/// admins added or promoted after the upgrade. Strictly fail-SAFE: an
+/// counter undercounts its pre-existing admins — signers already in storage
+/// are never learned (no enumeration), so the count only tracks admins added
+/// or promoted after the upgrade. Strictly fail-SAFE: an
 /// undercount can only make the guard refuse removals/demotions that would
 /// actually be safe (pre-existing admins stay effectively pinned unless a
 /// post-upgrade admin is counted); it can never permit the nonzero→zero
-/// transition.
+/// transition. `reconcile_counters` lets the owner close the gap by naming
+/// the pre-existing signers.
 const ADMIN_COUNT: Symbol = symbol_short!("admins");
 
 /// Instance storage key counting the wallet's DURABLE signers (`is_durable`:
@@ -64,20 +114,125 @@ const ADMIN_COUNT: Symbol = symbol_short!("admins");
 ///
 /// EXACT by construction (drift-free): durable entries enter and leave
 /// storage only through counter-tracked calls (`__constructor`/`add_signer`/
-/// `update_signer`/`remove_signer`). Non-durable signers are deliberately
-/// NOT counted — Temporary entries evict and expiring entries lapse with no
-/// contract call the counter could observe, so including them would inflate
-/// the count above the live-signer set and let a "guarded" removal reach
-/// zero live signers (the exact drift this design eliminates).
+/// `update_signer`/`remove_signer`/`apply_signer_changes`). Non-durable
+/// signers are deliberately NOT counted — Temporary entries evict and
+/// expiring entries lapse with no contract call the counter could observe, so
+/// including them would inflate the count above the live-signer set and let a
+/// "guarded" removal reach zero live signers (the exact drift this design
+/// eliminates).
 ///
 /// This is the classification-independent backstop beneath the richer
 /// `ADMIN_COUNT` guard: even if some admin-capable limits shape is
 /// mis-classified there, at least one durable signer always survives.
 /// Legacy caveat: a pre-1.0 wallet upgraded to this wasm starts at 0 and
-/// permanently undercounts pre-existing durable signers (no enumeration
-/// exists) — strictly fail-safe: over-refuses, never over-allows.
+/// undercounts pre-existing durable signers (no enumeration exists) until
+/// they are named to `reconcile_counters` — strictly fail-safe: over-refuses,
+/// never over-allows.
 const DURABLE_COUNT: Symbol = symbol_short!("durable");
 
+/// Instance storage key of the ledger timestamp the wallet was frozen at
+/// (`freeze`/`unfreeze`). Present only while frozen.
+const FROZEN: Symbol = symbol_short!("frozen");
+
+/// Instance storage key of the configured freeze quorum
+/// (`set_freeze_quorum`); `DEFAULT_FREEZE_QUORUM` while unset.
+const FREEZE_QUORUM: Symbol = symbol_short!("frzquorum");
+
+const DEFAULT_FREEZE_QUORUM: u32 = 2;
+
+/// Running durable / durable-admin counts across one signer mutation or a
+/// batch of them (`apply_signer_changes`). Every step records its transition
+/// with `track`; the `LastAdminSigner`/`LastSigner` guards run once, in
+/// `commit`, against the FINAL counts: a count that was decremented at any
+/// step must end above zero. Intermediate steps may pass through zero, so
+/// "remove the old admin, add the new one" is as valid a batch as the
+/// reverse.
+///
+/// Counts are signed so a legacy undercount (see `ADMIN_COUNT`) can dip
+/// below zero mid-batch instead of underflowing. For a single step "ends at
+/// or below zero after a decrement" is exactly the historical `count <= 1`
+/// check, and it stays fail-safe for batches: an undercounted wallet can
+/// only be refused a transition that was actually safe.
+struct SignerCounts {
+    admin: i64,
+    durable: i64,
+    admin_decremented: bool,
+    durable_decremented: bool,
+}
+
+impl SignerCounts {
+    fn load(env: &Env) -> Self {
+        Self {
+            admin: Contract::admin_count(env) as i64,
+            durable: Contract::durable_count(env) as i64,
+            admin_decremented: false,
+            durable_decremented: false,
+        }
+    }
+
+    /// Record one signer's transition from `old` to `new` (`None` = not
+    /// stored; the flag is `Contract::is_admin_excluded`): an add, update,
+    /// remove, suspend, resume or beneficiary change. An excluded signer is
+    /// never an admin, but exclusion leaves durability alone — the entry
+    /// cannot silently disappear either way.
+    fn track(
+        &mut self,
+        env: &Env,
+        old: Option<(&SignerVal, &SignerStorage, bool)>,
+        new: Option<(&SignerVal, &SignerStorage, bool)>,
+    ) {
+        let was_admin = old.is_some_and(|(val, storage, excluded)| {
+            !excluded && is_durable_admin(env, val, storage)
+        });
+        let is_admin = new.is_some_and(|(val, storage, excluded)| {
+            !excluded && is_durable_admin(env, val, storage)
+        });
+        if was_admin && !is_admin {
+            self.admin -= 1;
+            self.admin_decremented = true;
+        } else if !was_admin && is_admin {
+            self.admin += 1;
+        }
+
+        let was_durable = old.is_some_and(|(val, storage, _)| is_durable(val, storage));
+        let now_durable = new.is_some_and(|(val, storage, _)| is_durable(val, storage));
+        if was_durable && !now_durable {
+            self.durable -= 1;
+            self.durable_decremented = true;
+        } else if !was_durable && now_durable {
+            self.durable += 1;
+        }
+    }
+
+    /// Check the guards against the final counts and persist them. The admin
+    /// guard is checked first, so a transition that trips both reports
+    /// `LastAdminSigner`.
+    fn commit(&self, env: &Env) -> Result<(), Error> {
+        // Never take the wallet's durable admins to zero: from zero
+        // admin-capable signers no add_signer/upgrade can ever be authorized
+        // again, and the contract code is immutable.
+        if self.admin_decremented && self.admin <= 0 {
+            return Err(Error::LastAdminSigner);
+        }
+
+        // Terminal backstop, beneath (and independent of) the admin
+        // classification: never take the wallet's durable signers to zero.
+        // A wallet is born with a durable signer and every durable 1→0
+        // transition is rejected here, so at least one signer that cannot
+        // silently evict or expire always exists.
+        if self.durable_decremented && self.durable <= 0 {
+            return Err(Error::LastSigner);
+        }
+
+        // Undecremented counts only ever grew from a u32, so both are in
+        // range here.
+        Contract::set_admin_count(env, self.admin as u32);
+        Contract::set_durable_count(env, self.durable as u32);
+
+        Ok(())
+    }
+}
+
 #[contract]
 pub struct Contract;
 
@@ -108,161 +263,298 @@ impl Contract {
             .set::<Symbol, u32>(&DURABLE_COUNT, &count);
     }
 
-    fn add_signer_impl(env: &Env, signer: Signer) -> Result<(), Error> {
-        let (signer_key, signer_val, signer_storage) = process_signer(signer);
+    fn frozen(env: &Env) -> bool {
+        env.storage().instance().has(&FROZEN)
+    }
 
-        store_signer(env, &signer_key, &signer_val, &signer_storage, false)?;
+    fn freeze_quorum(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get::<Symbol, u32>(&FREEZE_QUORUM)
+            .unwrap_or(DEFAULT_FREEZE_QUORUM)
+    }
 
-        if is_durable(&signer_val, &signer_storage) {
-            Self::set_durable_count(env, Self::durable_count(env) + 1);
+    /// Frozen-mode gate of `__check_auth`: every context must be permitted
+    /// while frozen, and the signatures map must hold at least the effective
+    /// quorum of active durable admins. A wallet with fewer admins than its
+    /// quorum needs all of them (never fewer than one; a legacy undercount
+    /// only lowers this to what the counter knows), and only once
+    /// `FREEZE_TIMELOCK` has passed since the freeze — otherwise a
+    /// single-admin wallet would unfreeze with the very key it froze
+    /// against.
+    fn check_frozen(
+        env: &Env,
+        signatures: &Signatures,
+        auth_contexts: &Vec<Context>,
+    ) -> Result<(), Error> {
+        for context in auth_contexts.iter() {
+            if !is_frozen_permitted_context(env, &context) {
+                return Err(Error::WalletFrozen);
+            }
         }
 
-        if is_durable_admin(env, &signer_val, &signer_storage) {
-            Self::set_admin_count(env, Self::admin_count(env) + 1);
+        let admin_count = Self::admin_count(env);
+        let mut quorum = Self::freeze_quorum(env);
+        if admin_count < quorum {
+            let frozen_at = env
+                .storage()
+                .instance()
+                .get::<Symbol, u64>(&FROZEN)
+                .unwrap_or_default();
+            if env.ledger().timestamp() < frozen_at.saturating_add(FREEZE_TIMELOCK) {
+                return Err(Error::FreezeTimelockActive);
+            }
+            quorum = admin_count.max(1);
         }
 
-        // Policy signers get their install hook invoked (the policy sees the
-        // wallet as its authenticated invoker). A failing install aborts the
-        // add — policies must opt in to being attached.
-        if let SignerKey::Policy(policy) = &signer_key {
-            PolicyClient::new(env, policy).install(&env.current_contract_address());
+        let mut admins = 0;
+        for (signer_key, _) in signatures.0.iter() {
+            if let Some((signer_val, signer_storage)) =
+                get_signer_val_storage(env, &signer_key, false)
+            {
+                if is_durable_admin(env, &signer_val, &signer_storage)
+                    && signer_inactive_error(env, &signer_key, &signer_storage).is_none()
+                {
+                    admins += 1;
+                }
+            }
         }
 
-        extend_instance(env);
-
-        SignerAdded {
-            key: signer_key,
-            val: signer_val,
-            storage: signer_storage,
+        if admins < quorum {
+            return Err(Error::FreezeQuorumNotMet);
         }
-        .publish(env);
 
         Ok(())
     }
-}
 
-#[contractimpl]
-impl SmartWalletInterface for Contract {
-    fn __constructor(env: Env, signer: Signer) {
-        // Deploy-time-only initialization (CAP-0058 constructor). There is no
-        // init flag and no un-authenticated first-add path.
-        if let Err(error) = Self::add_signer_impl(&env, signer) {
-            panic_with_error!(env, error);
-        }
+    /// Whether a stored signer is excluded from the durable admin count
+    /// whatever its shape: suspended, or the inheritance beneficiary.
+    fn is_admin_excluded(
+        env: &Env,
+        signer_key: &SignerKey,
+        signer_storage: &SignerStorage,
+    ) -> bool {
+        is_signer_suspended(env, signer_key, signer_storage) || is_beneficiary(env, signer_key)
+    }
 
-        // A wallet born without a durable (Persistent + non-expiring) signer
-        // could reach zero live signers with no contract call — a Temporary
-        // first signer evicts, an expiring one lapses — and nothing on-chain
-        // can observe or prevent that. The first signer must be durable.
-        if Self::durable_count(&env) == 0 {
-            panic_with_error!(env, Error::LastSigner);
+    fn set_freeze_quorum_impl(env: &Env, quorum: u32) -> Result<(), Error> {
+        if quorum == 0 {
+            return Err(Error::InvalidFreezeQuorum);
         }
+
+        env.storage()
+            .instance()
+            .set::<Symbol, u32>(&FREEZE_QUORUM, &quorum);
+
+        Ok(())
     }
 
-    fn add_signer(env: Env, signer: Signer) -> Result<(), Error> {
-        env.current_contract_address().require_auth();
+    fn set_ttl_config_impl(env: &Env, config: Option<TtlConfig>) -> Result<(), Error> {
+        if let Some(config) = &config {
+            for schedule in [&config.auth, &config.mutation] {
+                for rule in [
+                    &schedule.instance,
+                    &schedule.persistent,
+                    &schedule.temporary,
+                ] {
+                    if rule.threshold > rule.extend_to {
+                        return Err(Error::InvalidTtlConfig);
+                    }
+                }
+            }
+        }
 
-        Self::add_signer_impl(&env, signer)
+        set_ttl_config(env, &config);
+
+        Ok(())
     }
 
-    fn update_signer(env: Env, signer: Signer) -> Result<(), Error> {
-        env.current_contract_address().require_auth();
+    fn set_inheritance_impl(env: &Env, config: Option<InheritanceConfig>) -> Result<(), Error> {
+        if config
+            .as_ref()
+            .is_some_and(|config| config.inactivity_period == 0)
+        {
+            return Err(Error::InvalidInactivityPeriod);
+        }
+
+        // The outgoing beneficiary counts as an admin again and the incoming
+        // one stops counting; the guards see both transitions at once.
+        let mut counts = SignerCounts::load(env);
 
-        let (signer_key, signer_val, signer_storage) = process_signer(signer);
+        if let Some(previous) = get_inheritance(env) {
+            if let Some((signer_val, signer_storage)) =
+                get_signer_val_storage(env, &previous.beneficiary, false)
+            {
+                let suspended = is_signer_suspended(env, &previous.beneficiary, &signer_storage);
+                counts.track(
+                    env,
+                    Some((&signer_val, &signer_storage, true)),
+                    Some((&signer_val, &signer_storage, suspended)),
+                );
+            }
+        }
 
-        let (old_val, old_storage) =
-            store_signer(&env, &signer_key, &signer_val, &signer_storage, true)?
-                .ok_or(Error::SignerNotFound)?;
+        if let Some(config) = &config {
+            let (signer_val, signer_storage) =
+                get_signer_val_storage(env, &config.beneficiary, false)
+                    .ok_or(Error::SignerNotFound)?;
+            let suspended = is_signer_suspended(env, &config.beneficiary, &signer_storage);
+            counts.track(
+                env,
+                Some((&signer_val, &signer_storage, suspended)),
+                Some((&signer_val, &signer_storage, true)),
+            );
+        }
 
-        // Durable-admin accounting. Demoting the LAST durable admin (limiting
-        // it, adding an expiration, or moving it to Temporary) closes the
-        // wallet's admin surface exactly like removing it — reject.
-        let was_admin = is_durable_admin(&env, &old_val, &old_storage);
-        let is_admin = is_durable_admin(&env, &signer_val, &signer_storage);
-        if was_admin && !is_admin {
-            let count = Self::admin_count(&env);
-            if count <= 1 {
-                return Err(Error::LastAdminSigner);
+        counts.commit(env)?;
+
+        set_inheritance(env, &config);
+
+        InheritanceUpdated { config }.publish(env);
+
+        Ok(())
+    }
+
+    fn set_fee_config_impl(env: &Env, config: Option<FeeConfig>) -> Result<(), Error> {
+        if let Some(config) = &config {
+            for (_, max_fee) in config.max_fees.iter() {
+                if max_fee < 0 {
+                    return Err(Error::InvalidFeeConfig);
+                }
             }
-            Self::set_admin_count(&env, count - 1);
-        } else if !was_admin && is_admin {
-            Self::set_admin_count(&env, Self::admin_count(&env) + 1);
         }
 
-        // Durable accounting (the LastSigner backstop). Demoting the LAST
-        // durable signer to Temporary or to an expiring value would let it
-        // later evict/lapse to zero live signers with no call to guard —
-        // reject the 1→0 transition here, at the only moment it is visible.
-        let was_durable = is_durable(&old_val, &old_storage);
-        let now_durable = is_durable(&signer_val, &signer_storage);
-        if was_durable && !now_durable {
-            let count = Self::durable_count(&env);
-            if count <= 1 {
-                return Err(Error::LastSigner);
+        set_fee_config(env, &config);
+
+        Ok(())
+    }
+
+    /// `__constructor`'s settings besides the signer registry, each through
+    /// its setter's validation. Runs after the initial signers are added, so
+    /// the inheritance beneficiary can be one of them.
+    fn apply_settings(env: &Env, settings: Vec<WalletSetting>) -> Result<(), Error> {
+        for setting in settings.iter() {
+            match setting {
+                WalletSetting::FreezeQuorum(quorum) => Self::set_freeze_quorum_impl(env, quorum)?,
+                WalletSetting::TtlConfig(config) => Self::set_ttl_config_impl(env, Some(config))?,
+                WalletSetting::Inheritance(config) => {
+                    Self::set_inheritance_impl(env, Some(config))?
+                }
+                WalletSetting::FeeConfig(config) => Self::set_fee_config_impl(env, Some(config))?,
+                WalletSetting::SignerRegistry(_) => {}
+                WalletSetting::WebAuthConfig(config) => set_web_auth_config(env, &Some(config)),
             }
-            Self::set_durable_count(&env, count - 1);
-        } else if !was_durable && now_durable {
-            Self::set_durable_count(&env, Self::durable_count(&env) + 1);
         }
 
-        extend_instance(&env);
+        Ok(())
+    }
+
+    fn add_signer_impl(env: &Env, signer: Signer, counts: &mut SignerCounts) -> Result<(), Error> {
+        let (signer_key, signer_val, signer_storage, signer_options) = process_signer(env, signer)?;
+
+        store_signer(
+            env,
+            &signer_key,
+            &signer_val,
+            &signer_storage,
+            &signer_options,
+            false,
+        )?;
+
+        // A fresh entry is never suspended, but its key may already be named
+        // as the beneficiary.
+        counts.track(
+            env,
+            None,
+            Some((
+                &signer_val,
+                &signer_storage,
+                is_beneficiary(env, &signer_key),
+            )),
+        );
+
+        // Policy signers get their install hook invoked (the policy sees the
+        // wallet as its authenticated invoker). A failing install aborts the
+        // add — policies must opt in to being attached.
+        if let SignerKey::Policy(policy) = &signer_key {
+            PolicyClient::new(env, policy).install(&env.current_contract_address());
+        }
+
+        register_signer(env, &signer_key);
+
+        SignerAdded {
+            key: signer_key,
+            val: signer_val,
+            storage: signer_storage,
+            options: signer_options,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    fn update_signer_impl(
+        env: &Env,
+        signer: Signer,
+        counts: &mut SignerCounts,
+    ) -> Result<(), Error> {
+        let (signer_key, signer_val, signer_storage, signer_options) = process_signer(env, signer)?;
+
+        let (old_val, old_storage) = store_signer(
+            env,
+            &signer_key,
+            &signer_val,
+            &signer_storage,
+            &signer_options,
+            true,
+        )?
+        .ok_or(Error::SignerNotFound)?;
+
+        // Demoting a durable (admin) signer — limiting it, adding an
+        // expiration, or moving it to Temporary — closes the wallet's surface
+        // exactly like removing it, so it is counted (and guarded) the same.
+        // The suspension flag survives the update (`store_signer`).
+        let excluded = Self::is_admin_excluded(env, &signer_key, &signer_storage);
+        counts.track(
+            env,
+            Some((&old_val, &old_storage, excluded)),
+            Some((&signer_val, &signer_storage, excluded)),
+        );
 
         SignerUpdated {
             key: signer_key,
             val: signer_val,
             storage: signer_storage,
             old_storage,
+            options: signer_options,
         }
-        .publish(&env);
+        .publish(env);
 
         Ok(())
     }
 
-    fn remove_signer(env: Env, signer_key: SignerKey) -> Result<(), Error> {
-        env.current_contract_address().require_auth();
-
+    fn remove_signer_impl(
+        env: &Env,
+        signer_key: SignerKey,
+        counts: &mut SignerCounts,
+    ) -> Result<(), Error> {
         let (signer_val, signer_storage) =
-            get_signer_val_storage(&env, &signer_key, false).ok_or(Error::SignerNotFound)?;
-
-        // Never remove the wallet's LAST durable admin signer:
-        // from zero unlimited signers no add_signer/upgrade can ever be
-        // authorized again, and the contract code is immutable.
-        // This guard runs at execution time, so it also covers the pass-1
-        // self-removal special case: a sole admin can AUTHORIZE its own
-        // removal, but the removal itself still fails here.
-        if is_durable_admin(&env, &signer_val, &signer_storage) {
-            let count = Self::admin_count(&env);
-            if count <= 1 {
-                return Err(Error::LastAdminSigner);
-            }
-            Self::set_admin_count(&env, count - 1);
-        }
+            get_signer_val_storage(env, &signer_key, false).ok_or(Error::SignerNotFound)?;
 
-        // Terminal backstop, beneath (and independent of) the admin
-        // classification above: NEVER remove the wallet's last DURABLE
-        // signer. Since a wallet is born with a durable signer and every
-        // durable 1→0 transition (remove here, demote in update_signer) is
-        // rejected, at least one signer that cannot silently evict or expire
-        // always exists — a zero-live-signer wallet is unreachable. The
-        // counter deliberately ignores non-durable signers: they can vanish
-        // with no contract call, and counting them is exactly what would let
-        // this guard drift open.
-        if is_durable(&signer_val, &signer_storage) {
-            let count = Self::durable_count(&env);
-            if count <= 1 {
-                return Err(Error::LastSigner);
-            }
-            Self::set_durable_count(&env, count - 1);
-        }
+        // The last-admin/last-signer guards run at execution time (in
+        // `SignerCounts::commit`), so they also cover the pass-1 self-removal
+        // special case: a sole admin can AUTHORIZE its own removal, but the
+        // removal itself still fails.
+        let excluded = Self::is_admin_excluded(env, &signer_key, &signer_storage);
+        counts.track(env, Some((&signer_val, &signer_storage, excluded)), None);
 
-        match &signer_storage {
-            SignerStorage::Persistent => {
-                env.storage().persistent().remove::<SignerKey>(&signer_key);
-            }
-            SignerStorage::Temporary => {
-                env.storage().temporary().remove::<SignerKey>(&signer_key);
-            }
-        }
+        remove_signer_entry(env, &signer_key, &signer_storage);
+
+        // Best-effort: a registry error is ignored. Unlike a policy, the
+        // registry is chosen by the owner (and detachable), so the
+        // non-recoverable failure mode described below is the owner's to
+        // avoid by picking a registry with bounded costs.
+        unregister_signer(env, &signer_key);
 
         // Removal is pure wallet state — NO policy code runs on this
         // path. Calling the policy's `uninstall` here
@@ -273,60 +565,104 @@ impl SmartWalletInterface for Contract {
         // transaction and rolls the removal back. Policies instead self-clean
         // their install-state via the permissionless `uninstall` entrypoint,
         // which verifies the signer is actually gone before acting.
-        extend_instance(&env);
-
         SignerRemoved {
             key: signer_key,
             storage: signer_storage,
         }
-        .publish(&env);
+        .publish(env);
 
         Ok(())
     }
 
-    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
-        env.current_contract_address().require_auth();
+    /// Shared body of `suspend_signer`/`resume_signer`. Setting the flag it
+    /// already has is a no-op with no event.
+    fn set_suspended_impl(env: &Env, signer_key: SignerKey, suspended: bool) -> Result<(), Error> {
+        let (signer_val, signer_storage) =
+            get_signer_val_storage(env, &signer_key, false).ok_or(Error::SignerNotFound)?;
 
-        let old_hash = env
-            .storage()
-            .instance()
-            .get::<Symbol, BytesN<32>>(&WASM_HASH);
+        if is_signer_suspended(env, &signer_key, &signer_storage) == suspended {
+            return Ok(());
+        }
 
-        env.deployer()
-            .update_current_contract_wasm(new_wasm_hash.clone());
+        // Suspending the last durable admin is guarded like removing it.
+        let beneficiary = is_beneficiary(env, &signer_key);
+        let mut counts = SignerCounts::load(env);
+        counts.track(
+            env,
+            Some((&signer_val, &signer_storage, beneficiary || !suspended)),
+            Some((&signer_val, &signer_storage, beneficiary || suspended)),
+        );
+        counts.commit(env)?;
 
-        env.storage()
-            .instance()
-            .set::<Symbol, BytesN<32>>(&WASM_HASH, &new_wasm_hash);
+        set_signer_suspended(env, &signer_key, &signer_storage, suspended);
 
-        extend_instance(&env);
+        extend_signer_key(
+            env,
+            &signer_key,
+            matches!(signer_storage, SignerStorage::Persistent),
+            TtlTrigger::Mutation,
+        );
+        extend_instance(env, TtlTrigger::Mutation);
 
-        Upgraded {
-            old_hash,
-            new_hash: new_wasm_hash,
+        if suspended {
+            SignerSuspended { key: signer_key }.publish(env);
+        } else {
+            SignerResumed { key: signer_key }.publish(env);
         }
-        .publish(&env);
 
         Ok(())
     }
 
-    fn get_signer(env: Env, signer_key: SignerKey) -> Option<SignerVal> {
-        get_signer_val_storage(&env, &signer_key, false).map(|(signer_val, _)| signer_val)
+    fn apply_signer_op(env: &Env, op: SignerOp, counts: &mut SignerCounts) -> Result<(), Error> {
+        match op {
+            SignerOp::Add(signer) => Self::add_signer_impl(env, signer, counts),
+            SignerOp::Update(signer) => Self::update_signer_impl(env, signer, counts),
+            SignerOp::Remove(signer_key) => Self::remove_signer_impl(env, signer_key, counts),
+        }
     }
-}
 
-#[contractimpl]
-impl CustomAccountInterface for Contract {
-    type Error = Error;
-    type Signature = Signatures;
+    /// Apply `ops` in order, then check the counter guards against the final
+    /// state. An `Err` rolls back every storage write and event of the
+    /// invocation, so a failing batch leaves no partial state behind.
+    fn apply_signer_ops(env: &Env, ops: Vec<SignerOp>) -> Result<(), Error> {
+        let mut counts = SignerCounts::load(env);
 
-    #[allow(non_snake_case)]
-    fn __check_auth(
-        env: Env,
-        signature_payload: Hash<32>,
-        signatures: Signatures,
-        auth_contexts: Vec<Context>,
+        for op in ops.iter() {
+            Self::apply_signer_op(env, op, &mut counts)?;
+        }
+
+        counts.commit(env)?;
+
+        extend_instance(env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    /// The whole of `__check_auth` against an arbitrary payload: the frozen
+    /// gate, pass 1 (context coverage) and pass 2 (every signatures map
+    /// entry), then the usage and activity records. Shared with
+    /// `execute_intent`, whose payload is an intent digest rather than a
+    /// host-built authorization payload, and with `is_valid_signature`,
+    /// which passes `record: false` so a check writes no records or TTLs.
+    fn authenticate(
+        env: &Env,
+        signature_payload: &BytesN<32>,
+        signatures: &Signatures,
+        auth_contexts: &Vec<Context>,
+        record: bool,
     ) -> Result<(), Error> {
+        // `execute(calls)` is authorized as its calls: everything below sees
+        // one context per call instead of the batch itself.
+        let auth_contexts = expand_execute_contexts(env, auth_contexts)?;
+
+        // Frozen mode: refuse everything but unfreezing and signer
+        // management, and require the freeze quorum of durable admins for
+        // those. Runs first so a frozen wallet invokes no policy code for a
+        // refused authorization; passes 1 and 2 still apply in full.
+        if Self::frozen(env) {
+            Self::check_frozen(env, signatures, &auth_contexts)?;
+        }
+
         // Pass 1 — context coverage. Every context must be authorizable by at
         // least one signer in the signatures map. `verify_context` rejects a
         // candidate boolean-ly for every RECOVERABLE failure (wrong shape,
@@ -340,19 +676,47 @@ impl CustomAccountInterface for Contract {
         // every other candidate check has passed (see
         // `verify_signer_limit_keys`). Expiration is deliberately NOT checked
         // here — pass 2 is the single point of truth and fails the whole
-        // auth if ANY map entry is expired.
+        // auth if ANY map entry is expired. An inactive candidate — not yet
+        // valid (`SignerOptions::valid_after`), suspended, or a dormant
+        // beneficiary — is skipped: it must not cover anything, not even its
+        // own removal, while inactive (pass 2 rejects it too). An active
+        // `SignerOptions::can_freeze` signer covers the wallet's own `freeze`
+        // and an active `can_web_auth` signer a SEP-45 login naming the
+        // wallet on a trusted web-auth contract (`WebAuthConfig`), whatever
+        // their limits; an activated beneficiary covers anything.
         for context in auth_contexts.iter() {
             let mut covered = false;
 
             for (signer_key, _) in signatures.0.iter() {
-                if let Some((signer_val, _)) = get_signer_val_storage(&env, &signer_key, false) {
-                    if verify_context(
-                        &env,
-                        &context,
-                        &signer_key,
-                        signer_limits(&signer_val),
-                        &signatures,
-                    ) {
+                if let Some((signer_val, signer_storage)) =
+                    get_signer_val_storage(env, &signer_key, false)
+                {
+                    if signer_inactive_error(env, &signer_key, &signer_storage).is_some() {
+                        continue;
+                    }
+
+                    if is_freeze_context(env, &context)
+                        && get_signer_options(env, &signer_key, &signer_storage).can_freeze
+                    {
+                        covered = true;
+                        break;
+                    }
+
+                    if is_web_auth_context(env, &context)
+                        && get_signer_options(env, &signer_key, &signer_storage).can_web_auth
+                    {
+                        covered = true;
+                        break;
+                    }
+
+                    let unlimited = SignerLimits(None);
+                    let limits = if is_beneficiary(env, &signer_key) {
+                        &unlimited
+                    } else {
+                        signer_limits(&signer_val)
+                    };
+
+                    if verify_context(env, &context, &signer_key, limits, signatures) {
                         covered = true;
                         break;
                     }
@@ -365,18 +729,27 @@ impl CustomAccountInterface for Contract {
         }
 
         // Pass 2 — verify EVERY signatures map entry: it must be stored on
-        // this wallet, unexpired, and its signature material must verify.
+        // this wallet, unexpired, active (already valid, not suspended), and
+        // its signature material must verify. Each verified entry's usage
+        // record is written together with its TTL bump.
         // Include only signatures that are needed; an invalid or expired
         // extra entry fails the entire authorization (deterministically,
         // regardless of map order).
         for (signer_key, signature) in signatures.0.iter() {
-            let (signer_val, _) =
-                get_signer_val_storage(&env, &signer_key, true).ok_or(Error::SignerNotFound)?;
+            let (signer_val, signer_storage) =
+                get_signer_val_storage(env, &signer_key, false).ok_or(Error::SignerNotFound)?;
 
-            if is_signer_expired(&env, signer_expiration(&signer_val)) {
+            // A revoked session (`revoke_sessions`) is expired.
+            if is_signer_expired(env, signer_expiration(&signer_val))
+                || is_session_revoked(env, &signer_key, &signer_val, &signer_storage)
+            {
                 return Err(Error::SignerExpired);
             }
 
+            if let Some(error) = signer_inactive_error(env, &signer_key, &signer_storage) {
+                return Err(error);
+            }
+
             match signature {
                 Signature::Policy => {
                     if let SignerKey::Policy(policy) = &signer_key {
@@ -392,12 +765,12 @@ impl CustomAccountInterface for Contract {
                         // escalation — it mirrors pass 1's "a signer may
                         // always remove itself" rule. Any additional context
                         // disables the skip.
-                        if !is_sole_self_removal(&env, &auth_contexts, &signer_key) {
+                        if !is_sole_self_removal(env, &auth_contexts, &signer_key) {
                             // Policy-as-signature sees the FULL context list
                             // (a policy used inside another signer's limits
                             // sees one context at a time). A rejecting policy
                             // fails the whole authorization.
-                            PolicyClient::new(&env, policy).policy__(
+                            PolicyClient::new(env, policy).policy__(
                                 &env.current_contract_address(),
                                 &signer_key,
                                 &auth_contexts,
@@ -420,21 +793,758 @@ impl CustomAccountInterface for Contract {
                 }
                 Signature::Secp256r1(signature) => {
                     if let SignerVal::Secp256r1(public_key, _, _) = &signer_val {
-                        verify_secp256r1_signature(
-                            &env,
-                            &signature_payload,
-                            public_key,
-                            signature,
-                        )?;
+                        verify_secp256r1_signature(env, signature_payload, public_key, signature)?;
+                    } else {
+                        return Err(Error::SignatureKeyValueMismatch);
+                    }
+                }
+                Signature::Address => {
+                    if let SignerKey::Address(address) = &signer_key {
+                        // The address authorizes this frame for the payload
+                        // in its own authorization entry (a contract account
+                        // runs its own `__check_auth`); a missing or
+                        // rejected authorization traps.
+                        address.require_auth_for_args(vec![env, signature_payload.into_val(env)]);
                     } else {
                         return Err(Error::SignatureKeyValueMismatch);
                     }
                 }
             }
+
+            if record {
+                record_signer_use(env, &signer_key, &signer_storage);
+            }
         }
 
-        extend_instance(&env);
+        if record {
+            // Fully verified: reset the inheritance clock if the owner signed.
+            record_owner_activity(env, signatures);
+            record_wallet_activity(env);
+
+            extend_instance(env, TtlTrigger::Auth);
+        }
 
         Ok(())
     }
 }
+
+#[contractimpl]
+impl SmartWalletInterface for Contract {
+    fn __constructor(env: Env, signers: Vec<Signer>, settings: Option<WalletSettings>) {
+        let settings = settings.map_or(Vec::new(&env), |settings| settings.0);
+
+        // Set first, so the initial signers are reported to the registry.
+        for setting in settings.iter() {
+            if let WalletSetting::SignerRegistry(registry) = setting {
+                set_signer_registry(&env, &Some(registry));
+            }
+        }
+
+        // Deploy-time-only initialization (CAP-0058 constructor). There is no
+        // init flag and no un-authenticated first-add path. The initial set
+        // goes through the same path as an `apply_signer_changes` batch of
+        // adds: each signer is validated, stored, emits `SignerAdded` and (for
+        // policies) runs its `install` hook; a duplicate key fails with
+        // `SignerAlreadyExists`.
+        let mut ops = Vec::new(&env);
+        for signer in signers.iter() {
+            ops.push_back(SignerOp::Add(signer));
+        }
+
+        if let Err(error) = Self::apply_signer_ops(&env, ops) {
+            panic_with_error!(env, error);
+        }
+
+        // A wallet born without a durable (Persistent + non-expiring) signer
+        // could reach zero live signers with no contract call — Temporary
+        // signers evict, expiring ones lapse — and nothing on-chain can
+        // observe or prevent that. The initial set (which may not be empty)
+        // must contain at least one durable signer.
+        if Self::durable_count(&env) == 0 {
+            panic_with_error!(env, Error::LastSigner);
+        }
+
+        if let Err(error) = Self::apply_settings(&env, settings) {
+            panic_with_error!(env, error);
+        }
+
+        set_schema_version(&env, CURRENT_SCHEMA_VERSION);
+
+        // The genesis hash: the first upgrade's rollback target.
+        if let Some(Executable::Wasm(wasm_hash)) = env.current_contract_address().executable() {
+            env.storage()
+                .instance()
+                .set::<Symbol, BytesN<32>>(&WASM_HASH, &wasm_hash);
+        }
+    }
+
+    fn add_signer(env: Env, signer: Signer) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::apply_signer_ops(&env, vec![&env, SignerOp::Add(signer)])
+    }
+
+    fn update_signer(env: Env, signer: Signer) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::apply_signer_ops(&env, vec![&env, SignerOp::Update(signer)])
+    }
+
+    fn remove_signer(env: Env, signer_key: SignerKey) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::apply_signer_ops(&env, vec![&env, SignerOp::Remove(signer_key)])
+    }
+
+    fn apply_signer_changes(env: Env, ops: Vec<SignerOp>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::apply_signer_ops(&env, ops)
+    }
+
+    fn suspend_signer(env: Env, signer_key: SignerKey) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::set_suspended_impl(&env, signer_key, true)
+    }
+
+    fn resume_signer(env: Env, signer_key: SignerKey) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::set_suspended_impl(&env, signer_key, false)
+    }
+
+    fn revoke_sessions(env: Env) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        let generation = session_generation(&env) + 1;
+
+        set_session_generation(&env, generation);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        SessionsRevoked { generation }.publish(&env);
+
+        Ok(())
+    }
+
+    fn set_inheritance(env: Env, config: Option<InheritanceConfig>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::set_inheritance_impl(&env, config)?;
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn freeze(env: Env) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        if !Self::frozen(&env) {
+            env.storage()
+                .instance()
+                .set::<Symbol, u64>(&FROZEN, &env.ledger().timestamp());
+
+            WalletFrozen {}.publish(&env);
+        }
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn unfreeze(env: Env) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        if Self::frozen(&env) {
+            env.storage().instance().remove(&FROZEN);
+
+            WalletUnfrozen {}.publish(&env);
+        }
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn set_freeze_quorum(env: Env, quorum: u32) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::set_freeze_quorum_impl(&env, quorum)?;
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn set_ttl_config(env: Env, config: Option<TtlConfig>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::set_ttl_config_impl(&env, config)?;
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn reconcile_counters(env: Env, signer_keys: Vec<SignerKey>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        let mut admin_count: u32 = 0;
+        let mut durable_count: u32 = 0;
+
+        for (index, signer_key) in signer_keys.iter().enumerate() {
+            if signer_keys.first_index_of(&signer_key) != Some(index as u32) {
+                return Err(Error::DuplicateSignerKey);
+            }
+
+            let (signer_val, signer_storage) =
+                get_signer_val_storage(&env, &signer_key, false).ok_or(Error::SignerNotFound)?;
+
+            // Same classification as `SignerCounts::track`.
+            if is_durable(&signer_val, &signer_storage) {
+                durable_count += 1;
+            }
+            if !Self::is_admin_excluded(&env, &signer_key, &signer_storage)
+                && is_durable_admin(&env, &signer_val, &signer_storage)
+            {
+                admin_count += 1;
+            }
+        }
+
+        // Both the stored counters and the derived counts are lower bounds
+        // of the true counts, so their maximum is too: a reconciliation can
+        // never overcount.
+        let admin_count = admin_count.max(Self::admin_count(&env));
+        let durable_count = durable_count.max(Self::durable_count(&env));
+
+        Self::set_admin_count(&env, admin_count);
+        Self::set_durable_count(&env, durable_count);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        CountersReconciled {
+            admin_count,
+            durable_count,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    fn migrate_legacy_signers(
+        env: Env,
+        signer_keys: Vec<SignerKey>,
+        reference: LedgerReference,
+    ) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        let mut counts = SignerCounts::load(&env);
+
+        for signer_key in signer_keys.iter() {
+            let Some(legacy_signer) = read_legacy_signer(&env, &signer_key, &reference)? else {
+                continue;
+            };
+            let LegacySigner { migrated, storage } = legacy_signer;
+
+            // An entry v1 cannot decode was never counted. Migration only
+            // lifts limits, so it never decrements a count.
+            let excluded = Self::is_admin_excluded(&env, &signer_key, &storage);
+            counts.track(&env, None, Some((&migrated, &storage, excluded)));
+
+            store_migrated_signer(&env, &signer_key, &migrated, &storage);
+
+            SignerUpdated {
+                key: signer_key.clone(),
+                val: migrated,
+                storage: storage.clone(),
+                old_storage: storage.clone(),
+                options: get_signer_options(&env, &signer_key, &storage),
+            }
+            .publish(&env);
+        }
+
+        counts.commit(&env)?;
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn execute(env: Env, calls: Vec<(Address, Symbol, Vec<Val>)>) -> Result<Vec<Val>, Error> {
+        // `__check_auth` refuses self-calls too, but only when it runs.
+        for (contract, _, _) in calls.iter() {
+            if contract == env.current_contract_address() {
+                return Err(Error::ExecuteSelfCall);
+            }
+        }
+
+        env.current_contract_address().require_auth();
+
+        let mut results = Vec::new(&env);
+
+        for (contract, fn_name, args) in calls.iter() {
+            results.push_back(env.invoke_contract::<Val>(&contract, &fn_name, args));
+        }
+
+        Ok(results)
+    }
+
+    fn execute_intent(env: Env, intent: Intent, signatures: Signatures) -> Result<Val, Error> {
+        if intent.contract == env.current_contract_address() {
+            return Err(Error::ExecuteSelfCall);
+        }
+
+        if env.ledger().timestamp() > intent.deadline {
+            return Err(Error::IntentExpired);
+        }
+
+        if let IntentFee::Token(fee) = &intent.fee {
+            check_fee(&env, fee)?;
+        }
+
+        // Consumed first: a replay fails before any policy is consulted, and
+        // the call itself can never replay it. A failed authentication rolls
+        // the consumption back with everything else.
+        consume_intent_nonce(&env, intent.nonce, intent.deadline)?;
+
+        // The fee transfer is authorized together with the call, so signer
+        // limits and policies bound it like any other transfer.
+        let mut auth_contexts = vec![&env, intent_context(&intent)];
+        if let IntentFee::Token(fee) = &intent.fee {
+            auth_contexts.push_back(fee_context(&env, fee));
+        }
+
+        Self::authenticate(
+            &env,
+            &intent_hash(&env, &intent).to_bytes(),
+            &signatures,
+            &auth_contexts,
+            true,
+        )?;
+
+        let result = env.invoke_contract::<Val>(&intent.contract, &intent.fn_name, intent.args);
+
+        IntentExecuted {
+            nonce: intent.nonce,
+            contract: intent.contract,
+            fn_name: intent.fn_name,
+        }
+        .publish(&env);
+
+        if let IntentFee::Token(fee) = intent.fee {
+            pay_fee(&env, &fee);
+
+            FeePaid {
+                nonce: intent.nonce,
+                token: fee.token,
+                amount: fee.amount,
+                recipient: fee.recipient,
+            }
+            .publish(&env);
+        }
+
+        Ok(result)
+    }
+
+    fn set_fee_config(env: Env, config: Option<FeeConfig>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        Self::set_fee_config_impl(&env, config)?;
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn set_web_auth_config(env: Env, config: Option<WebAuthConfig>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        set_web_auth_config(&env, &config);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn cancel_intent(env: Env, nonce: u64, deadline: u64) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        consume_intent_nonce(&env, nonce, deadline)?;
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        IntentCancelled { nonce }.publish(&env);
+
+        Ok(())
+    }
+
+    fn create_subaccount(
+        env: Env,
+        salt: BytesN<32>,
+        config: SubaccountConfig,
+    ) -> Result<Address, Error> {
+        env.current_contract_address().require_auth();
+
+        let mut signers = vec![
+            &env,
+            Signer::Address(
+                env.current_contract_address(),
+                SignerExpiration(None),
+                SignerLimits(None),
+                SignerStorage::Persistent,
+                SignerOptions::default(),
+            ),
+        ];
+        signers.append(&config.signers);
+
+        // The wallet is the deployer, so the deploy is authorized by the
+        // call itself. The subaccount's constructor validates the signers.
+        let subaccount = env
+            .deployer()
+            .with_current_contract(salt)
+            .deploy_v2(config.wasm_hash.clone(), (signers, None::<WalletSettings>));
+
+        record_subaccount(&env, &subaccount);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        SubaccountCreated {
+            subaccount: subaccount.clone(),
+            wasm_hash: config.wasm_hash,
+        }
+        .publish(&env);
+
+        Ok(subaccount)
+    }
+
+    fn set_signer_registry(env: Env, registry: Option<Address>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        set_signer_registry(&env, &registry);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        let old_hash = env
+            .storage()
+            .instance()
+            .get::<Symbol, BytesN<32>>(&WASM_HASH);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.storage()
+            .instance()
+            .set::<Symbol, BytesN<32>>(&WASM_HASH, &new_wasm_hash);
+
+        // Re-upgrading an unconfirmed upgrade keeps its rollback target and
+        // the rollback target's schema version: the hash it replaces was
+        // never confirmed. The new code gets a full window of its own.
+        let deadline = env
+            .ledger()
+            .timestamp()
+            .saturating_add(UPGRADE_CONFIRM_WINDOW);
+        let pending = match get_pending_upgrade(&env) {
+            Some(pending) => PendingUpgrade {
+                hash: new_wasm_hash.clone(),
+                deadline,
+                ..pending
+            },
+            None => PendingUpgrade {
+                hash: new_wasm_hash.clone(),
+                rollback_hash: old_hash.clone(),
+                deadline,
+                schema_version: schema_version(&env),
+            },
+        };
+
+        set_pending_upgrade(&env, &Some(pending));
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Upgraded {
+            old_hash,
+            new_hash: new_wasm_hash,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    fn confirm_upgrade(env: Env) -> Result<(), Error> {
+        // Wallet auth runs the new `__check_auth`: the first half of the
+        // self-test is that a real signer still authorizes under this code.
+        env.current_contract_address().require_auth();
+
+        let pending = get_pending_upgrade(&env).ok_or(Error::UpgradeNotPending)?;
+
+        if env.ledger().timestamp() > pending.deadline {
+            return Err(Error::UpgradeExpired);
+        }
+
+        // The rest: this code can read its own counters (every durable
+        // admin is durable), and storage is in its layout.
+        if Self::admin_count(&env) > Self::durable_count(&env)
+            || schema_version(&env) != CURRENT_SCHEMA_VERSION
+        {
+            return Err(Error::UpgradeSelfTestFailed);
+        }
+
+        set_pending_upgrade(&env, &None);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        UpgradeConfirmed { hash: pending.hash }.publish(&env);
+
+        Ok(())
+    }
+
+    fn rollback_upgrade(env: Env) -> Result<(), Error> {
+        let pending = get_pending_upgrade(&env).ok_or(Error::UpgradeNotPending)?;
+
+        // The guardian's right covers the confirm window only: past it, the
+        // wallet itself decides what happens to the running code.
+        match get_upgrade_guardian(&env) {
+            Some(guardian) if env.ledger().timestamp() <= pending.deadline => {
+                guardian.require_auth()
+            }
+            _ => env.current_contract_address().require_auth(),
+        }
+
+        // The guardian's auth bypasses the frozen gate of `__check_auth`.
+        if Self::frozen(&env) {
+            return Err(Error::WalletFrozen);
+        }
+
+        let rollback_hash = pending.rollback_hash.ok_or(Error::NoRollbackTarget)?;
+
+        // The previous code cannot read storage migrated past its version.
+        if schema_version(&env) != pending.schema_version {
+            return Err(Error::RollbackAfterMigration);
+        }
+
+        env.deployer()
+            .update_current_contract_wasm(rollback_hash.clone());
+
+        env.storage()
+            .instance()
+            .set::<Symbol, BytesN<32>>(&WASM_HASH, &rollback_hash);
+
+        set_pending_upgrade(&env, &None);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        UpgradeRolledBack {
+            from_hash: pending.hash,
+            to_hash: rollback_hash,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    fn set_upgrade_guardian(env: Env, guardian: Option<Address>) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        set_upgrade_guardian(&env, &guardian);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        Ok(())
+    }
+
+    fn migrate(env: Env) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        let from_version = schema_version(&env);
+
+        if from_version >= CURRENT_SCHEMA_VERSION {
+            return Err(Error::AlreadyMigrated);
+        }
+
+        migrate_schema_step(&env, from_version)?;
+
+        let to_version = from_version + 1;
+
+        set_schema_version(&env, to_version);
+
+        extend_instance(&env, TtlTrigger::Mutation);
+
+        SchemaMigrated {
+            from_version,
+            to_version,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    fn bump(env: Env, signer_keys: Vec<SignerKey>) {
+        for signer_key in signer_keys.iter() {
+            get_signer_val_storage(&env, &signer_key, true);
+        }
+
+        extend_instance(&env, TtlTrigger::Auth);
+    }
+
+    fn prune_expired(env: Env, signer_keys: Vec<SignerKey>) {
+        for signer_key in signer_keys.iter() {
+            let Some((signer_val, signer_storage)) =
+                get_signer_val_storage(&env, &signer_key, false)
+            else {
+                continue;
+            };
+
+            if !is_signer_expired(&env, signer_expiration(&signer_val))
+                && !is_session_revoked(&env, &signer_key, &signer_val, &signer_storage)
+            {
+                continue;
+            }
+
+            // An expiration or a session is never durable, so pruning cannot
+            // reach the `ADMIN_COUNT`/`DURABLE_COUNT` guards. Keep it that
+            // way should either classification ever change.
+            if is_durable(&signer_val, &signer_storage) {
+                continue;
+            }
+
+            remove_signer_entry(&env, &signer_key, &signer_storage);
+            unregister_signer(&env, &signer_key);
+
+            SignerRemoved {
+                key: signer_key,
+                storage: signer_storage,
+            }
+            .publish(&env);
+        }
+    }
+
+    fn get_signer(env: Env, signer_key: SignerKey) -> Option<SignerVal> {
+        get_signer_val_storage(&env, &signer_key, false).map(|(signer_val, _)| signer_val)
+    }
+
+    fn get_signer_options(env: Env, signer_key: SignerKey) -> Option<SignerOptions> {
+        get_signer_val_storage(&env, &signer_key, false)
+            .map(|(_, signer_storage)| get_signer_options(&env, &signer_key, &signer_storage))
+    }
+
+    fn is_signer_suspended(env: Env, signer_key: SignerKey) -> Option<bool> {
+        get_signer_val_storage(&env, &signer_key, false)
+            .map(|(_, signer_storage)| is_signer_suspended(&env, &signer_key, &signer_storage))
+    }
+
+    fn get_session_generation(env: Env) -> u32 {
+        session_generation(&env)
+    }
+
+    fn get_inheritance(env: Env) -> Option<InheritanceConfig> {
+        get_inheritance(&env)
+    }
+
+    fn get_last_owner_activity(env: Env) -> Option<u64> {
+        get_last_owner_activity(&env)
+    }
+
+    fn get_signer_activity(env: Env, signer_key: SignerKey) -> Option<SignerActivity> {
+        get_signer_val_storage(&env, &signer_key, false)
+            .map(|(_, signer_storage)| get_signer_activity(&env, &signer_key, &signer_storage))
+    }
+
+    fn get_last_active(env: Env) -> Option<u64> {
+        get_last_active(&env)
+    }
+
+    fn get_ttl_config(env: Env) -> Option<TtlConfig> {
+        get_ttl_config(&env)
+    }
+
+    fn get_schema_version(env: Env) -> u32 {
+        schema_version(&env)
+    }
+
+    fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        get_pending_upgrade(&env)
+    }
+
+    fn get_upgrade_guardian(env: Env) -> Option<Address> {
+        get_upgrade_guardian(&env)
+    }
+
+    fn get_intent_hash(env: Env, intent: Intent) -> BytesN<32> {
+        intent_hash(&env, &intent).into()
+    }
+
+    fn get_signature_hash(env: Env, hash: BytesN<32>) -> BytesN<32> {
+        signature_hash(&env, &hash).into()
+    }
+
+    fn is_valid_signature(env: Env, hash: BytesN<32>, signatures: Signatures) -> bool {
+        let auth_contexts = vec![&env, signature_context(&env, &hash)];
+
+        Self::authenticate(
+            &env,
+            &signature_hash(&env, &hash).to_bytes(),
+            &signatures,
+            &auth_contexts,
+            false,
+        )
+        .is_ok()
+    }
+
+    fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        get_fee_config(&env)
+    }
+
+    fn get_web_auth_config(env: Env) -> Option<WebAuthConfig> {
+        get_web_auth_config(&env)
+    }
+
+    fn is_subaccount(env: Env, address: Address) -> bool {
+        is_subaccount(&env, &address)
+    }
+
+    fn get_signer_registry(env: Env) -> Option<Address> {
+        get_signer_registry(&env)
+    }
+
+    fn is_intent_nonce_used(env: Env, nonce: u64) -> bool {
+        is_intent_nonce_used(&env, nonce)
+    }
+
+    fn is_frozen(env: Env) -> bool {
+        Self::frozen(&env)
+    }
+
+    fn get_freeze_quorum(env: Env) -> u32 {
+        Self::freeze_quorum(&env)
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for Contract {
+    type Error = Error;
+    type Signature = Signatures;
+
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Signatures,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), Error> {
+        Self::authenticate(
+            &env,
+            &signature_payload.to_bytes(),
+            &signatures,
+            &auth_contexts,
+            true,
+        )
+    }
+}
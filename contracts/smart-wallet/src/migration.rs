@@ -0,0 +1,189 @@
+//! Storage migrations.
+//!
+//! Schema versions: the wallet records the version of its storage layout in
+//! instance storage, and `migrate` moves it forward one step at a time. A
+//! storage change ships as a new `CURRENT_SCHEMA_VERSION` plus the step that
+//! transforms the previous layout (`migrate_schema_step`).
+//!
+//! Pre-1.0 signer entries: the legacy contract stored the same flat
+//! `SignerKey → SignerVal` entries, with two differences: `SignerExpiration`
+//! held a ledger sequence number (`u32`), and an empty limits map meant
+//! unlimited. `migrate_legacy_signers` rewrites such entries in place. They
+//! cannot be found by a schema step, since storage cannot be enumerated.
+
+use smart_wallet_interface::types::{
+    Error, LedgerReference, SignerExpiration, SignerKey, SignerLimits, SignerStorage, SignerVal,
+};
+use soroban_sdk::{contracttype, symbol_short, BytesN, Env, Symbol, TryFromVal, Val};
+
+use crate::signer::signer_limits;
+use crate::storage::{extend_signer_key, TtlTrigger};
+
+/// Instance storage key of the wallet's storage schema version. Absent on a
+/// wallet deployed before schema versioning, which reads as version 0.
+const SCHEMA_VERSION: Symbol = symbol_short!("schema");
+
+/// The storage schema version this code reads and writes. Set by the
+/// constructor; older wallets reach it through `migrate`.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// The historical ledger close time legacy expirations are converted at.
+const LEGACY_LEDGER_CLOSE_SECONDS: u64 = 5;
+
+/// The legacy `SignerExpiration`: a ledger sequence number, inclusive.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacySignerExpiration(pub Option<u32>);
+
+/// The legacy `SignerVal`. Its limits share v1's encoding.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LegacySignerVal {
+    Policy(LegacySignerExpiration, SignerLimits),
+    Ed25519(LegacySignerExpiration, SignerLimits),
+    Secp256r1(BytesN<65>, LegacySignerExpiration, SignerLimits),
+}
+
+/// A stored signer entry to rewrite: its v1 rewrite and its durability. v1
+/// cannot decode its current value.
+pub struct LegacySigner {
+    pub migrated: SignerVal,
+    pub storage: SignerStorage,
+}
+
+pub fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get::<Symbol, u32>(&SCHEMA_VERSION)
+        .unwrap_or(0)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set::<Symbol, u32>(&SCHEMA_VERSION, &version);
+}
+
+/// Transform storage from schema version `from` to `from + 1`. Fails with
+/// `UnsupportedSchemaVersion` for a version with no step.
+pub fn migrate_schema_step(_env: &Env, from: u32) -> Result<(), Error> {
+    match from {
+        // 0 → 1 introduces the version record itself: wallets deployed
+        // before it already share version 1's layout.
+        0 => Ok(()),
+        _ => Err(Error::UnsupportedSchemaVersion),
+    }
+}
+
+/// Read the raw signer entry for `signer_key` (Temporary before Persistent,
+/// like `get_signer_val_storage`) and work out its v1 rewrite, or `None` if
+/// it has nothing to rewrite.
+pub fn read_legacy_signer(
+    env: &Env,
+    signer_key: &SignerKey,
+    reference: &LedgerReference,
+) -> Result<Option<LegacySigner>, Error> {
+    let (raw, storage) = match env.storage().temporary().get::<SignerKey, Val>(signer_key) {
+        Some(raw) => (raw, SignerStorage::Temporary),
+        None => (
+            env.storage()
+                .persistent()
+                .get::<SignerKey, Val>(signer_key)
+                .ok_or(Error::SignerNotFound)?,
+            SignerStorage::Persistent,
+        ),
+    };
+
+    // Decodes as v1: a non-expiring entry (or a v1 one), of which only empty
+    // limits can still carry legacy meaning. Whether they do cannot be told
+    // from the entry, and lifting a v1 no-permissions signer to unlimited
+    // would be an escalation, so such an entry is refused rather than
+    // guessed at.
+    if let Ok(current) = SignerVal::try_from_val(env, &raw) {
+        let empty_limits = signer_limits(&current)
+            .0
+            .as_ref()
+            .is_some_and(|limits| limits.is_empty());
+
+        // No pre-1.0 wallet had Address signers: their empty limits already
+        // mean "no permissions".
+        if empty_limits && !matches!(current, SignerVal::Address(..)) {
+            return Err(Error::AmbiguousLegacyLimits);
+        }
+
+        return Ok(None);
+    }
+
+    let legacy =
+        LegacySignerVal::try_from_val(env, &raw).map_err(|_| Error::InvalidLegacySigner)?;
+    let migrated = match legacy {
+        LegacySignerVal::Policy(expiration, limits) => SignerVal::Policy(
+            migrate_expiration(reference, &expiration),
+            migrate_limits(limits),
+        ),
+        LegacySignerVal::Ed25519(expiration, limits) => SignerVal::Ed25519(
+            migrate_expiration(reference, &expiration),
+            migrate_limits(limits),
+        ),
+        LegacySignerVal::Secp256r1(public_key, expiration, limits) => SignerVal::Secp256r1(
+            public_key,
+            migrate_expiration(reference, &expiration),
+            migrate_limits(limits),
+        ),
+    };
+
+    Ok(Some(LegacySigner { migrated, storage }))
+}
+
+/// Store a migrated signer value in place. Its companion entries are left
+/// as they are.
+pub fn store_migrated_signer(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_val: &SignerVal,
+    signer_storage: &SignerStorage,
+) {
+    let is_persistent = match signer_storage {
+        SignerStorage::Persistent => {
+            env.storage()
+                .persistent()
+                .set::<SignerKey, SignerVal>(signer_key, signer_val);
+
+            true
+        }
+        SignerStorage::Temporary => {
+            env.storage()
+                .temporary()
+                .set::<SignerKey, SignerVal>(signer_key, signer_val);
+
+            false
+        }
+    };
+
+    extend_signer_key(env, signer_key, is_persistent, TtlTrigger::Mutation);
+}
+
+/// The v1 timestamp of a legacy expiration ledger, extrapolated from
+/// `reference`.
+fn migrate_expiration(
+    reference: &LedgerReference,
+    expiration: &LegacySignerExpiration,
+) -> SignerExpiration {
+    SignerExpiration(expiration.0.map(|ledger| {
+        if ledger >= reference.sequence {
+            let seconds = (ledger - reference.sequence) as u64 * LEGACY_LEDGER_CLOSE_SECONDS;
+            reference.timestamp.saturating_add(seconds)
+        } else {
+            let seconds = (reference.sequence - ledger) as u64 * LEGACY_LEDGER_CLOSE_SECONDS;
+            reference.timestamp.saturating_sub(seconds)
+        }
+    }))
+}
+
+/// Legacy limits in v1 terms: an empty map meant unlimited.
+fn migrate_limits(limits: SignerLimits) -> SignerLimits {
+    match limits.0 {
+        Some(map) if map.is_empty() => SignerLimits(None),
+        _ => limits,
+    }
+}
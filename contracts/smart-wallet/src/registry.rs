@@ -0,0 +1,60 @@
+use smart_wallet_interface::{
+    events::SignerRegistryFailed, types::SignerKey, SignerRegistryClient,
+};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+/// Instance storage key of the signer registry the wallet reports to
+/// (`set_signer_registry`). Absent = no registry.
+const SIGNER_REGISTRY: Symbol = symbol_short!("registry");
+
+pub fn get_signer_registry(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get::<Symbol, Address>(&SIGNER_REGISTRY)
+}
+
+pub fn set_signer_registry(env: &Env, registry: &Option<Address>) {
+    match registry {
+        Some(registry) => env
+            .storage()
+            .instance()
+            .set::<Symbol, Address>(&SIGNER_REGISTRY, registry),
+        None => env.storage().instance().remove(&SIGNER_REGISTRY),
+    }
+}
+
+/// Report an added signer to the registry, if any. A registry that rejects
+/// the call must not abort the add, so the failure is announced with
+/// `SignerRegistryFailed` instead.
+pub fn register_signer(env: &Env, signer_key: &SignerKey) {
+    if let Some(registry) = get_signer_registry(env) {
+        let result = SignerRegistryClient::new(env, &registry)
+            .try_register(&env.current_contract_address(), signer_key);
+
+        if !matches!(result, Ok(Ok(()))) {
+            report_failure(env, registry, signer_key, true);
+        }
+    }
+}
+
+/// Report a removed signer to the registry, if any. As with
+/// `register_signer`, a failing registry never blocks the removal.
+pub fn unregister_signer(env: &Env, signer_key: &SignerKey) {
+    if let Some(registry) = get_signer_registry(env) {
+        let result = SignerRegistryClient::new(env, &registry)
+            .try_unregister(&env.current_contract_address(), signer_key);
+
+        if !matches!(result, Ok(Ok(()))) {
+            report_failure(env, registry, signer_key, false);
+        }
+    }
+}
+
+fn report_failure(env: &Env, registry: Address, signer_key: &SignerKey, registered: bool) {
+    SignerRegistryFailed {
+        key: signer_key.clone(),
+        registry,
+        registered,
+    }
+    .publish(env);
+}
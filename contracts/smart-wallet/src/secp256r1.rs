@@ -0,0 +1,300 @@
+//! SEC-1 public key validation and point decompression for P-256
+//! (secp256r1), the curve WebAuthn passkeys sign with.
+//!
+//! The host's `secp256r1_verify` only ever sees a key at signature time, so a
+//! malformed key would otherwise be stored happily and then fail every
+//! verification forever. This module is the registration-time gate: every
+//! key — compressed or uncompressed — is decoded to its affine coordinates,
+//! checked to lie on the curve, and re-encoded as the 65-byte uncompressed
+//! form the wallet stores and the host verifies against.
+//!
+//! Field arithmetic is a minimal Montgomery implementation over four 64-bit
+//! limbs (little-endian limb order). It only ever touches PUBLIC data (keys),
+//! so it is deliberately not hardened against timing side channels.
+
+use smart_wallet_interface::types::Error;
+use soroban_sdk::{Bytes, BytesN, Env};
+
+/// SEC-1 uncompressed point: `0x04 || x || y`.
+pub const UNCOMPRESSED_LEN: u32 = 65;
+/// SEC-1 compressed point: `0x02 | (y & 1) || x`.
+pub const COMPRESSED_LEN: u32 = 33;
+
+const TAG_UNCOMPRESSED: u8 = 0x04;
+const TAG_COMPRESSED_EVEN: u8 = 0x02;
+const TAG_COMPRESSED_ODD: u8 = 0x03;
+
+type Fe = [u64; 4];
+
+/// p = 2^256 - 2^224 + 2^192 + 2^96 - 1
+const P: Fe = [
+    0xffff_ffff_ffff_ffff,
+    0x0000_0000_ffff_ffff,
+    0x0000_0000_0000_0000,
+    0xffff_ffff_0000_0001,
+];
+
+/// R^2 mod p, R = 2^256 (Montgomery conversion constant).
+const R2: Fe = [
+    0x0000_0000_0000_0003,
+    0xffff_fffb_ffff_ffff,
+    0xffff_ffff_ffff_fffe,
+    0x0000_0004_ffff_fffd,
+];
+
+/// Curve coefficient b (the curve is y^2 = x^3 - 3x + b), canonical form.
+const B: Fe = [
+    0x3bce_3c3e_27d2_604b,
+    0x651d_06b0_cc53_b0f6,
+    0xb3eb_bd55_7698_86bc,
+    0x5ac6_35d8_aa3a_93e7,
+];
+
+/// (p + 1) / 4. p ≡ 3 (mod 4), so a^((p+1)/4) is a square root of a
+/// whenever one exists.
+const SQRT_EXP: Fe = [
+    0x0000_0000_0000_0000,
+    0x0000_0000_4000_0000,
+    0x4000_0000_0000_0000,
+    0x3fff_ffff_c000_0000,
+];
+
+/// Validate a SEC-1 encoded P-256 public key and return its uncompressed
+/// 65-byte form.
+///
+/// Accepts exactly:
+/// - 65 bytes, `0x04 || x || y`, with `(x, y)` on the curve; or
+/// - 33 bytes, `0x02 || x` / `0x03 || x`, where `x` has a curve point — `y`
+///   is recovered on-chain, with the parity the tag selects.
+///
+/// Coordinates must be canonical (`< p`). The point at infinity (the
+/// identity) has no 33/65-byte SEC-1 encoding and no affine point satisfies
+/// the curve equation at `(0, 0)`, so the identity is rejected by the same
+/// checks. Anything else fails with `Error::InvalidPublicKey`.
+pub fn decode_public_key(env: &Env, public_key: &Bytes) -> Result<BytesN<65>, Error> {
+    let len = public_key.len();
+
+    if len != UNCOMPRESSED_LEN && len != COMPRESSED_LEN {
+        return Err(Error::InvalidPublicKey);
+    }
+
+    let mut buffer = [0u8; UNCOMPRESSED_LEN as usize];
+    public_key.copy_into_slice(&mut buffer[..len as usize]);
+
+    let tag = buffer[0];
+    let x = from_be_bytes(&buffer[1..33]).ok_or(Error::InvalidPublicKey)?;
+    let rhs = curve_rhs(&to_mont(&x));
+
+    let y = match (len, tag) {
+        (UNCOMPRESSED_LEN, TAG_UNCOMPRESSED) => {
+            let y = from_be_bytes(&buffer[33..65]).ok_or(Error::InvalidPublicKey)?;
+            let y_mont = to_mont(&y);
+
+            if mont_mul(&y_mont, &y_mont) != rhs {
+                return Err(Error::InvalidPublicKey);
+            }
+
+            y
+        }
+        (COMPRESSED_LEN, TAG_COMPRESSED_EVEN | TAG_COMPRESSED_ODD) => {
+            let root = pow(&rhs, &SQRT_EXP);
+
+            // No square root: x is not the abscissa of any curve point.
+            if mont_mul(&root, &root) != rhs {
+                return Err(Error::InvalidPublicKey);
+            }
+
+            let y = from_mont(&root);
+            let odd = (tag & 1) == 1;
+
+            if (y[0] & 1 == 1) == odd {
+                y
+            } else {
+                // y = 0 has no odd negation; P-256 has no such point anyway
+                // (prime order, so no 2-torsion), but never emit p itself.
+                if y == [0; 4] {
+                    return Err(Error::InvalidPublicKey);
+                }
+
+                sub(&P, &y)
+            }
+        }
+        _ => return Err(Error::InvalidPublicKey),
+    };
+
+    buffer[0] = TAG_UNCOMPRESSED;
+    to_be_bytes(&x, &mut buffer[1..33]);
+    to_be_bytes(&y, &mut buffer[33..65]);
+
+    Ok(BytesN::from_array(env, &buffer))
+}
+
+/// x^3 - 3x + b, all in Montgomery form.
+fn curve_rhs(x: &Fe) -> Fe {
+    let x3 = mont_mul(&mont_mul(x, x), x);
+    let three_x = add_mod(&add_mod(x, x), x);
+
+    add_mod(&sub_mod(&x3, &three_x), &to_mont(&B))
+}
+
+/// Parse 32 big-endian bytes, rejecting non-canonical values (>= p).
+fn from_be_bytes(bytes: &[u8]) -> Option<Fe> {
+    let mut fe = [0u64; 4];
+
+    for (i, limb) in fe.iter_mut().enumerate() {
+        let start = (3 - i) * 8;
+        let mut chunk = [0u8; 8];
+        chunk.copy_from_slice(&bytes[start..start + 8]);
+        *limb = u64::from_be_bytes(chunk);
+    }
+
+    if geq(&fe, &P) {
+        None
+    } else {
+        Some(fe)
+    }
+}
+
+fn to_be_bytes(fe: &Fe, out: &mut [u8]) {
+    for (i, limb) in fe.iter().enumerate() {
+        let start = (3 - i) * 8;
+        out[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+    }
+}
+
+fn geq(a: &Fe, b: &Fe) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+
+    true
+}
+
+/// a - b over 256 bits, returning the borrow.
+fn sub_with_borrow(a: &Fe, b: &Fe) -> (Fe, bool) {
+    let mut out = [0u64; 4];
+    let mut borrow = false;
+
+    for i in 0..4 {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        out[i] = diff;
+        borrow = b1 || b2;
+    }
+
+    (out, borrow)
+}
+
+/// a - b for a >= b.
+fn sub(a: &Fe, b: &Fe) -> Fe {
+    sub_with_borrow(a, b).0
+}
+
+fn add_mod(a: &Fe, b: &Fe) -> Fe {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+
+    for i in 0..4 {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        out[i] = sum;
+        carry = c1 || c2;
+    }
+
+    if carry || geq(&out, &P) {
+        sub_with_borrow(&out, &P).0
+    } else {
+        out
+    }
+}
+
+fn sub_mod(a: &Fe, b: &Fe) -> Fe {
+    let (out, borrow) = sub_with_borrow(a, b);
+
+    if borrow {
+        // Wrap back into [0, p): out + p, discarding the final carry.
+        let mut wrapped = [0u64; 4];
+        let mut carry = false;
+
+        for i in 0..4 {
+            let (sum, c1) = out[i].overflowing_add(P[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            wrapped[i] = sum;
+            carry = c1 || c2;
+        }
+
+        wrapped
+    } else {
+        out
+    }
+}
+
+/// Montgomery multiplication (CIOS): a * b * R^-1 mod p. For P-256,
+/// -p^-1 mod 2^64 = 1, so the per-round reduction factor is just `t[0]`.
+fn mont_mul(a: &Fe, b: &Fe) -> Fe {
+    let mut t = [0u64; 6];
+
+    for bi in b.iter() {
+        let mut carry: u128 = 0;
+
+        for j in 0..4 {
+            let acc = t[j] as u128 + (a[j] as u128) * (*bi as u128) + carry;
+            t[j] = acc as u64;
+            carry = acc >> 64;
+        }
+
+        let acc = t[4] as u128 + carry;
+        t[4] = acc as u64;
+        t[5] = (acc >> 64) as u64;
+
+        let m = t[0];
+        let mut carry = (t[0] as u128 + (m as u128) * (P[0] as u128)) >> 64;
+
+        for j in 1..4 {
+            let acc = t[j] as u128 + (m as u128) * (P[j] as u128) + carry;
+            t[j - 1] = acc as u64;
+            carry = acc >> 64;
+        }
+
+        let acc = t[4] as u128 + carry;
+        t[3] = acc as u64;
+        t[4] = t[5] + (acc >> 64) as u64;
+        t[5] = 0;
+    }
+
+    let out = [t[0], t[1], t[2], t[3]];
+
+    if t[4] != 0 || geq(&out, &P) {
+        sub_with_borrow(&out, &P).0
+    } else {
+        out
+    }
+}
+
+fn to_mont(a: &Fe) -> Fe {
+    mont_mul(a, &R2)
+}
+
+fn from_mont(a: &Fe) -> Fe {
+    mont_mul(a, &[1, 0, 0, 0])
+}
+
+/// base^exponent for a Montgomery-form base (square-and-multiply, MSB
+/// first).
+fn pow(base: &Fe, exponent: &Fe) -> Fe {
+    let mut result = to_mont(&[1, 0, 0, 0]);
+
+    for limb in exponent.iter().rev() {
+        for bit in (0..64).rev() {
+            result = mont_mul(&result, &result);
+
+            if (limb >> bit) & 1 == 1 {
+                result = mont_mul(&result, base);
+            }
+        }
+    }
+
+    result
+}
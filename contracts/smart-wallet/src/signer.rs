@@ -1,39 +1,114 @@
 use smart_wallet_interface::types::{
-    Error, Signer, SignerExpiration, SignerKey, SignerStorage, SignerVal,
+    Error, Signer, SignerActivity, SignerExpiration, SignerKey, SignerOptions, SignerStorage,
+    SignerVal, MAX_SIGNER_LABEL_LEN,
 };
-use soroban_sdk::Env;
+use soroban_sdk::{symbol_short, Env, Symbol};
 
-use crate::storage::extend_signer_key;
+use crate::{
+    activity::{get_signer_activity, set_signer_activity},
+    inheritance::is_beneficiary_dormant,
+    secp256r1::decode_public_key,
+    storage::{companion_keys, extend_signer_key, StorageKey, TtlTrigger},
+};
+
+/// Instance storage key of the wallet's session generation, bumped by
+/// `revoke_sessions`.
+const SESSION_GENERATION: Symbol = symbol_short!("sess_gen");
 
-pub fn process_signer(signer: Signer) -> (SignerKey, SignerVal, SignerStorage) {
-    match signer {
-        Signer::Policy(policy, signer_expiration, signer_limits, signer_storage) => (
+/// A `Signer` split into its storage key, stored value, durability and
+/// options (see `process_signer`).
+pub type ProcessedSigner = (SignerKey, SignerVal, SignerStorage, SignerOptions);
+
+/// Split a `Signer` into its storage key, stored value, durability and
+/// options. Secp256r1 public keys are validated here — the single
+/// registration path for `__constructor`/`add_signer`/`update_signer` — and
+/// stored in their uncompressed form (`Error::InvalidPublicKey` otherwise),
+/// as are Address signers (`Error::SelfAddressSigner`) and the metadata
+/// label's bound (`Error::InvalidSignerMetadata`).
+pub fn process_signer(env: &Env, signer: Signer) -> Result<ProcessedSigner, Error> {
+    let processed = match signer {
+        Signer::Policy(
+            policy,
+            signer_expiration,
+            signer_limits,
+            signer_storage,
+            signer_options,
+        ) => (
             SignerKey::Policy(policy),
             SignerVal::Policy(signer_expiration, signer_limits),
             signer_storage,
+            signer_options,
         ),
-        Signer::Ed25519(public_key, signer_expiration, signer_limits, signer_storage) => (
+        Signer::Ed25519(
+            public_key,
+            signer_expiration,
+            signer_limits,
+            signer_storage,
+            signer_options,
+        ) => (
             SignerKey::Ed25519(public_key),
             SignerVal::Ed25519(signer_expiration, signer_limits),
             signer_storage,
+            signer_options,
         ),
-        Signer::Secp256r1(id, public_key, signer_expiration, signer_limits, signer_storage) => (
+        Signer::Secp256r1(
+            id,
+            public_key,
+            signer_expiration,
+            signer_limits,
+            signer_storage,
+            signer_options,
+        ) => (
             SignerKey::Secp256r1(id),
-            SignerVal::Secp256r1(public_key, signer_expiration, signer_limits),
+            SignerVal::Secp256r1(
+                decode_public_key(env, &public_key)?,
+                signer_expiration,
+                signer_limits,
+            ),
             signer_storage,
+            signer_options,
         ),
+        Signer::Address(
+            address,
+            signer_expiration,
+            signer_limits,
+            signer_storage,
+            signer_options,
+        ) => {
+            if address == env.current_contract_address() {
+                return Err(Error::SelfAddressSigner);
+            }
+
+            (
+                SignerKey::Address(address),
+                SignerVal::Address(signer_expiration, signer_limits),
+                signer_storage,
+                signer_options,
+            )
+        }
+    };
+
+    let (_, _, _, signer_options) = &processed;
+
+    if let Some(label) = &signer_options.metadata.label {
+        if label.len() > MAX_SIGNER_LABEL_LEN {
+            return Err(Error::InvalidSignerMetadata);
+        }
     }
+
+    Ok(processed)
 }
 
-/// Store a signer entry. `update: false` requires the key to be new,
-/// `update: true` requires it to exist. Returns the previous entry when
-/// updating (for the `SignerUpdated` event's `old_storage` and the durable
-/// admin accounting).
+/// Store a signer entry and its companion entries. `update: false` requires
+/// the key to be new, `update: true` requires it to exist. Returns the
+/// previous entry when updating (for the `SignerUpdated` event's
+/// `old_storage` and the durable admin accounting).
 pub fn store_signer(
     env: &Env,
     signer_key: &SignerKey,
     signer_val: &SignerVal,
     signer_storage: &SignerStorage,
+    signer_options: &SignerOptions,
     update: bool,
 ) -> Result<Option<(SignerVal, SignerStorage)>, Error> {
     let previous = get_signer_val_storage(env, signer_key, false);
@@ -44,41 +119,229 @@ pub fn store_signer(
         _ => {}
     }
 
+    // A session signer keeps the generation it was added under across
+    // updates, so an update never revives a revoked session. A signer that
+    // becomes a session by update joins the current generation.
+    let generation = match &previous {
+        Some((previous_val, previous_storage)) if !is_durable(previous_val, previous_storage) => {
+            get_signer_session_generation(env, signer_key, previous_storage)
+        }
+        _ => session_generation(env),
+    };
+
+    // An update that flips durability leaves the old entries behind — remove
+    // them so the "at most one entry per signer key" invariant holds (and the
+    // companions never outlive or stray from their signer). An update never
+    // resumes a signer: the suspension flag moves with it, as does its usage
+    // record.
+    let mut suspended = false;
+    let mut activity = None;
+
+    if let Some((_, previous_storage)) = &previous {
+        if previous_storage != signer_storage {
+            suspended = is_signer_suspended(env, signer_key, previous_storage);
+            activity = Some(get_signer_activity(env, signer_key, previous_storage))
+                .filter(|activity| *activity != SignerActivity::default());
+            remove_signer_entry(env, signer_key, previous_storage);
+        }
+    }
+
+    let options_key = StorageKey::SignerOptions(signer_key.clone());
+    let has_options = *signer_options != SignerOptions::default();
+
+    // Durable signers are never sessions and carry no generation; generation
+    // 0 is the implicit default and is not stored either.
+    let generation_key = StorageKey::SessionGeneration(signer_key.clone());
+    let has_generation = !is_durable(signer_val, signer_storage) && generation > 0;
+
     let is_persistent = match signer_storage {
         SignerStorage::Persistent => {
-            env.storage()
-                .persistent()
-                .set::<SignerKey, SignerVal>(signer_key, signer_val);
+            let storage = env.storage().persistent();
+
+            storage.set::<SignerKey, SignerVal>(signer_key, signer_val);
+
+            if has_options {
+                storage.set::<StorageKey, SignerOptions>(&options_key, signer_options);
+            } else {
+                storage.remove::<StorageKey>(&options_key);
+            }
+
+            if has_generation {
+                storage.set::<StorageKey, u32>(&generation_key, &generation);
+            } else {
+                storage.remove::<StorageKey>(&generation_key);
+            }
+
             true
         }
         SignerStorage::Temporary => {
-            env.storage()
-                .temporary()
-                .set::<SignerKey, SignerVal>(signer_key, signer_val);
+            let storage = env.storage().temporary();
+
+            storage.set::<SignerKey, SignerVal>(signer_key, signer_val);
+
+            if has_options {
+                storage.set::<StorageKey, SignerOptions>(&options_key, signer_options);
+            } else {
+                storage.remove::<StorageKey>(&options_key);
+            }
+
+            if has_generation {
+                storage.set::<StorageKey, u32>(&generation_key, &generation);
+            } else {
+                storage.remove::<StorageKey>(&generation_key);
+            }
+
             false
         }
     };
 
-    extend_signer_key(env, signer_key, is_persistent);
+    if suspended {
+        set_signer_suspended(env, signer_key, signer_storage, true);
+    }
 
-    // An update that flips durability leaves the old entry behind — remove it
-    // so the "at most one entry per signer key" invariant holds.
-    if let Some((_, previous_storage)) = &previous {
-        match previous_storage {
-            SignerStorage::Persistent => {
-                if !is_persistent {
-                    env.storage().persistent().remove::<SignerKey>(signer_key);
-                }
+    if let Some(activity) = &activity {
+        set_signer_activity(env, signer_key, signer_storage, activity);
+    }
+
+    extend_signer_key(env, signer_key, is_persistent, TtlTrigger::Mutation);
+
+    Ok(previous)
+}
+
+/// Remove a signer entry and its companion entries from `signer_storage`.
+pub fn remove_signer_entry(env: &Env, signer_key: &SignerKey, signer_storage: &SignerStorage) {
+    match signer_storage {
+        SignerStorage::Persistent => {
+            let storage = env.storage().persistent();
+
+            storage.remove::<SignerKey>(signer_key);
+
+            for companion_key in companion_keys(signer_key) {
+                storage.remove::<StorageKey>(&companion_key);
             }
-            SignerStorage::Temporary => {
-                if is_persistent {
-                    env.storage().temporary().remove::<SignerKey>(signer_key);
-                }
+        }
+        SignerStorage::Temporary => {
+            let storage = env.storage().temporary();
+
+            storage.remove::<SignerKey>(signer_key);
+
+            for companion_key in companion_keys(signer_key) {
+                storage.remove::<StorageKey>(&companion_key);
             }
         }
     }
+}
 
-    Ok(previous)
+/// The wallet's current session generation (`revoke_sessions`), 0 until the
+/// first revocation.
+pub fn session_generation(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get::<Symbol, u32>(&SESSION_GENERATION)
+        .unwrap_or(0)
+}
+
+pub fn set_session_generation(env: &Env, generation: u32) {
+    env.storage()
+        .instance()
+        .set::<Symbol, u32>(&SESSION_GENERATION, &generation);
+}
+
+/// The session generation recorded for the signer entry in
+/// `signer_storage`; 0 if none is recorded (including signers stored before
+/// generations existed).
+pub fn get_signer_session_generation(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_storage: &SignerStorage,
+) -> u32 {
+    let generation_key = StorageKey::SessionGeneration(signer_key.clone());
+
+    match signer_storage {
+        SignerStorage::Persistent => env
+            .storage()
+            .persistent()
+            .get::<StorageKey, u32>(&generation_key),
+        SignerStorage::Temporary => env
+            .storage()
+            .temporary()
+            .get::<StorageKey, u32>(&generation_key),
+    }
+    .unwrap_or(0)
+}
+
+/// A SESSION signer — any non-durable one (Temporary or expiring) — added
+/// under a generation older than the wallet's current one has been revoked
+/// by `revoke_sessions` and is treated exactly like an expired signer.
+/// Durable signers are never revoked.
+pub fn is_session_revoked(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_val: &SignerVal,
+    signer_storage: &SignerStorage,
+) -> bool {
+    !is_durable(signer_val, signer_storage)
+        && get_signer_session_generation(env, signer_key, signer_storage) < session_generation(env)
+}
+
+/// Whether the signer entry in `signer_storage` is suspended.
+pub fn is_signer_suspended(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_storage: &SignerStorage,
+) -> bool {
+    let suspended_key = StorageKey::Suspended(signer_key.clone());
+
+    match signer_storage {
+        SignerStorage::Persistent => env.storage().persistent().has(&suspended_key),
+        SignerStorage::Temporary => env.storage().temporary().has(&suspended_key),
+    }
+}
+
+/// Set or clear the suspension flag of the signer entry in `signer_storage`.
+/// The caller extends the signer's TTL afterwards (`extend_signer_key`).
+pub fn set_signer_suspended(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_storage: &SignerStorage,
+    suspended: bool,
+) {
+    let suspended_key = StorageKey::Suspended(signer_key.clone());
+
+    match (signer_storage, suspended) {
+        (SignerStorage::Persistent, true) => env
+            .storage()
+            .persistent()
+            .set::<StorageKey, bool>(&suspended_key, &true),
+        (SignerStorage::Persistent, false) => env.storage().persistent().remove(&suspended_key),
+        (SignerStorage::Temporary, true) => env
+            .storage()
+            .temporary()
+            .set::<StorageKey, bool>(&suspended_key, &true),
+        (SignerStorage::Temporary, false) => env.storage().temporary().remove(&suspended_key),
+    }
+}
+
+/// The options stored alongside a signer entry in `signer_storage`, or the
+/// defaults if it has none.
+pub fn get_signer_options(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_storage: &SignerStorage,
+) -> SignerOptions {
+    let options_key = StorageKey::SignerOptions(signer_key.clone());
+
+    match signer_storage {
+        SignerStorage::Persistent => env
+            .storage()
+            .persistent()
+            .get::<StorageKey, SignerOptions>(&options_key),
+        SignerStorage::Temporary => env
+            .storage()
+            .temporary()
+            .get::<StorageKey, SignerOptions>(&options_key),
+    }
+    .unwrap_or_default()
 }
 
 /// A DURABLE signer entry: `Persistent` storage AND non-expiring
@@ -140,7 +403,8 @@ pub fn is_durable_admin(env: &Env, signer_val: &SignerVal, signer_storage: &Sign
 
 /// Look up a signer entry, checking Temporary before Persistent (invariant:
 /// at most one entry exists per key, but the lookup order is load-bearing for
-/// determinism and indexers mirror it).
+/// determinism and indexers mirror it). `extend_ttl` extends a found entry
+/// on the `TtlTrigger::Auth` schedule.
 pub fn get_signer_val_storage(
     env: &Env,
     signer_key: &SignerKey,
@@ -153,7 +417,7 @@ pub fn get_signer_val_storage(
     {
         Some(signer_val) => {
             if extend_ttl {
-                extend_signer_key(env, signer_key, false);
+                extend_signer_key(env, signer_key, false, TtlTrigger::Auth);
             }
 
             Some((signer_val, SignerStorage::Temporary))
@@ -165,7 +429,7 @@ pub fn get_signer_val_storage(
         {
             Some(signer_val) => {
                 if extend_ttl {
-                    extend_signer_key(env, signer_key, true);
+                    extend_signer_key(env, signer_key, true, TtlTrigger::Auth);
                 }
 
                 Some((signer_val, SignerStorage::Persistent))
@@ -180,6 +444,7 @@ pub fn signer_expiration(signer_val: &SignerVal) -> &SignerExpiration {
         SignerVal::Policy(signer_expiration, _) => signer_expiration,
         SignerVal::Ed25519(signer_expiration, _) => signer_expiration,
         SignerVal::Secp256r1(_, signer_expiration, _) => signer_expiration,
+        SignerVal::Address(signer_expiration, _) => signer_expiration,
     }
 }
 
@@ -188,6 +453,7 @@ pub fn signer_limits(signer_val: &SignerVal) -> &smart_wallet_interface::types::
         SignerVal::Policy(_, signer_limits) => signer_limits,
         SignerVal::Ed25519(_, signer_limits) => signer_limits,
         SignerVal::Secp256r1(_, _, signer_limits) => signer_limits,
+        SignerVal::Address(_, signer_limits) => signer_limits,
     }
 }
 
@@ -201,3 +467,37 @@ pub fn is_signer_expired(env: &Env, signer_expiration: &SignerExpiration) -> boo
         None => false,
     }
 }
+
+/// Validity start is a UNIX timestamp in seconds, inclusive: not yet valid
+/// while `ledger timestamp < valid_after`. See `SignerOptions::valid_after`.
+pub fn is_signer_not_yet_valid(env: &Env, signer_options: &SignerOptions) -> bool {
+    match signer_options.valid_after {
+        Some(valid_after) => env.ledger().timestamp() < valid_after,
+        None => false,
+    }
+}
+
+/// Why a stored, unexpired signer cannot currently sign, if it cannot: not
+/// yet valid (`SignerOptions::valid_after`), suspended (`suspend_signer`), or
+/// a dormant inheritance beneficiary (`InheritanceConfig`).
+/// Pass 1 skips such signers as candidates; pass 2 rejects them with this
+/// error.
+pub fn signer_inactive_error(
+    env: &Env,
+    signer_key: &SignerKey,
+    signer_storage: &SignerStorage,
+) -> Option<Error> {
+    if is_signer_not_yet_valid(env, &get_signer_options(env, signer_key, signer_storage)) {
+        return Some(Error::SignerNotYetValid);
+    }
+
+    if is_signer_suspended(env, signer_key, signer_storage) {
+        return Some(Error::SignerSuspended);
+    }
+
+    if is_beneficiary_dormant(env, signer_key) {
+        return Some(Error::BeneficiaryDormant);
+    }
+
+    None
+}
@@ -4,9 +4,54 @@
 //! its signers alive indefinitely, funded by the transactions that use it.
 //! The extend threshold is one week of ledgers below max, so at most one
 //! bump per entry per week is actually written.
+//!
+//! That is the DEFAULT: a wallet may replace it with its own `TtlConfig`
+//! (`set_ttl_config`), with separate rules for authentication and mutation.
 
-use smart_wallet_interface::types::SignerKey;
-use soroban_sdk::Env;
+use smart_wallet_interface::types::{SignerKey, TtlConfig, TtlRule, TtlSchedule};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+/// Instance storage key of the wallet's `TtlConfig`. Absent = default.
+const TTL_CONFIG: Symbol = symbol_short!("ttl_cfg");
+
+/// Contract-data keys other than the signer entries themselves (which are
+/// keyed by the bare `SignerKey`) and the instance-storage `Symbol`s.
+///
+/// The per-signer variants are COMPANION entries (see `companion_keys`): each
+/// lives in its signer's own durability, is written and TTL-extended together
+/// with it, and moves or disappears with it — so a companion always shares
+/// its signer's TTL and a Temporary signer's companions cannot evict before
+/// the signer does.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StorageKey {
+    /// A signer's non-default `SignerOptions`.
+    SignerOptions(SignerKey),
+    /// Present (`true`) while the signer is suspended (`suspend_signer`).
+    Suspended(SignerKey),
+    /// The nonzero session generation a non-durable signer was added under
+    /// (see `is_session_revoked`).
+    SessionGeneration(SignerKey),
+    /// The signer's `SignerActivity`, once it has been used (see
+    /// `record_signer_use`).
+    SignerActivity(SignerKey),
+    /// Present (temporary) from an intent nonce's consumption until its
+    /// deadline (see `consume_intent_nonce`). Not a signer companion.
+    IntentNonce(u64),
+    /// Present (persistent) for each wallet deployed by `create_subaccount`
+    /// (see `record_subaccount`). Not a signer companion.
+    Subaccount(Address),
+}
+
+/// Every companion entry key a signer may have.
+pub fn companion_keys(signer_key: &SignerKey) -> [StorageKey; 4] {
+    [
+        StorageKey::SignerOptions(signer_key.clone()),
+        StorageKey::Suspended(signer_key.clone()),
+        StorageKey::SessionGeneration(signer_key.clone()),
+        StorageKey::SignerActivity(signer_key.clone()),
+    ]
+}
 
 /// One week of ledgers at the historical 5s close time. Close time can drift
 /// (CAP-0070 dynamic timing); this constant only shapes how often TTL bumps
@@ -22,28 +67,84 @@ fn extend_threshold(max_ttl: u32) -> u32 {
     max_ttl.saturating_sub(WEEK_OF_LEDGERS)
 }
 
-pub fn extend_instance(env: &Env) {
-    let max_ttl = env.storage().max_ttl();
+/// What a TTL extension is for, selecting the `TtlConfig` schedule.
+#[derive(Clone, Copy)]
+pub enum TtlTrigger {
+    /// `__check_auth` and `bump`.
+    Auth,
+    /// Wallet-authorized state changes.
+    Mutation,
+}
 
+pub fn get_ttl_config(env: &Env) -> Option<TtlConfig> {
     env.storage()
         .instance()
-        .extend_ttl(extend_threshold(max_ttl), max_ttl);
+        .get::<Symbol, TtlConfig>(&TTL_CONFIG)
 }
 
-pub fn extend_signer_key(env: &Env, signer_key: &SignerKey, persistent: bool) {
+pub fn set_ttl_config(env: &Env, config: &Option<TtlConfig>) {
+    match config {
+        Some(config) => env
+            .storage()
+            .instance()
+            .set::<Symbol, TtlConfig>(&TTL_CONFIG, config),
+        None => env.storage().instance().remove(&TTL_CONFIG),
+    }
+}
+
+/// The `(threshold, extend_to)` pair for one class of entry: the configured
+/// rule clamped to `max_ttl` (and the threshold to the target), or the
+/// default prepay.
+fn ttl_rule(
+    env: &Env,
+    trigger: TtlTrigger,
+    rule: impl FnOnce(TtlSchedule) -> TtlRule,
+) -> (u32, u32) {
     let max_ttl = env.storage().max_ttl();
 
+    match get_ttl_config(env) {
+        Some(config) => {
+            let rule = rule(match trigger {
+                TtlTrigger::Auth => config.auth,
+                TtlTrigger::Mutation => config.mutation,
+            });
+            let extend_to = rule.extend_to.min(max_ttl);
+
+            (rule.threshold.min(extend_to), extend_to)
+        }
+        None => (extend_threshold(max_ttl), max_ttl),
+    }
+}
+
+pub fn extend_instance(env: &Env, trigger: TtlTrigger) {
+    let (threshold, extend_to) = ttl_rule(env, trigger, |schedule| schedule.instance);
+
+    env.storage().instance().extend_ttl(threshold, extend_to);
+}
+
+/// Extend a signer entry and its companion entries (if present).
+pub fn extend_signer_key(env: &Env, signer_key: &SignerKey, persistent: bool, trigger: TtlTrigger) {
     if persistent {
-        env.storage().persistent().extend_ttl::<SignerKey>(
-            signer_key,
-            extend_threshold(max_ttl),
-            max_ttl,
-        );
+        let (threshold, extend_to) = ttl_rule(env, trigger, |schedule| schedule.persistent);
+        let storage = env.storage().persistent();
+
+        storage.extend_ttl::<SignerKey>(signer_key, threshold, extend_to);
+
+        for companion_key in companion_keys(signer_key) {
+            if storage.has::<StorageKey>(&companion_key) {
+                storage.extend_ttl::<StorageKey>(&companion_key, threshold, extend_to);
+            }
+        }
     } else {
-        env.storage().temporary().extend_ttl::<SignerKey>(
-            signer_key,
-            extend_threshold(max_ttl),
-            max_ttl,
-        );
+        let (threshold, extend_to) = ttl_rule(env, trigger, |schedule| schedule.temporary);
+        let storage = env.storage().temporary();
+
+        storage.extend_ttl::<SignerKey>(signer_key, threshold, extend_to);
+
+        for companion_key in companion_keys(signer_key) {
+            if storage.has::<StorageKey>(&companion_key) {
+                storage.extend_ttl::<StorageKey>(&companion_key, threshold, extend_to);
+            }
+        }
     }
 }
@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::StorageKey;
+
+pub fn is_subaccount(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has::<StorageKey>(&StorageKey::Subaccount(address.clone()))
+}
+
+/// Record `address` as deployed by this wallet's `create_subaccount`. The
+/// entry is never removed: the subaccount's signers may drop the parent, but
+/// the deployment stays a fact.
+pub fn record_subaccount(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .set::<StorageKey, bool>(&StorageKey::Subaccount(address.clone()), &true);
+}
@@ -2,11 +2,31 @@
 
 pub mod test_common;
 
+mod test_activity;
 mod test_admin;
 mod test_auth;
 mod test_base64_url;
+mod test_batch;
+mod test_counters;
 mod test_deploy;
 mod test_events;
+mod test_execute;
+mod test_factory;
+mod test_freeze;
 mod test_fuzz;
+mod test_inheritance;
 mod test_integration;
+mod test_intent;
+mod test_migration;
+mod test_prune;
+mod test_registry;
+mod test_secp256r1;
+mod test_sessions;
+mod test_signature;
+mod test_subaccount;
+mod test_suspend;
+mod test_ttl;
+mod test_upgrade;
+mod test_validity;
+mod test_web_auth;
 mod test_webauthn;
@@ -0,0 +1,232 @@
+#![cfg(test)]
+//! Usage tracking: per-signer `SignerActivity` written by successful
+//! authorizations only, at most once per `ACTIVITY_INTERVAL`, its companion
+//! entry's lifecycle and TTL, and the wallet-wide last-active timestamp.
+
+extern crate std;
+
+use smart_wallet_interface::types::{
+    Error, Signatures, SignerActivity, SignerExpiration, SignerKey, SignerLimits, SignerStorage,
+    ACTIVITY_INTERVAL,
+};
+use soroban_sdk::{
+    map,
+    testutils::{
+        storage::{Persistent as _, Temporary as _},
+        Address as _, Ledger as _,
+    },
+    vec, Address, Env, IntoVal,
+};
+
+use crate::storage::StorageKey;
+use crate::tests::test_common::*;
+
+const NOW: u64 = 1_000_000;
+
+fn setup<'a>(env: &Env) -> (Ed25519Signer, Address, crate::ContractClient<'a>) {
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(
+        env,
+        &owner.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    (owner, wallet, client)
+}
+
+fn check(
+    env: &Env,
+    wallet: &Address,
+    signers: &[&Ed25519Signer],
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let payload = payload(env, 6);
+    let mut signatures = map![env];
+    for signer in signers {
+        signatures.set(signer.signer_key(env), signer.sign(env, &payload));
+    }
+
+    env.try_invoke_contract_check_auth::<Error>(
+        wallet,
+        &payload,
+        Signatures(signatures).into_val(env),
+        &vec![
+            env,
+            transfer_context(env, &Address::generate(env), wallet, 1),
+        ],
+    )
+}
+
+fn used(last_used: u64, use_count: u32) -> Option<SignerActivity> {
+    Some(SignerActivity {
+        last_used,
+        use_count,
+    })
+}
+
+/// Every entry of a successful signatures map is recorded; signers that did
+/// not sign are untouched.
+#[test]
+fn successful_auth_records_each_signer() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let idle = Ed25519Signer::new(3);
+
+    client.mock_all_auths().add_signer(&device.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+    client.mock_all_auths().add_signer(&idle.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+
+    assert_eq!(
+        client.get_signer_activity(&owner.signer_key(&env)),
+        Some(SignerActivity::default())
+    );
+    assert_eq!(
+        client.get_signer_activity(&Ed25519Signer::new(9).signer_key(&env)),
+        None
+    );
+
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+    env.ledger().set_timestamp(NOW + ACTIVITY_INTERVAL);
+    assert_eq!(check(&env, &wallet, &[&owner, &device]), Ok(()));
+
+    assert_eq!(
+        client.get_signer_activity(&owner.signer_key(&env)),
+        used(NOW + ACTIVITY_INTERVAL, 2)
+    );
+    assert_eq!(
+        client.get_signer_activity(&device.signer_key(&env)),
+        used(NOW + ACTIVITY_INTERVAL, 1)
+    );
+    assert_eq!(
+        client.get_signer_activity(&idle.signer_key(&env)),
+        Some(SignerActivity::default())
+    );
+}
+
+/// A failed authorization records nothing, not even for the entries that
+/// verified before the failure.
+#[test]
+fn failed_auth_records_nothing() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let stranger = Ed25519Signer::new(9);
+
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &stranger]),
+        Err(Ok(Error::SignerNotFound))
+    );
+    assert_eq!(
+        client.get_signer_activity(&owner.signer_key(&env)),
+        Some(SignerActivity::default())
+    );
+    assert_eq!(client.get_last_active(), None);
+}
+
+#[test]
+fn last_active_tracks_successful_auth() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+
+    assert_eq!(client.get_last_active(), None);
+
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+    assert_eq!(client.get_last_active(), Some(NOW));
+
+    env.ledger().set_timestamp(NOW + ACTIVITY_INTERVAL);
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+    assert_eq!(client.get_last_active(), Some(NOW + ACTIVITY_INTERVAL));
+}
+
+/// Within `ACTIVITY_INTERVAL` of the last recorded use, an authorization
+/// writes neither the signer's record nor the wallet's last-active time.
+#[test]
+fn recent_activity_is_not_rewritten() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let key = owner.signer_key(&env);
+
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+
+    env.ledger().set_timestamp(NOW + ACTIVITY_INTERVAL - 1);
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+    assert_eq!(env.cost_estimate().resources().write_entries, 0);
+    assert_eq!(client.get_signer_activity(&key), used(NOW, 1));
+    assert_eq!(client.get_last_active(), Some(NOW));
+
+    env.ledger().set_timestamp(NOW + ACTIVITY_INTERVAL);
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+    assert_eq!(env.cost_estimate().resources().write_entries, 2);
+}
+
+fn activity_ttl(env: &Env, wallet: &Address, key: &SignerKey, persistent: bool) -> Option<u32> {
+    let activity_key = StorageKey::SignerActivity(key.clone());
+
+    env.as_contract(wallet, || {
+        if persistent {
+            let storage = env.storage().persistent();
+            storage
+                .has(&activity_key)
+                .then(|| storage.get_ttl(&activity_key))
+        } else {
+            let storage = env.storage().temporary();
+            storage
+                .has(&activity_key)
+                .then(|| storage.get_ttl(&activity_key))
+        }
+    })
+}
+
+/// The record is created at the maximum TTL (bumped with its signer), moves
+/// with the signer across durability flips, and is cleared by removal.
+#[test]
+fn activity_entry_lifecycle() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let key = device.signer_key(&env);
+    let plain = |storage: SignerStorage| {
+        device.signer(&env, SignerExpiration(None), SignerLimits(None), storage)
+    };
+
+    client
+        .mock_all_auths()
+        .add_signer(&plain(SignerStorage::Persistent));
+    assert_eq!(activity_ttl(&env, &wallet, &key, true), None);
+
+    assert_eq!(check(&env, &wallet, &[&device]), Ok(()));
+    let max_ttl = env.as_contract(&wallet, || env.storage().max_ttl());
+    assert_eq!(activity_ttl(&env, &wallet, &key, true), Some(max_ttl));
+
+    client
+        .mock_all_auths()
+        .update_signer(&plain(SignerStorage::Temporary));
+    assert_eq!(client.get_signer_activity(&key), used(NOW, 1));
+    assert_eq!(activity_ttl(&env, &wallet, &key, true), None);
+    assert!(activity_ttl(&env, &wallet, &key, false).is_some());
+
+    client.mock_all_auths().remove_signer(&key);
+    assert_eq!(activity_ttl(&env, &wallet, &key, false), None);
+
+    client
+        .mock_all_auths()
+        .add_signer(&plain(SignerStorage::Persistent));
+    assert_eq!(
+        client.get_signer_activity(&key),
+        Some(SignerActivity::default())
+    );
+}
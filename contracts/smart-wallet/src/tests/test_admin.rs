@@ -4,11 +4,13 @@
 
 extern crate std;
 
+use signer_registry::{Contract as RegistryContract, ContractClient as RegistryClient};
 use smart_wallet_interface::{
     events::{SignerAdded, SignerRemoved, SignerUpdated, Upgraded},
     types::{
-        Error, Signatures, Signer, SignerExpiration, SignerKey, SignerLimits, SignerStorage,
-        SignerVal,
+        Error, FeeConfig, InheritanceConfig, Signatures, Signer, SignerExpiration, SignerKey,
+        SignerLimits, SignerMetadata, SignerOptions, SignerStorage, SignerVal, WalletSetting,
+        WalletSettings, WebAuthConfig, MAX_SIGNER_LABEL_LEN,
     },
     PolicyInterface, SmartWalletClient,
 };
@@ -22,11 +24,6 @@ use soroban_sdk::{
 use crate::tests::test_common::*;
 use crate::{Contract, ContractClient};
 
-mod smart_wallet_wasm {
-    use soroban_sdk::auth::Context;
-    soroban_sdk::contractimport!(file = "fixtures/smart_wallet.wasm");
-}
-
 /// Policy that records install/uninstall state per wallet (the pattern
 /// stateful policies must follow) and allows everything.
 #[contract]
@@ -140,6 +137,7 @@ fn constructor_stores_signer_and_emits_event() {
         key: a.signer_key(&env),
         val: SignerVal::Ed25519(SignerExpiration(None), SignerLimits(None)),
         storage: SignerStorage::Persistent,
+        options: SignerOptions::default(),
     };
     assert_eq!(
         env.events().all(),
@@ -158,6 +156,282 @@ fn constructor_stores_signer_and_emits_event() {
     );
 }
 
+/// The constructor takes a whole initial set: every signer is stored and
+/// announced in order, policies are installed, and the counters see them all.
+#[test]
+fn constructor_stores_signer_set() {
+    let env = test_env();
+    let a = Ed25519Signer::new(1);
+    let b = Ed25519Signer::new(2);
+    let passkey = Passkey::new(3);
+    let policy = env.register(LifecyclePolicy, ());
+
+    let signers = vec![
+        &env,
+        a.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+        b.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+        passkey.signer(
+            &env,
+            SignerExpiration(Some(123_456)),
+            SignerLimits(None),
+            SignerStorage::Temporary,
+        ),
+        Signer::Policy(
+            policy.clone(),
+            SignerExpiration(None),
+            SignerLimits(Some(soroban_sdk::map![&env])),
+            SignerStorage::Persistent,
+            SignerOptions::default(),
+        ),
+    ];
+
+    let (wallet, client) = register_wallet_with(&env, &signers);
+
+    let expected = [
+        SignerAdded {
+            key: a.signer_key(&env),
+            val: SignerVal::Ed25519(SignerExpiration(None), SignerLimits(None)),
+            storage: SignerStorage::Persistent,
+            options: SignerOptions::default(),
+        },
+        SignerAdded {
+            key: b.signer_key(&env),
+            val: SignerVal::Ed25519(SignerExpiration(None), SignerLimits(None)),
+            storage: SignerStorage::Persistent,
+            options: SignerOptions::default(),
+        },
+        SignerAdded {
+            key: passkey.signer_key(&env),
+            val: SignerVal::Secp256r1(
+                passkey.public_key(&env),
+                SignerExpiration(Some(123_456)),
+                SignerLimits(None),
+            ),
+            storage: SignerStorage::Temporary,
+            options: SignerOptions::default(),
+        },
+        SignerAdded {
+            key: SignerKey::Policy(policy.clone()),
+            val: SignerVal::Policy(
+                SignerExpiration(None),
+                SignerLimits(Some(soroban_sdk::map![&env])),
+            ),
+            storage: SignerStorage::Persistent,
+            options: SignerOptions::default(),
+        },
+    ];
+    let mut expected_events = vec![&env];
+    for event in expected.iter() {
+        expected_events.push_back((wallet.clone(), event.topics(&env), event.data(&env)));
+    }
+    assert_eq!(env.events().all(), expected_events);
+
+    assert!(is_installed(&env, &policy, &wallet));
+    assert!(has_entry(
+        &env,
+        &wallet,
+        &passkey.signer_key(&env),
+        &SignerStorage::Temporary
+    ));
+
+    // Both initial admins were counted: one may go, the second may not.
+    client.mock_all_auths().remove_signer(&a.signer_key(&env));
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&b.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn constructor_rejects_empty_signer_set() {
+    let env = test_env();
+
+    register_wallet_with(&env, &vec![&env]);
+}
+
+/// The durable requirement applies to the set: several non-durable signers
+/// do not add up to a durable one.
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn constructor_rejects_set_without_durable_signer() {
+    let env = test_env();
+    let a = Ed25519Signer::new(1);
+    let b = Ed25519Signer::new(2);
+
+    register_wallet_with(
+        &env,
+        &vec![
+            &env,
+            a.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(None),
+                SignerStorage::Temporary,
+            ),
+            b.signer(
+                &env,
+                SignerExpiration(Some(u64::MAX)),
+                SignerLimits(None),
+                SignerStorage::Persistent,
+            ),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")]
+fn constructor_rejects_duplicate_keys() {
+    let env = test_env();
+    let a = Ed25519Signer::new(1);
+
+    register_wallet_with(
+        &env,
+        &vec![
+            &env,
+            a.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(None),
+                SignerStorage::Persistent,
+            ),
+            a.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(None),
+                SignerStorage::Temporary,
+            ),
+        ],
+    );
+}
+
+/// Constructor settings are applied as by their setters, the registry
+/// before the signers so the initial set is reported to it.
+#[test]
+fn constructor_applies_settings() {
+    let env = test_env();
+    let a = Ed25519Signer::new(1);
+    let b = Ed25519Signer::new(2);
+    let token = Address::generate(&env);
+    let registry = env.register(RegistryContract, ());
+    let admin = |signer: &Ed25519Signer| {
+        signer.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        )
+    };
+    let inheritance = InheritanceConfig {
+        beneficiary: b.signer_key(&env),
+        inactivity_period: 1_000,
+    };
+    let fee_config = FeeConfig {
+        max_fees: soroban_sdk::map![&env, (token, 10)],
+    };
+    let web_auth_config = WebAuthConfig {
+        contracts: soroban_sdk::map![
+            &env,
+            (
+                Address::generate(&env),
+                soroban_sdk::String::from_str(&env, "anchor.example.com")
+            )
+        ],
+    };
+
+    let (wallet, client) = register_wallet_with_settings(
+        &env,
+        &vec![&env, admin(&a), admin(&b)],
+        Some(WalletSettings(vec![
+            &env,
+            WalletSetting::FreezeQuorum(1),
+            WalletSetting::Inheritance(inheritance.clone()),
+            WalletSetting::FeeConfig(fee_config.clone()),
+            WalletSetting::SignerRegistry(registry.clone()),
+            WalletSetting::WebAuthConfig(web_auth_config.clone()),
+        ])),
+    );
+
+    assert_eq!(client.get_freeze_quorum(), 1);
+    assert_eq!(client.get_inheritance(), Some(inheritance));
+    assert_eq!(client.get_fee_config(), Some(fee_config));
+    assert_eq!(client.get_signer_registry(), Some(registry.clone()));
+    assert_eq!(client.get_web_auth_config(), Some(web_auth_config));
+    assert_eq!(
+        RegistryClient::new(&env, &registry).lookup(&a.signer_key(&env), &0, &10),
+        vec![&env, wallet]
+    );
+
+    // The beneficiary is not counted, so `a` is the only admin.
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&a.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn constructor_rejects_invalid_settings() {
+    let env = test_env();
+    let a = Ed25519Signer::new(1);
+
+    register_wallet_with_settings(
+        &env,
+        &vec![
+            &env,
+            a.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(None),
+                SignerStorage::Persistent,
+            ),
+        ],
+        Some(WalletSettings(vec![&env, WalletSetting::FreezeQuorum(0)])),
+    );
+}
+
+/// The inheritance beneficiary must be one of the initial signers.
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn constructor_rejects_unknown_beneficiary() {
+    let env = test_env();
+    let a = Ed25519Signer::new(1);
+
+    register_wallet_with_settings(
+        &env,
+        &vec![
+            &env,
+            a.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(None),
+                SignerStorage::Persistent,
+            ),
+        ],
+        Some(WalletSettings(vec![
+            &env,
+            WalletSetting::Inheritance(InheritanceConfig {
+                beneficiary: Ed25519Signer::new(2).signer_key(&env),
+                inactivity_period: 1_000,
+            }),
+        ])),
+    );
+}
+
 // --- add_signer --------------------------------------------------------------
 
 #[test]
@@ -244,6 +518,7 @@ fn add_signer_emits_event() {
             SignerLimits(None),
         ),
         storage: SignerStorage::Temporary,
+        options: SignerOptions::default(),
     };
     assert_eq!(
         env.events().all(),
@@ -326,6 +601,7 @@ fn update_signer_durability_flip() {
         val: SignerVal::Ed25519(SignerExpiration(Some(9_999)), SignerLimits(None)),
         storage: SignerStorage::Temporary,
         old_storage: SignerStorage::Persistent,
+        options: SignerOptions::default(),
     };
     assert_eq!(
         env.events().all(),
@@ -386,6 +662,7 @@ fn update_signer_same_durability() {
         val: SignerVal::Ed25519(SignerExpiration(Some(1_000)), SignerLimits(None)),
         storage: SignerStorage::Persistent,
         old_storage: SignerStorage::Persistent,
+        options: SignerOptions::default(),
     };
     assert_eq!(
         env.events().all(),
@@ -525,13 +802,14 @@ fn upgrade_emits_event_and_tracks_hash() {
         ),
     );
 
-    let wasm_hash = env.deployer().upload_contract_wasm(smart_wallet_wasm::WASM);
+    let genesis = genesis_hash(&wallet);
+    let wasm_hash = upload_wallet_wasm(&env);
 
-    // First upgrade: no cached previous hash — old_hash is None.
+    // First upgrade: the constructor cached the genesis hash.
     client.mock_all_auths().upgrade(&wasm_hash);
 
     let expected = Upgraded {
-        old_hash: None,
+        old_hash: Some(genesis),
         new_hash: wasm_hash.clone(),
     };
     assert_eq!(
@@ -583,7 +861,7 @@ fn upgrade_requires_auth() {
         ),
     );
 
-    let wasm_hash = env.deployer().upload_contract_wasm(smart_wallet_wasm::WASM);
+    let wasm_hash = upload_wallet_wasm(&env);
 
     assert!(client.try_upgrade(&wasm_hash).is_err());
 }
@@ -615,6 +893,7 @@ fn policy_install_uninstall_lifecycle() {
         SignerExpiration(None),
         SignerLimits(None),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
     assert!(is_installed(&env, &policy, &wallet));
 
@@ -648,6 +927,7 @@ fn constructor_installs_policy_signer() {
             SignerExpiration(None),
             SignerLimits(None),
             SignerStorage::Persistent,
+            SignerOptions::default(),
         ),
     );
 
@@ -678,6 +958,7 @@ fn rejecting_policy_cannot_be_added() {
             SignerExpiration(None),
             SignerLimits(None),
             SignerStorage::Persistent,
+            SignerOptions::default(),
         ))
         .is_err());
 
@@ -719,6 +1000,7 @@ fn budget_exhausting_uninstall_cannot_block_removal() {
         SignerExpiration(None),
         SignerLimits(None),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     client.mock_all_auths().remove_signer(&policy_key);
@@ -978,7 +1260,7 @@ fn last_admin_self_removal_fails_at_execution() {
     let root_auth = soroban_sdk::xdr::SorobanAuthorizationEntry {
         credentials: soroban_sdk::xdr::SorobanCredentials::Address(
             soroban_sdk::xdr::SorobanAddressCredentials {
-                address: wallet.clone().try_into().unwrap(),
+                address: wallet.clone().into(),
                 nonce,
                 signature_expiration_ledger,
                 signature: Signatures(soroban_sdk::map![
@@ -1010,7 +1292,11 @@ fn last_admin_self_removal_fails_at_execution() {
 /// Register a wallet at a PRE-GENERATED address so the constructor signer's
 /// limits can reference the wallet itself.
 fn register_wallet_at<'a>(env: &Env, wallet: &Address, signer: &Signer) -> ContractClient<'a> {
-    env.register_at(wallet, Contract, (signer.clone(),));
+    env.register_at(
+        wallet,
+        Contract,
+        (vec![env, signer.clone()], None::<WalletSettings>),
+    );
     ContractClient::new(env, wallet)
 }
 
@@ -1269,7 +1555,7 @@ fn sole_self_cosigner_signer_cannot_self_remove() {
     let root_auth = soroban_sdk::xdr::SorobanAuthorizationEntry {
         credentials: soroban_sdk::xdr::SorobanCredentials::Address(
             soroban_sdk::xdr::SorobanAddressCredentials {
-                address: wallet.clone().try_into().unwrap(),
+                address: wallet.clone().into(),
                 nonce,
                 signature_expiration_ledger,
                 signature: Signatures(map![&env, (key.clone(), a.sign(&env, &payload))])
@@ -1296,6 +1582,9 @@ fn sole_self_cosigner_signer_cannot_self_remove() {
     assert_eq!(client.mock_all_auths().try_remove_signer(&key), Ok(Ok(())));
 }
 
+/// Builds a sole signer's limits for a given wallet address.
+type LimitsBuilder = fn(&Env, &Address, &Ed25519Signer) -> SignerLimits;
+
 /// EVERY sole-signer shape's last removal is rejected. The error code shows
 /// which guard fired: `LastAdminSigner` for shapes the admin counter tracks,
 /// `LastSigner` (the backstop) for everything else — either way, zero
@@ -1305,11 +1594,7 @@ fn every_sole_signer_shape_last_removal_rejected() {
     let a = Ed25519Signer::new(1);
 
     // (limits-builder, expected error) per shape. Built per-env below.
-    let cases: std::vec::Vec<(
-        &str,
-        fn(&Env, &Address, &Ed25519Signer) -> SignerLimits,
-        Error,
-    )> = std::vec![
+    let cases: std::vec::Vec<(&str, LimitsBuilder, Error)> = std::vec![
         (
             "unlimited",
             |_, _, _| SignerLimits(None),
@@ -1395,6 +1680,7 @@ fn every_sole_signer_shape_last_removal_rejected() {
             SignerExpiration(None),
             SignerLimits(Some(map![&env])),
             SignerStorage::Persistent,
+            SignerOptions::default(),
         ),
     );
     assert_eq!(
@@ -1415,6 +1701,7 @@ fn every_sole_signer_shape_last_removal_rejected() {
             SignerExpiration(None),
             SignerLimits(Some(map![&env, (wallet.clone(), None)])),
             SignerStorage::Persistent,
+            SignerOptions::default(),
         ),
     );
     assert_eq!(
@@ -1700,3 +1987,79 @@ fn secp256r1_signer_roundtrip() {
         ))
     );
 }
+
+// --- Signer metadata ----------------------------------------------------------
+
+/// Metadata is stored with the options, emitted in the signer events, and
+/// its label bounded at `MAX_SIGNER_LABEL_LEN` bytes.
+#[test]
+fn signer_metadata_roundtrip_event_and_bound() {
+    let env = test_env();
+    let (wallet, client) = register_wallet(
+        &env,
+        &Ed25519Signer::new(1).signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+    let device = Ed25519Signer::new(2);
+    let options = |label: &str| SignerOptions {
+        metadata: SignerMetadata {
+            label: Some(soroban_sdk::String::from_str(&env, label)),
+            created_at: Some(1_690_000_000),
+            device: Some(soroban_sdk::Symbol::new(&env, "iphone")),
+        },
+        ..Default::default()
+    };
+    let labelled = |label: &str| {
+        with_options(
+            device.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(None),
+                SignerStorage::Persistent,
+            ),
+            options(label),
+        )
+    };
+
+    let too_long = "x".repeat(MAX_SIGNER_LABEL_LEN as usize + 1);
+    assert_eq!(
+        client.mock_all_auths().try_add_signer(&labelled(&too_long)),
+        Err(Ok(Error::InvalidSignerMetadata))
+    );
+
+    let at_bound = "x".repeat(MAX_SIGNER_LABEL_LEN as usize);
+    client.mock_all_auths().add_signer(&labelled(&at_bound));
+
+    client
+        .mock_all_auths()
+        .update_signer(&labelled("Alice's iPhone"));
+    let expected = SignerUpdated {
+        key: device.signer_key(&env),
+        val: SignerVal::Ed25519(SignerExpiration(None), SignerLimits(None)),
+        storage: SignerStorage::Persistent,
+        old_storage: SignerStorage::Persistent,
+        options: options("Alice's iPhone"),
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+    assert_eq!(
+        client.get_signer_options(&device.signer_key(&env)),
+        Some(options("Alice's iPhone"))
+    );
+
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_update_signer(&labelled(&too_long)),
+        Err(Ok(Error::InvalidSignerMetadata))
+    );
+}
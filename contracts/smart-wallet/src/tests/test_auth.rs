@@ -8,7 +8,7 @@ extern crate std;
 use smart_wallet_interface::{
     types::{
         Error, Signature, Signatures, Signer, SignerExpiration, SignerKey, SignerLimits,
-        SignerStorage,
+        SignerOptions, SignerStorage, WalletSettings,
     },
     PolicyInterface,
 };
@@ -936,6 +936,7 @@ fn policy_as_signature_called_once_with_all_contexts() {
         SignerExpiration(None),
         no_limits(),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
@@ -1107,6 +1108,7 @@ fn policy_co_signer_own_limits_not_enforced() {
             Some(vec![&env, SignerKey::Policy(policy_b.clone())]),
         ),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
     client.mock_all_auths().add_signer(&Signer::Policy(
         policy_b.clone(),
@@ -1117,6 +1119,7 @@ fn policy_co_signer_own_limits_not_enforced() {
             Some(vec![&env, SignerKey::Policy(policy_a.clone())]),
         ),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
@@ -1205,6 +1208,7 @@ fn rejecting_policy_as_signature_fails_auth() {
         SignerExpiration(None),
         empty_limits(&env),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
@@ -1268,6 +1272,7 @@ fn rejecting_policy_can_self_remove() {
         SignerExpiration(None),
         empty_limits(&env),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
@@ -1310,6 +1315,7 @@ fn policy_self_removal_skip_requires_sole_context() {
         SignerExpiration(None),
         empty_limits(&env),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
@@ -1354,12 +1360,19 @@ fn sole_rejecting_policy_self_removal_blocked_by_backstop() {
     env.register_at(
         &wallet,
         Contract,
-        (Signer::Policy(
-            policy.clone(),
-            SignerExpiration(None),
-            empty_limits(&env),
-            SignerStorage::Persistent,
-        ),),
+        (
+            vec![
+                &env,
+                Signer::Policy(
+                    policy.clone(),
+                    SignerExpiration(None),
+                    empty_limits(&env),
+                    SignerStorage::Persistent,
+                    SignerOptions::default(),
+                ),
+            ],
+            None::<WalletSettings>,
+        ),
     );
     let client = ContractClient::new(&env, &wallet);
 
@@ -1384,7 +1397,7 @@ fn sole_rejecting_policy_self_removal_blocked_by_backstop() {
     let root_auth = soroban_sdk::xdr::SorobanAuthorizationEntry {
         credentials: soroban_sdk::xdr::SorobanCredentials::Address(
             soroban_sdk::xdr::SorobanAddressCredentials {
-                address: wallet.clone().try_into().unwrap(),
+                address: wallet.clone().into(),
                 nonce,
                 signature_expiration_ledger,
                 signature: Signatures(map![&env, (policy_key.clone(), Signature::Policy)])
@@ -1428,6 +1441,7 @@ fn policy_signature_removing_other_key_still_consults_policy() {
         SignerExpiration(None),
         no_limits(),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
@@ -1473,6 +1487,7 @@ fn rejecting_policy_self_removal_full_stack() {
         SignerExpiration(None),
         empty_limits(&env),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let root_invocation = remove_signer_invocation(&env, &wallet, &policy_key);
@@ -1482,7 +1497,7 @@ fn rejecting_policy_self_removal_full_stack() {
     let root_auth = soroban_sdk::xdr::SorobanAuthorizationEntry {
         credentials: soroban_sdk::xdr::SorobanCredentials::Address(
             soroban_sdk::xdr::SorobanAddressCredentials {
-                address: wallet.clone().try_into().unwrap(),
+                address: wallet.clone().into(),
                 nonce,
                 signature_expiration_ledger,
                 signature: Signatures(map![&env, (policy_key.clone(), Signature::Policy)])
@@ -1519,12 +1534,19 @@ fn sole_admin_capable_policy_cannot_self_remove() {
     env.register_at(
         &wallet,
         Contract,
-        (Signer::Policy(
-            policy.clone(),
-            SignerExpiration(None),
-            contract_limits(&env, &wallet, None),
-            SignerStorage::Persistent,
-        ),),
+        (
+            vec![
+                &env,
+                Signer::Policy(
+                    policy.clone(),
+                    SignerExpiration(None),
+                    contract_limits(&env, &wallet, None),
+                    SignerStorage::Persistent,
+                    SignerOptions::default(),
+                ),
+            ],
+            None::<WalletSettings>,
+        ),
     );
     let client = ContractClient::new(&env, &wallet);
 
@@ -1551,7 +1573,7 @@ fn sole_admin_capable_policy_cannot_self_remove() {
     let root_auth = soroban_sdk::xdr::SorobanAuthorizationEntry {
         credentials: soroban_sdk::xdr::SorobanCredentials::Address(
             soroban_sdk::xdr::SorobanAddressCredentials {
-                address: wallet.clone().try_into().unwrap(),
+                address: wallet.clone().into(),
                 nonce,
                 signature_expiration_ledger,
                 signature: Signatures(map![&env, (policy_key.clone(), Signature::Policy)])
@@ -1691,6 +1713,7 @@ fn sample_policy_charged_once_per_authorization() {
         SignerExpiration(None),
         empty_limits(&env),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
@@ -1796,6 +1819,7 @@ fn duplicated_sample_policy_entry_charged_once() {
         SignerExpiration(None),
         empty_limits(&env),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
@@ -1889,6 +1913,66 @@ fn temporary_signer_auth_extends_ttl() {
     );
 }
 
+/// `bump` is permissionless and extends the instance and the listed signer
+/// entries (with their companions) to max TTL, skipping unknown keys.
+#[test]
+fn bump_extends_ttl_without_auth() {
+    use crate::storage::StorageKey;
+    use soroban_sdk::testutils::storage::{Instance as _, Persistent as _, Temporary as _};
+
+    let env = test_env();
+    let a = Ed25519Signer::new(1);
+    let b = Ed25519Signer::new(2);
+    let unknown = Ed25519Signer::new(3);
+
+    let (wallet, client) = register_wallet(
+        &env,
+        &a.signer(
+            &env,
+            SignerExpiration(None),
+            no_limits(),
+            SignerStorage::Persistent,
+        ),
+    );
+    client.mock_all_auths().add_signer(&b.signer(
+        &env,
+        SignerExpiration(None),
+        no_limits(),
+        SignerStorage::Temporary,
+    ));
+    client.mock_all_auths().suspend_signer(&b.signer_key(&env));
+
+    env.ledger().with_mut(|l| l.sequence_number += 200_000);
+
+    let a_key = a.signer_key(&env);
+    let b_key = b.signer_key(&env);
+    let suspended_key = StorageKey::Suspended(b_key.clone());
+    let ttls = || {
+        env.as_contract(&wallet, || {
+            (
+                env.storage().instance().get_ttl(),
+                env.storage().persistent().get_ttl(&a_key),
+                env.storage().temporary().get_ttl(&b_key),
+                env.storage().temporary().get_ttl(&suspended_key),
+            )
+        })
+    };
+    let max_ttl = env.as_contract(&wallet, || env.storage().max_ttl());
+    assert_ne!(ttls(), (max_ttl, max_ttl, max_ttl, max_ttl));
+
+    client.bump(&vec![
+        &env,
+        a_key.clone(),
+        b_key.clone(),
+        unknown.signer_key(&env),
+    ]);
+
+    assert_eq!(env.auths(), std::vec![]);
+    assert_eq!(ttls(), (max_ttl, max_ttl, max_ttl, max_ttl));
+    assert_eq!(client.get_signer(&unknown.signer_key(&env)), None);
+    assert_eq!(client.is_signer_suspended(&b_key), Some(true));
+}
+
 /// An expired stored policy referenced in limits rejects the candidate
 /// (boolean, in pass 1 — such keys never reach pass 2).
 #[test]
@@ -1920,6 +2004,7 @@ fn expired_stored_policy_limit_key_rejects() {
         SignerExpiration(Some(1)),
         no_limits(),
         SignerStorage::Persistent,
+        SignerOptions::default(),
     ));
 
     let payload = payload(&env, 7);
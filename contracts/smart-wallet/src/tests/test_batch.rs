@@ -0,0 +1,403 @@
+#![cfg(test)]
+//! `apply_signer_changes`: atomic batches under one wallet auth, guards
+//! checked against the batch's final state, and all-or-nothing rollback.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::{SignerAdded, SignerRemoved, SignerUpdated},
+    types::{
+        Error, Signatures, SignerExpiration, SignerKey, SignerLimits, SignerOp, SignerOptions,
+        SignerStorage, SignerVal,
+    },
+};
+use soroban_sdk::{map, testutils::Events as _, vec, Address, Env, Event as _, IntoVal};
+
+use crate::tests::test_admin::LifecyclePolicy;
+use crate::tests::test_common::*;
+
+fn durable_admin(env: &Env, signer: &Ed25519Signer) -> SignerOp {
+    SignerOp::Add(signer.signer(
+        env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ))
+}
+
+fn admin_wallet<'a>(env: &Env, seed: u8) -> (Ed25519Signer, Address, crate::ContractClient<'a>) {
+    let admin = Ed25519Signer::new(seed);
+    let (wallet, client) = register_wallet(
+        env,
+        &admin.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    (admin, wallet, client)
+}
+
+/// The motivating case: swap a wallet's ONLY admin for a new one in one
+/// call, in either order — the intermediate zero-admin state is fine.
+#[test]
+fn rotate_sole_admin_in_one_call() {
+    let env = test_env();
+
+    for remove_first in [true, false] {
+        let (old, _, client) = admin_wallet(&env, 1);
+        let new = Ed25519Signer::new(2);
+
+        let ops = if remove_first {
+            vec![
+                &env,
+                SignerOp::Remove(old.signer_key(&env)),
+                durable_admin(&env, &new),
+            ]
+        } else {
+            vec![
+                &env,
+                durable_admin(&env, &new),
+                SignerOp::Remove(old.signer_key(&env)),
+            ]
+        };
+
+        assert_eq!(
+            client.mock_all_auths().try_apply_signer_changes(&ops),
+            Ok(Ok(()))
+        );
+        assert_eq!(client.get_signer(&old.signer_key(&env)), None);
+        assert!(client.get_signer(&new.signer_key(&env)).is_some());
+
+        // The counters followed the batch: the new admin is now the last one.
+        assert_eq!(
+            client
+                .mock_all_auths()
+                .try_remove_signer(&new.signer_key(&env)),
+            Err(Ok(Error::LastAdminSigner))
+        );
+    }
+}
+
+#[test]
+fn batch_requires_wallet_auth_once() {
+    let env = test_env();
+    let (old, wallet, client) = admin_wallet(&env, 1);
+    let new = Ed25519Signer::new(2);
+    let ops = vec![
+        &env,
+        durable_admin(&env, &new),
+        SignerOp::Remove(old.signer_key(&env)),
+    ];
+
+    assert!(client.try_apply_signer_changes(&ops).is_err());
+
+    client.mock_all_auths().apply_signer_changes(&ops);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, wallet);
+}
+
+/// Each op emits exactly the event its single-signer entrypoint would, in
+/// batch order.
+#[test]
+fn batch_emits_per_op_events_in_order() {
+    let env = test_env();
+    let (old, wallet, client) = admin_wallet(&env, 1);
+    let new = Ed25519Signer::new(2);
+
+    client.mock_all_auths().apply_signer_changes(&vec![
+        &env,
+        durable_admin(&env, &new),
+        SignerOp::Update(new.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        )),
+        SignerOp::Remove(old.signer_key(&env)),
+    ]);
+
+    let added = SignerAdded {
+        key: new.signer_key(&env),
+        val: SignerVal::Ed25519(SignerExpiration(None), SignerLimits(None)),
+        storage: SignerStorage::Persistent,
+        options: SignerOptions::default(),
+    };
+    let updated = SignerUpdated {
+        key: new.signer_key(&env),
+        val: SignerVal::Ed25519(SignerExpiration(None), SignerLimits(None)),
+        storage: SignerStorage::Persistent,
+        old_storage: SignerStorage::Persistent,
+        options: SignerOptions::default(),
+    };
+    let removed = SignerRemoved {
+        key: old.signer_key(&env),
+        storage: SignerStorage::Persistent,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), added.topics(&env), added.data(&env)),
+            (wallet.clone(), updated.topics(&env), updated.data(&env)),
+            (wallet.clone(), removed.topics(&env), removed.data(&env)),
+        ]
+    );
+}
+
+/// A batch whose FINAL state has no durable admin is rejected, even though
+/// every step on its own would be accepted from the step before.
+#[test]
+fn batch_ending_without_admin_rejected() {
+    let env = test_env();
+    let (old, _, client) = admin_wallet(&env, 1);
+    let limited = Ed25519Signer::new(2);
+    let temporary = Ed25519Signer::new(3);
+
+    for replacement in [
+        limited.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(Some(map![&env])),
+            SignerStorage::Persistent,
+        ),
+        temporary.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Temporary,
+        ),
+    ] {
+        assert_eq!(
+            client.mock_all_auths().try_apply_signer_changes(&vec![
+                &env,
+                SignerOp::Add(replacement),
+                SignerOp::Remove(old.signer_key(&env)),
+            ]),
+            Err(Ok(Error::LastAdminSigner))
+        );
+    }
+
+    // Demotion counts too.
+    assert_eq!(
+        client.mock_all_auths().try_apply_signer_changes(&vec![
+            &env,
+            durable_admin(&env, &limited),
+            SignerOp::Update(old.signer(
+                &env,
+                SignerExpiration(Some(u64::MAX)),
+                SignerLimits(None),
+                SignerStorage::Persistent,
+            )),
+            SignerOp::Remove(limited.signer_key(&env)),
+        ]),
+        Err(Ok(Error::LastAdminSigner))
+    );
+
+    assert!(client.get_signer(&old.signer_key(&env)).is_some());
+    assert_eq!(client.get_signer(&limited.signer_key(&env)), None);
+    assert_eq!(client.get_signer(&temporary.signer_key(&env)), None);
+}
+
+/// The durable backstop is also checked against the final state: a wallet
+/// whose only durable signer is limited (no admin) cannot swap it for a
+/// non-durable one, whatever the order.
+#[test]
+fn batch_ending_without_durable_signer_rejected() {
+    let env = test_env();
+    let durable = Ed25519Signer::new(1);
+    let temporary = Ed25519Signer::new(2);
+
+    let (_, client) = register_wallet(
+        &env,
+        &durable.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(Some(map![&env])),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    assert_eq!(
+        client.mock_all_auths().try_apply_signer_changes(&vec![
+            &env,
+            SignerOp::Remove(durable.signer_key(&env)),
+            SignerOp::Add(temporary.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(None),
+                SignerStorage::Temporary,
+            )),
+        ]),
+        Err(Ok(Error::LastSigner))
+    );
+
+    // Replacing it with another durable signer is fine.
+    assert_eq!(
+        client.mock_all_auths().try_apply_signer_changes(&vec![
+            &env,
+            SignerOp::Remove(durable.signer_key(&env)),
+            SignerOp::Add(temporary.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(Some(map![&env])),
+                SignerStorage::Persistent,
+            )),
+        ]),
+        Ok(Ok(()))
+    );
+}
+
+/// Any failing op rolls back every op before it: no storage, no events.
+#[test]
+fn failing_op_rolls_back_whole_batch() {
+    let env = test_env();
+    let (old, _, client) = admin_wallet(&env, 1);
+    let new = Ed25519Signer::new(2);
+    let unknown = Ed25519Signer::new(3);
+
+    let cases = [
+        (
+            vec![&env, durable_admin(&env, &new), durable_admin(&env, &new)],
+            Error::SignerAlreadyExists,
+        ),
+        (
+            vec![
+                &env,
+                durable_admin(&env, &new),
+                SignerOp::Remove(old.signer_key(&env)),
+                SignerOp::Remove(unknown.signer_key(&env)),
+            ],
+            Error::SignerNotFound,
+        ),
+        (
+            vec![
+                &env,
+                durable_admin(&env, &new),
+                SignerOp::Update(unknown.signer(
+                    &env,
+                    SignerExpiration(None),
+                    SignerLimits(None),
+                    SignerStorage::Persistent,
+                )),
+            ],
+            Error::SignerNotFound,
+        ),
+    ];
+
+    for (ops, error) in cases {
+        assert_eq!(
+            client.mock_all_auths().try_apply_signer_changes(&ops),
+            Err(Ok(error))
+        );
+        assert_eq!(env.events().all(), []);
+        assert!(client.get_signer(&old.signer_key(&env)).is_some());
+        assert_eq!(client.get_signer(&new.signer_key(&env)), None);
+    }
+
+    // The rolled-back adds left the counters untouched.
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&old.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+}
+
+#[test]
+fn empty_batch_is_noop() {
+    let env = test_env();
+    let (admin, _, client) = admin_wallet(&env, 1);
+
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_apply_signer_changes(&vec![&env]),
+        Ok(Ok(()))
+    );
+    assert_eq!(env.events().all(), []);
+    assert!(client.get_signer(&admin.signer_key(&env)).is_some());
+}
+
+/// A policy added in a batch gets its `install` hook, as with `add_signer`.
+#[test]
+fn batch_add_installs_policy() {
+    let env = test_env();
+    let (_, wallet, client) = admin_wallet(&env, 1);
+    let policy = env.register(LifecyclePolicy, ());
+
+    client.mock_all_auths().apply_signer_changes(&vec![
+        &env,
+        SignerOp::Add(smart_wallet_interface::types::Signer::Policy(
+            policy.clone(),
+            SignerExpiration(None),
+            SignerLimits(Some(map![&env])),
+            SignerStorage::Persistent,
+            SignerOptions::default(),
+        )),
+    ]);
+
+    let installed: Option<bool> = env.as_contract(&policy, || {
+        env.storage()
+            .persistent()
+            .get(&crate::tests::test_admin::LifecycleKey::Installed(wallet))
+    });
+    assert_eq!(installed, Some(true));
+}
+
+/// Authorization is the wallet-self `apply_signer_changes` context: a
+/// wallet-self grant covers it, but a limited signer's self-removal
+/// exception does not extend to a batch that only removes itself.
+#[test]
+fn batch_authorization_is_wallet_self_context() {
+    let env = test_env();
+    let (_, wallet, client) = admin_wallet(&env, 1);
+    let granted = Ed25519Signer::new(2);
+    let limited = Ed25519Signer::new(3);
+
+    client.mock_all_auths().add_signer(&granted.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env, (wallet.clone(), None)])),
+        SignerStorage::Persistent,
+    ));
+    client.mock_all_auths().add_signer(&limited.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env])),
+        SignerStorage::Persistent,
+    ));
+
+    let batch_context = |key: &SignerKey| {
+        contract_context(
+            &env,
+            &wallet,
+            "apply_signer_changes",
+            vec![
+                &env,
+                vec![&env, SignerOp::Remove(key.clone())].into_val(&env),
+            ],
+        )
+    };
+
+    let payload = payload(&env, 1);
+    let check = |signer: &Ed25519Signer| {
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (signer.signer_key(&env), signer.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &vec![&env, batch_context(&signer.signer_key(&env))],
+        )
+    };
+
+    assert_eq!(check(&granted), Ok(()));
+    assert_eq!(check(&limited), Err(Ok(Error::MissingContext)));
+}
@@ -9,7 +9,8 @@ use p256::ecdsa::{
 };
 use sha2::{Digest, Sha256};
 use smart_wallet_interface::types::{
-    Secp256r1Signature, Signature, Signer, SignerExpiration, SignerKey, SignerLimits, SignerStorage,
+    Secp256r1Signature, Signature, Signer, SignerExpiration, SignerKey, SignerLimits,
+    SignerOptions, SignerStorage, WalletSettings,
 };
 use soroban_sdk::{
     auth::{Context, ContractContext},
@@ -18,7 +19,7 @@ use soroban_sdk::{
         HashIdPreimage, HashIdPreimageSorobanAuthorization, InvokeContractArgs, Limits, ScVal,
         SorobanAuthorizedFunction, SorobanAuthorizedInvocation, ToXdr, VecM, WriteXdr,
     },
-    Address, Bytes, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+    Address, Bytes, BytesN, Env, Executable, IntoVal, Symbol, TryFromVal, Val, Vec,
 };
 
 use crate::{Contract, ContractClient};
@@ -33,13 +34,76 @@ pub fn test_env() -> Env {
     env
 }
 
+/// Register a wallet whose constructor receives the single `signer`.
 pub fn register_wallet<'a>(env: &Env, signer: &Signer) -> (Address, ContractClient<'a>) {
-    let address = env.register(Contract, (signer.clone(),));
+    register_wallet_with(env, &soroban_sdk::vec![env, signer.clone()])
+}
+
+/// Register a wallet with a full initial signer set.
+pub fn register_wallet_with<'a>(env: &Env, signers: &Vec<Signer>) -> (Address, ContractClient<'a>) {
+    register_wallet_with_settings(env, signers, None)
+}
+
+/// Register a wallet with a full initial signer set and constructor settings.
+pub fn register_wallet_with_settings<'a>(
+    env: &Env,
+    signers: &Vec<Signer>,
+    settings: Option<WalletSettings>,
+) -> (Address, ContractClient<'a>) {
+    let address = env.register(Contract, (signers.clone(), settings));
     let client = ContractClient::new(env, &address);
 
     (address, client)
 }
 
+mod smart_wallet_wasm {
+    use soroban_sdk::auth::Context;
+    soroban_sdk::contractimport!(file = "fixtures/smart_wallet.wasm");
+}
+
+/// Upload the wasm build of this contract (for the upgrade paths). Upload is
+/// a transaction of its own, and parsing a wasm this size alone exceeds the
+/// default test budget, so it runs on an unlimited budget; the default is
+/// restored for whatever the test does next. The fixture is the plain cargo
+/// build, not the optimized artifact `stellar contract build` deploys, so
+/// mainnet resource limits (the code entry size cap among them) are disabled
+/// for the rest of the test.
+pub fn upload_wallet_wasm(env: &Env) -> BytesN<32> {
+    env.cost_estimate().disable_resource_limits();
+    env.cost_estimate().budget().reset_unlimited();
+    let wasm_hash = env.deployer().upload_contract_wasm(smart_wallet_wasm::WASM);
+    env.cost_estimate().budget().reset_default();
+
+    wasm_hash
+}
+
+/// The wasm hash `wallet` was deployed with, which its constructor caches. A
+/// natively registered contract reports the hash of empty code.
+pub fn genesis_hash(wallet: &Address) -> BytesN<32> {
+    match wallet.executable() {
+        Some(Executable::Wasm(hash)) => hash,
+        _ => panic!("wallet is not a wasm contract"),
+    }
+}
+
+/// `signer` with its trailing `SignerOptions` replaced.
+pub fn with_options(signer: Signer, options: SignerOptions) -> Signer {
+    match signer {
+        Signer::Policy(policy, expiration, limits, storage, _) => {
+            Signer::Policy(policy, expiration, limits, storage, options)
+        }
+        Signer::Ed25519(public_key, expiration, limits, storage, _) => {
+            Signer::Ed25519(public_key, expiration, limits, storage, options)
+        }
+        Signer::Secp256r1(id, public_key, expiration, limits, storage, _) => {
+            Signer::Secp256r1(id, public_key, expiration, limits, storage, options)
+        }
+        Signer::Address(address, expiration, limits, storage, _) => {
+            Signer::Address(address, expiration, limits, storage, options)
+        }
+    }
+}
+
 // --- Ed25519 -----------------------------------------------------------
 
 pub struct Ed25519Signer {
@@ -74,7 +138,13 @@ impl Ed25519Signer {
         limits: SignerLimits,
         storage: SignerStorage,
     ) -> Signer {
-        Signer::Ed25519(self.public_key(env), expiration, limits, storage)
+        Signer::Ed25519(
+            self.public_key(env),
+            expiration,
+            limits,
+            storage,
+            SignerOptions::default(),
+        )
     }
 
     pub fn sign(&self, env: &Env, payload: &BytesN<32>) -> Signature {
@@ -148,6 +218,17 @@ impl Passkey {
         BytesN::from_array(env, &self.public_key_bytes)
     }
 
+    /// The SEC-1 compressed (33-byte) encoding of the same public key.
+    pub fn compressed_public_key(&self, env: &Env) -> Bytes {
+        Bytes::from_slice(
+            env,
+            self.signing_key
+                .verifying_key()
+                .to_encoded_point(true)
+                .as_bytes(),
+        )
+    }
+
     pub fn signer_key(&self, env: &Env) -> SignerKey {
         SignerKey::Secp256r1(self.key_id(env))
     }
@@ -161,10 +242,11 @@ impl Passkey {
     ) -> Signer {
         Signer::Secp256r1(
             self.key_id(env),
-            self.public_key(env),
+            self.public_key(env).into(),
             expiration,
             limits,
             storage,
+            SignerOptions::default(),
         )
     }
 
@@ -302,15 +384,11 @@ pub fn transfer_invocation(
 ) -> SorobanAuthorizedInvocation {
     SorobanAuthorizedInvocation {
         function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
-            contract_address: token.clone().try_into().unwrap(),
+            contract_address: token.clone().into(),
             function_name: "transfer".try_into().unwrap(),
-            args: std::vec![
-                from.clone().try_into().unwrap(),
-                to.clone().try_into().unwrap(),
-                amount.try_into().unwrap(),
-            ]
-            .try_into()
-            .unwrap(),
+            args: std::vec![from.clone().into(), to.clone().into(), amount.into(),]
+                .try_into()
+                .unwrap(),
         }),
         sub_invocations: VecM::default(),
     }
@@ -328,7 +406,7 @@ pub fn remove_signer_invocation(
 
     SorobanAuthorizedInvocation {
         function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
-            contract_address: wallet.clone().try_into().unwrap(),
+            contract_address: wallet.clone().into(),
             function_name: "remove_signer".try_into().unwrap(),
             args: std::vec![key_scval].try_into().unwrap(),
         }),
@@ -336,6 +414,27 @@ pub fn remove_signer_invocation(
     }
 }
 
+/// Build the wallet-self `execute(calls)` `SorobanAuthorizedInvocation` for
+/// full-stack tests. The calls are invoked by the wallet itself, so they
+/// need no sub-invocations.
+pub fn execute_invocation(
+    env: &Env,
+    wallet: &Address,
+    calls: &Vec<(Address, Symbol, Vec<Val>)>,
+) -> SorobanAuthorizedInvocation {
+    let calls_val: Val = calls.into_val(env);
+    let calls_scval = ScVal::try_from_val(env, &calls_val).unwrap();
+
+    SorobanAuthorizedInvocation {
+        function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+            contract_address: wallet.clone().into(),
+            function_name: "execute".try_into().unwrap(),
+            args: std::vec![calls_scval].try_into().unwrap(),
+        }),
+        sub_invocations: VecM::default(),
+    }
+}
+
 /// Extract the last-32-byte ed25519/contract raw key from an Address.
 pub fn address_raw_bytes(env: &Env, address: &Address) -> [u8; 32] {
     let bytes = address.clone().to_xdr(env);
@@ -0,0 +1,177 @@
+#![cfg(test)]
+//! `reconcile_counters`: re-deriving the admin/durable counters of a wallet
+//! upgraded from a pre-1.0 wasm, which starts both at zero.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::CountersReconciled,
+    types::{Error, Signer, SignerExpiration, SignerLimits, SignerStorage},
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    vec, Address, Env, Event as _, Symbol,
+};
+
+use crate::tests::test_common::*;
+
+const NOW: u64 = 1_000_000;
+
+fn durable_admin(env: &Env, signer: &Ed25519Signer) -> Signer {
+    signer.signer(
+        env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    )
+}
+
+/// Drop both counters, as on a wallet upgraded from a pre-1.0 wasm.
+fn forget_counters(env: &Env, wallet: &Address) {
+    env.as_contract(wallet, || {
+        env.storage().instance().remove(&symbol_short!("admins"));
+        env.storage().instance().remove(&symbol_short!("durable"));
+    });
+}
+
+fn counters(env: &Env, wallet: &Address) -> (u32, u32) {
+    env.as_contract(wallet, || {
+        let get = |key: Symbol| {
+            env.storage()
+                .instance()
+                .get::<Symbol, u32>(&key)
+                .unwrap_or(0)
+        };
+
+        (get(symbol_short!("admins")), get(symbol_short!("durable")))
+    })
+}
+
+/// A legacy wallet's original admins are pinned until they are named; after
+/// reconciling, the original passkey can be rotated out.
+#[test]
+fn reconcile_counters_unpins_legacy_admins() {
+    let env = test_env();
+    let original = Ed25519Signer::new(1);
+    let second = Ed25519Signer::new(2);
+    let (wallet, client) = register_wallet(&env, &durable_admin(&env, &original));
+
+    client
+        .mock_all_auths()
+        .add_signer(&durable_admin(&env, &second));
+    forget_counters(&env, &wallet);
+
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&original.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+
+    client.mock_all_auths().reconcile_counters(&vec![
+        &env,
+        original.signer_key(&env),
+        second.signer_key(&env),
+    ]);
+
+    let expected = CountersReconciled {
+        admin_count: 2,
+        durable_count: 2,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+
+    client
+        .mock_all_auths()
+        .remove_signer(&original.signer_key(&env));
+    assert_eq!(counters(&env, &wallet), (1, 1));
+}
+
+/// Keys are classified as the counters classify them, and a counter is never
+/// lowered by listing fewer signers than it already accounts for.
+#[test]
+fn reconcile_counters_classifies_and_never_lowers() {
+    let env = test_env();
+    env.ledger().set_timestamp(NOW);
+    let owner = Ed25519Signer::new(1);
+    let suspended = Ed25519Signer::new(2);
+    let expiring = Ed25519Signer::new(3);
+    let session = Ed25519Signer::new(4);
+    let (wallet, client) = register_wallet(&env, &durable_admin(&env, &owner));
+
+    client
+        .mock_all_auths()
+        .add_signer(&durable_admin(&env, &suspended));
+    client
+        .mock_all_auths()
+        .suspend_signer(&suspended.signer_key(&env));
+    client.mock_all_auths().add_signer(&expiring.signer(
+        &env,
+        SignerExpiration(Some(NOW + 100)),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    client.mock_all_auths().add_signer(&session.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+    forget_counters(&env, &wallet);
+
+    client.mock_all_auths().reconcile_counters(&vec![
+        &env,
+        owner.signer_key(&env),
+        suspended.signer_key(&env),
+        expiring.signer_key(&env),
+        session.signer_key(&env),
+    ]);
+    // A suspended admin is durable but not counted as an admin.
+    assert_eq!(counters(&env, &wallet), (1, 2));
+
+    client
+        .mock_all_auths()
+        .reconcile_counters(&vec![&env, owner.signer_key(&env)]);
+    assert_eq!(counters(&env, &wallet), (1, 2));
+}
+
+/// Requires wallet auth, and refuses repeated keys (which would overcount)
+/// and keys that are not stored.
+#[test]
+fn reconcile_counters_rejects_unsafe_key_sets() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let unknown = Ed25519Signer::new(2);
+    let (wallet, client) = register_wallet(&env, &durable_admin(&env, &owner));
+    forget_counters(&env, &wallet);
+
+    assert!(client
+        .try_reconcile_counters(&vec![&env, owner.signer_key(&env)])
+        .is_err());
+
+    assert_eq!(
+        client.mock_all_auths().try_reconcile_counters(&vec![
+            &env,
+            owner.signer_key(&env),
+            owner.signer_key(&env),
+        ]),
+        Err(Ok(Error::DuplicateSignerKey))
+    );
+
+    assert_eq!(
+        client.mock_all_auths().try_reconcile_counters(&vec![
+            &env,
+            owner.signer_key(&env),
+            unknown.signer_key(&env),
+        ]),
+        Err(Ok(Error::SignerNotFound))
+    );
+
+    assert_eq!(counters(&env, &wallet), (0, 0));
+}
@@ -2,6 +2,7 @@
 //! The wallet as a deployer: a real `CreateContractV2HostFn` authorization
 //! through the host, plus the negative (limited signers cannot deploy —
 //! that rule's unit coverage lives in test_auth.rs).
+#![allow(clippy::unnecessary_fallible_conversions)]
 
 extern crate std;
 
@@ -14,14 +14,22 @@
 extern crate std;
 
 use smart_wallet_interface::{
-    events::{SignerAdded, SignerRemoved, SignerUpdated, Upgraded},
-    types::{SignerExpiration, SignerKey, SignerLimits, SignerStorage, SignerVal},
+    events::{
+        CountersReconciled, FeePaid, InheritanceUpdated, IntentCancelled, IntentExecuted,
+        SchemaMigrated, SessionsRevoked, SignerAdded, SignerRemoved, SignerResumed,
+        SignerSuspended, SignerUpdated, SubaccountCreated, UpgradeConfirmed, UpgradeRolledBack,
+        Upgraded, WalletFrozen, WalletUnfrozen,
+    },
+    types::{
+        InheritanceConfig, SignerExpiration, SignerKey, SignerLimits, SignerMetadata,
+        SignerOptions, SignerStorage, SignerVal,
+    },
 };
 use soroban_sdk::{
     map,
     testutils::Events as _,
     xdr::{ContractEventBody, Limits, WriteXdr},
-    Address, Bytes, BytesN, Env, String as SdkString,
+    Address, Bytes, BytesN, Env, String as SdkString, Symbol,
 };
 
 use crate::tests::test_common::*;
@@ -71,7 +79,7 @@ fn event_wire_format_golden_vectors() {
     )> = std::vec::Vec::new();
 
     // SignerAdded: Ed25519 key, expiring, with a limits map requiring a
-    // policy co-signer — exercises every nested type.
+    // policy co-signer and every option set — exercises every nested type.
     let evt = SignerAdded {
         key: SignerKey::Ed25519(BytesN::from_array(&env, &[0x11; 32])),
         val: SignerVal::Ed25519(
@@ -85,6 +93,16 @@ fn event_wire_format_golden_vectors() {
             ])),
         ),
         storage: SignerStorage::Persistent,
+        options: SignerOptions {
+            valid_after: Some(1_700_000_000),
+            can_freeze: true,
+            can_web_auth: true,
+            metadata: SignerMetadata {
+                label: Some(SdkString::from_str(&env, "Alice's iPhone")),
+                created_at: Some(1_690_000_000),
+                device: Some(Symbol::new(&env, "iphone")),
+            },
+        },
     };
     env.as_contract(&wallet, || evt.publish(&env));
     let (topics, data) = last_event_hex(&env);
@@ -100,6 +118,7 @@ fn event_wire_format_golden_vectors() {
         ),
         storage: SignerStorage::Temporary,
         old_storage: SignerStorage::Persistent,
+        options: SignerOptions::default(),
     };
     env.as_contract(&wallet, || evt.publish(&env));
     let (topics, data) = last_event_hex(&env);
@@ -114,6 +133,86 @@ fn event_wire_format_golden_vectors() {
     let (topics, data) = last_event_hex(&env);
     actual.push(("signer_removed", topics, data));
 
+    // SignerSuspended / SignerResumed: key topic only.
+    let evt = SignerSuspended {
+        key: SignerKey::Ed25519(BytesN::from_array(&env, &[0x11; 32])),
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("signer_suspended", topics, data));
+
+    let evt = SignerResumed {
+        key: SignerKey::Ed25519(BytesN::from_array(&env, &[0x11; 32])),
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("signer_resumed", topics, data));
+
+    // SessionsRevoked: no key topic.
+    let evt = SessionsRevoked { generation: 7 };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("sessions_revoked", topics, data));
+
+    // InheritanceUpdated: both Option<config> encodings.
+    let evt = InheritanceUpdated {
+        config: Some(InheritanceConfig {
+            beneficiary: SignerKey::Ed25519(BytesN::from_array(&env, &[0x11; 32])),
+            inactivity_period: 31_536_000,
+        }),
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("inheritance_updated_set", topics, data));
+
+    let evt = InheritanceUpdated { config: None };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("inheritance_updated_cleared", topics, data));
+
+    // WalletFrozen / WalletUnfrozen: no key topic, no data.
+    env.as_contract(&wallet, || WalletFrozen {}.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("wallet_frozen", topics, data));
+
+    env.as_contract(&wallet, || WalletUnfrozen {}.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("wallet_unfrozen", topics, data));
+
+    // UpgradeConfirmed / UpgradeRolledBack: no key topic.
+    let evt = UpgradeConfirmed {
+        hash: BytesN::from_array(&env, &[0x44; 32]),
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("upgrade_confirmed", topics, data));
+
+    let evt = UpgradeRolledBack {
+        from_hash: BytesN::from_array(&env, &[0x44; 32]),
+        to_hash: BytesN::from_array(&env, &[0x55; 32]),
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("upgrade_rolled_back", topics, data));
+
+    // SchemaMigrated: no key topic.
+    let evt = SchemaMigrated {
+        from_version: 1,
+        to_version: 2,
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("schema_migrated", topics, data));
+
+    // CountersReconciled: no key topic.
+    let evt = CountersReconciled {
+        admin_count: 2,
+        durable_count: 3,
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("counters_reconciled", topics, data));
+
     // Upgraded: both Option<old_hash> encodings.
     let evt = Upgraded {
         old_hash: None,
@@ -131,6 +230,40 @@ fn event_wire_format_golden_vectors() {
     let (topics, data) = last_event_hex(&env);
     actual.push(("upgraded_subsequent", topics, data));
 
+    // IntentExecuted / IntentCancelled / FeePaid: no key topic.
+    let evt = IntentExecuted {
+        nonce: 7,
+        contract: fixed.clone(),
+        fn_name: Symbol::new(&env, "transfer"),
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("intent_executed", topics, data));
+
+    let evt = IntentCancelled { nonce: 7 };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("intent_cancelled", topics, data));
+
+    let evt = FeePaid {
+        nonce: 7,
+        token: fixed.clone(),
+        amount: 5,
+        recipient: fixed.clone(),
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("fee_paid", topics, data));
+
+    // SubaccountCreated: the subaccount is the key topic.
+    let evt = SubaccountCreated {
+        subaccount: fixed.clone(),
+        wasm_hash: BytesN::from_array(&env, &[0x44; 32]),
+    };
+    env.as_contract(&wallet, || evt.publish(&env));
+    let (topics, data) = last_event_hex(&env);
+    actual.push(("subaccount_created", topics, data));
+
     // GOLDEN VECTORS — raw ScVal XDR hex, captured from soroban-sdk 27.0.0's
     // #[contractevent] derive. First topic: Symbol(<snake_case struct name>);
     // second topic (when present): the #[topic] key field. Data: ScMap of the
@@ -148,8 +281,9 @@ fn event_wire_format_golden_vectors() {
                 // SignerKey::Ed25519([0x11; 32])
                 "0000001000000001000000020000000f0000000745643235353139000000000d000000201111111111111111111111111111111111111111111111111111111111111111".into(),
             ],
-            // { storage: Persistent, val: Ed25519(exp Some(1735689600), limits Some({C… -> Some([Policy(C…)])})) }
-            "0000001100000001000000020000000f0000000773746f72616765000000001000000001000000010000000f0000000a50657273697374656e7400000000000f0000000376616c000000001000000001000000030000000f0000000745643235353139000000001000000001000000010000000500000000677485800000001000000001000000010000001100000001000000010000001200000001d7928b72c2703ccfeaf7eb9ff4ef4d504a55a8b979fc9b450ea2c842b4d1ce610000001000000001000000010000001000000001000000020000000f00000006506f6c69637900000000001200000001d7928b72c2703ccfeaf7eb9ff4ef4d504a55a8b979fc9b450ea2c842b4d1ce61".into(),
+            // { options: { can_freeze: true, can_web_auth: true, metadata: { created_at: Some(1690000000), device: Some(iphone), label: Some("Alice's iPhone") }, valid_after: Some(1700000000) },
+            //   storage: Persistent, val: Ed25519(exp Some(1735689600), limits Some({C… -> Some([Policy(C…)])})) }
+            "0000001100000001000000030000000f000000076f7074696f6e73000000001100000001000000040000000f0000000a63616e5f667265657a65000000000000000000010000000f0000000c63616e5f7765625f6175746800000000000000010000000f000000086d657461646174610000001100000001000000030000000f0000000a637265617465645f61740000000000050000000064bb5a800000000f0000000664657669636500000000000f000000066970686f6e6500000000000f000000056c6162656c0000000000000e0000000e416c6963652773206950686f6e6500000000000f0000000b76616c69645f61667465720000000005000000006553f1000000000f0000000773746f72616765000000001000000001000000010000000f0000000a50657273697374656e7400000000000f0000000376616c000000001000000001000000030000000f0000000745643235353139000000001000000001000000010000000500000000677485800000001000000001000000010000001100000001000000010000001200000001d7928b72c2703ccfeaf7eb9ff4ef4d504a55a8b979fc9b450ea2c842b4d1ce610000001000000001000000010000001000000001000000020000000f00000006506f6c69637900000000001200000001d7928b72c2703ccfeaf7eb9ff4ef4d504a55a8b979fc9b450ea2c842b4d1ce61".into(),
         ),
         (
             "signer_updated",
@@ -159,8 +293,8 @@ fn event_wire_format_golden_vectors() {
                 // SignerKey::Secp256r1(bytes [0x22; 20])
                 "0000001000000001000000020000000f000000095365637032353672310000000000000d000000142222222222222222222222222222222222222222".into(),
             ],
-            // { old_storage: Persistent, storage: Temporary, val: Secp256r1(pk [0x33; 65], exp None, limits None) }
-            "0000001100000001000000030000000f0000000b6f6c645f73746f72616765000000001000000001000000010000000f0000000a50657273697374656e7400000000000f0000000773746f72616765000000001000000001000000010000000f0000000954656d706f726172790000000000000f0000000376616c000000001000000001000000040000000f000000095365637032353672310000000000000d0000004133333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333330000000000001000000001000000010000000100000010000000010000000100000001".into(),
+            // { old_storage: Persistent, options: default, storage: Temporary, val: Secp256r1(pk [0x33; 65], exp None, limits None) }
+            "0000001100000001000000040000000f0000000b6f6c645f73746f72616765000000001000000001000000010000000f0000000a50657273697374656e7400000000000f000000076f7074696f6e73000000001100000001000000040000000f0000000a63616e5f667265657a65000000000000000000000000000f0000000c63616e5f7765625f6175746800000000000000000000000f000000086d657461646174610000001100000001000000030000000f0000000a637265617465645f61740000000000010000000f000000066465766963650000000000010000000f000000056c6162656c000000000000010000000f0000000b76616c69645f616674657200000000010000000f0000000773746f72616765000000001000000001000000010000000f0000000954656d706f726172790000000000000f0000000376616c000000001000000001000000040000000f000000095365637032353672310000000000000d0000004133333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333330000000000001000000001000000010000000100000010000000010000000100000001".into(),
         ),
         (
             "signer_removed",
@@ -173,6 +307,108 @@ fn event_wire_format_golden_vectors() {
             // { storage: Temporary }
             "0000001100000001000000010000000f0000000773746f72616765000000001000000001000000010000000f0000000954656d706f72617279000000".into(),
         ),
+        (
+            "signer_suspended",
+            std::vec![
+                // Symbol("signer_suspended")
+                "0000000f000000107369676e65725f73757370656e646564".into(),
+                // SignerKey::Ed25519([0x11; 32])
+                "0000001000000001000000020000000f0000000745643235353139000000000d000000201111111111111111111111111111111111111111111111111111111111111111".into(),
+            ],
+            // {} — no data fields encodes as an empty ScMap.
+            "000000110000000100000000".into(),
+        ),
+        (
+            "signer_resumed",
+            std::vec![
+                // Symbol("signer_resumed")
+                "0000000f0000000e7369676e65725f726573756d65640000".into(),
+                // SignerKey::Ed25519([0x11; 32])
+                "0000001000000001000000020000000f0000000745643235353139000000000d000000201111111111111111111111111111111111111111111111111111111111111111".into(),
+            ],
+            // {}
+            "000000110000000100000000".into(),
+        ),
+        (
+            "sessions_revoked",
+            std::vec![
+                // Symbol("sessions_revoked") — no key topic.
+                "0000000f0000001073657373696f6e735f7265766f6b6564".into(),
+            ],
+            // { generation: U32(7) }
+            "0000001100000001000000010000000f0000000a67656e65726174696f6e00000000000300000007".into(),
+        ),
+        (
+            "inheritance_updated_set",
+            std::vec![
+                // Symbol("inheritance_updated") — no key topic.
+                "0000000f00000013696e6865726974616e63655f7570646174656400".into(),
+            ],
+            // { config: { beneficiary: Ed25519([0x11; 32]), inactivity_period: U64(31536000) } }
+            "0000001100000001000000010000000f00000006636f6e66696700000000001100000001000000020000000f0000000b62656e6566696369617279000000001000000001000000020000000f0000000745643235353139000000000d0000002011111111111111111111111111111111111111111111111111111111111111110000000f00000011696e61637469766974795f706572696f64000000000000050000000001e13380".into(),
+        ),
+        (
+            "inheritance_updated_cleared",
+            std::vec![
+                "0000000f00000013696e6865726974616e63655f7570646174656400".into(),
+            ],
+            // { config: Void }
+            "0000001100000001000000010000000f00000006636f6e666967000000000001".into(),
+        ),
+        (
+            "wallet_frozen",
+            std::vec![
+                // Symbol("wallet_frozen") — no key topic.
+                "0000000f0000000d77616c6c65745f66726f7a656e000000".into(),
+            ],
+            // {}
+            "000000110000000100000000".into(),
+        ),
+        (
+            "wallet_unfrozen",
+            std::vec![
+                // Symbol("wallet_unfrozen")
+                "0000000f0000000f77616c6c65745f756e66726f7a656e00".into(),
+            ],
+            // {}
+            "000000110000000100000000".into(),
+        ),
+        (
+            "upgrade_confirmed",
+            std::vec![
+                // Symbol("upgrade_confirmed") — no key topic.
+                "0000000f00000011757067726164655f636f6e6669726d6564000000".into(),
+            ],
+            // { hash: [0x44; 32] }
+            "0000001100000001000000010000000f00000004686173680000000d000000204444444444444444444444444444444444444444444444444444444444444444".into(),
+        ),
+        (
+            "upgrade_rolled_back",
+            std::vec![
+                // Symbol("upgrade_rolled_back")
+                "0000000f00000013757067726164655f726f6c6c65645f6261636b00".into(),
+            ],
+            // { from_hash: [0x44; 32], to_hash: [0x55; 32] }
+            "0000001100000001000000020000000f0000000966726f6d5f686173680000000000000d0000002044444444444444444444444444444444444444444444444444444444444444440000000f00000007746f5f68617368000000000d000000205555555555555555555555555555555555555555555555555555555555555555".into(),
+        ),
+        (
+            "schema_migrated",
+            std::vec![
+                // Symbol("schema_migrated") — no key topic.
+                "0000000f0000000f736368656d615f6d6967726174656400".into(),
+            ],
+            // { from_version: U32(1), to_version: U32(2) }
+            "0000001100000001000000020000000f0000000c66726f6d5f76657273696f6e00000003000000010000000f0000000a746f5f76657273696f6e00000000000300000002".into(),
+        ),
+        (
+            "counters_reconciled",
+            std::vec![
+                // Symbol("counters_reconciled") — no key topic.
+                "0000000f00000013636f756e746572735f7265636f6e63696c656400".into(),
+            ],
+            // { admin_count: U32(2), durable_count: U32(3) }
+            "0000001100000001000000020000000f0000000b61646d696e5f636f756e740000000003000000020000000f0000000d64757261626c655f636f756e740000000000000300000003".into(),
+        ),
         (
             "upgraded_first",
             std::vec![
@@ -190,6 +426,44 @@ fn event_wire_format_golden_vectors() {
             // { new_hash: [0x44; 32], old_hash: [0x55; 32] } — Some encodes as the bare inner value.
             "0000001100000001000000020000000f000000086e65775f686173680000000d0000002044444444444444444444444444444444444444444444444444444444444444440000000f000000086f6c645f686173680000000d000000205555555555555555555555555555555555555555555555555555555555555555".into(),
         ),
+        (
+            "intent_executed",
+            std::vec![
+                // Symbol("intent_executed") — no key topic.
+                "0000000f0000000f696e74656e745f657865637574656400".into(),
+            ],
+            // { contract: C…, fn_name: Symbol(transfer), nonce: U64(7) }
+            "0000001100000001000000030000000f00000008636f6e74726163740000001200000001d7928b72c2703ccfeaf7eb9ff4ef4d504a55a8b979fc9b450ea2c842b4d1ce610000000f00000007666e5f6e616d65000000000f000000087472616e736665720000000f000000056e6f6e6365000000000000050000000000000007".into(),
+        ),
+        (
+            "intent_cancelled",
+            std::vec![
+                // Symbol("intent_cancelled") — no key topic.
+                "0000000f00000010696e74656e745f63616e63656c6c6564".into(),
+            ],
+            // { nonce: U64(7) }
+            "0000001100000001000000010000000f000000056e6f6e6365000000000000050000000000000007".into(),
+        ),
+        (
+            "fee_paid",
+            std::vec![
+                // Symbol("fee_paid") — no key topic.
+                "0000000f000000086665655f70616964".into(),
+            ],
+            // { amount: I128(5), nonce: U64(7), recipient: C…, token: C… }
+            "0000001100000001000000040000000f00000006616d6f756e7400000000000a000000000000000000000000000000050000000f000000056e6f6e63650000000000000500000000000000070000000f00000009726563697069656e740000000000001200000001d7928b72c2703ccfeaf7eb9ff4ef4d504a55a8b979fc9b450ea2c842b4d1ce610000000f00000005746f6b656e0000000000001200000001d7928b72c2703ccfeaf7eb9ff4ef4d504a55a8b979fc9b450ea2c842b4d1ce61".into(),
+        ),
+        (
+            "subaccount_created",
+            std::vec![
+                // Symbol("subaccount_created")
+                "0000000f000000127375626163636f756e745f637265617465640000".into(),
+                // Address(C…)
+                "0000001200000001d7928b72c2703ccfeaf7eb9ff4ef4d504a55a8b979fc9b450ea2c842b4d1ce61".into(),
+            ],
+            // { wasm_hash: [0x44; 32] }
+            "0000001100000001000000010000000f000000097761736d5f686173680000000000000d000000204444444444444444444444444444444444444444444444444444444444444444".into(),
+        ),
     ];
 
     assert_eq!(actual, expected);
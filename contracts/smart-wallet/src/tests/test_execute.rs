@@ -0,0 +1,232 @@
+#![cfg(test)]
+//! `execute`: batched calls under one wallet authorization, and the
+//! per-call context expansion in `__check_auth`.
+
+extern crate std;
+
+use smart_wallet_interface::types::{
+    Error, Signatures, SignerExpiration, SignerLimits, SignerStorage,
+};
+use soroban_sdk::{
+    auth::Context,
+    map,
+    testutils::Address as _,
+    token, vec,
+    xdr::{SorobanAddressCredentials, SorobanAuthorizationEntry, SorobanCredentials},
+    Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::tests::test_common::*;
+
+fn register_sac(env: &Env) -> (Address, token::StellarAssetClient<'_>, token::Client<'_>) {
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let sac_address = sac.address();
+
+    (
+        sac_address.clone(),
+        token::StellarAssetClient::new(env, &sac_address),
+        token::Client::new(env, &sac_address),
+    )
+}
+
+fn transfer_call(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> (Address, Symbol, Vec<Val>) {
+    (
+        token.clone(),
+        Symbol::new(env, "transfer"),
+        (from.clone(), to.clone(), amount).into_val(env),
+    )
+}
+
+fn execute_context(
+    env: &Env,
+    wallet: &Address,
+    calls: &Vec<(Address, Symbol, Vec<Val>)>,
+) -> Context {
+    contract_context(env, wallet, "execute", vec![env, calls.into_val(env)])
+}
+
+/// One authorization of `execute` — by a signer limited to the token, with
+/// no entry for the wallet itself — runs every call in order, and each
+/// call's own `require_auth` of the wallet is satisfied by the wallet
+/// invoking it.
+#[test]
+fn execute_runs_calls_in_order_as_wallet() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let payroll = Ed25519Signer::new(2);
+    let (wallet, client) = register_wallet(
+        &env,
+        &owner.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+    let (token, token_admin, token_client) = register_sac(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token_admin.mock_all_auths().mint(&wallet, &100);
+    client.mock_all_auths().add_signer(&payroll.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env, (token.clone(), None)])),
+        SignerStorage::Persistent,
+    ));
+
+    let calls = vec![
+        &env,
+        transfer_call(&env, &token, &wallet, &alice, 10),
+        transfer_call(&env, &token, &wallet, &bob, 20),
+        (
+            token.clone(),
+            Symbol::new(&env, "balance"),
+            vec![&env, wallet.into_val(&env)],
+        ),
+    ];
+
+    let signature_expiration_ledger = env.ledger().sequence();
+    let nonce = 1i64;
+    let root_invocation = execute_invocation(&env, &wallet, &calls);
+    let payload = auth_payload(&env, nonce, signature_expiration_ledger, &root_invocation);
+    let root_auth = SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: wallet.clone().into(),
+            nonce,
+            signature_expiration_ledger,
+            signature: Signatures(map![
+                &env,
+                (payroll.signer_key(&env), payroll.sign(&env, &payload)),
+            ])
+            .try_into()
+            .unwrap(),
+        }),
+        root_invocation,
+    };
+
+    let results = client.set_auths(&[root_auth]).execute(&calls);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        i128::try_from_val(&env, &results.get_unchecked(2)).unwrap(),
+        70
+    );
+    assert_eq!(token_client.balance(&alice), 10);
+    assert_eq!(token_client.balance(&bob), 20);
+
+    assert!(client.set_auths(&[]).try_execute(&calls).is_err());
+}
+
+/// Each call is covered on its own: a signer limited to some of the batch's
+/// contracts cannot authorize the batch, one limited to all of them can —
+/// without any entry for the wallet itself.
+#[test]
+fn execute_contexts_checked_against_limits() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let payroll = Ed25519Signer::new(2);
+    let (wallet, client) = register_wallet(
+        &env,
+        &owner.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    let employee = Address::generate(&env);
+
+    let calls = vec![
+        &env,
+        transfer_call(&env, &token_a, &wallet, &employee, 10),
+        transfer_call(&env, &token_b, &wallet, &employee, 20),
+    ];
+    let check = |calls: &Vec<(Address, Symbol, Vec<Val>)>| {
+        let payload = payload(&env, 1);
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (payroll.signer_key(&env), payroll.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &vec![&env, execute_context(&env, &wallet, calls)],
+        )
+    };
+
+    client.mock_all_auths().add_signer(&payroll.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env, (token_a.clone(), None)])),
+        SignerStorage::Persistent,
+    ));
+    assert_eq!(check(&calls), Err(Ok(Error::MissingContext)));
+    assert_eq!(check(&vec![&env, calls.get_unchecked(0)]), Ok(()));
+
+    client.mock_all_auths().update_signer(&payroll.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![
+            &env,
+            (token_a.clone(), None),
+            (token_b.clone(), None)
+        ])),
+        SignerStorage::Persistent,
+    ));
+    assert_eq!(check(&calls), Ok(()));
+}
+
+/// A batch may not call the wallet itself — neither through `execute` nor
+/// through an authorization of such a batch.
+#[test]
+fn execute_rejects_self_calls() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(
+        &env,
+        &owner.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    let calls = vec![
+        &env,
+        (
+            wallet.clone(),
+            Symbol::new(&env, "revoke_sessions"),
+            Vec::<Val>::new(&env),
+        ),
+    ];
+
+    assert_eq!(
+        client.mock_all_auths().try_execute(&calls),
+        Err(Ok(Error::ExecuteSelfCall))
+    );
+
+    let payload = payload(&env, 1);
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (owner.signer_key(&env), owner.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &vec![&env, execute_context(&env, &wallet, &calls)],
+        ),
+        Err(Ok(Error::ExecuteSelfCall))
+    );
+}
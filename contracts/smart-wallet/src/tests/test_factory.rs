@@ -0,0 +1,192 @@
+#![cfg(test)]
+//! The wallet factory: wallets deployed from the pinned wasm at addresses
+//! bound to their passkey's key id and public key and their settings.
+
+extern crate std;
+
+use smart_wallet_interface::types::{
+    SignerExpiration, SignerLimits, SignerOptions, SignerVal, WalletSetting, WalletSettings,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Instance as _, Events as _, Ledger as _},
+    vec,
+    xdr::ToXdr,
+    Bytes, BytesN, Env, Event as _, Symbol,
+};
+use wallet_factory::{
+    Contract as FactoryContract, ContractClient as FactoryClient, WalletDeployed,
+};
+
+use crate::{tests::test_common::*, ContractClient};
+
+fn setup<'a>(env: &Env) -> (FactoryClient<'a>, BytesN<32>) {
+    let wasm_hash = upload_wallet_wasm(env);
+    let factory = env.register(FactoryContract, (wasm_hash.clone(),));
+
+    (FactoryClient::new(env, &factory), wasm_hash)
+}
+
+/// `deploy` lands at the address `get_wallet_address` derives, running the
+/// pinned wasm with the passkey as its unlimited persistent admin, and the
+/// wallet caches its genesis hash.
+#[test]
+fn factory_deploys_wallet_at_derived_address() {
+    let env = test_env();
+    let (factory, wasm_hash) = setup(&env);
+    let passkey = Passkey::new(1);
+    let key_id = passkey.key_id(&env);
+    let public_key: Bytes = passkey.public_key(&env).into();
+
+    assert_eq!(factory.get_wasm_hash(), wasm_hash);
+
+    let expected_address = factory.get_wallet_address(&key_id, &public_key, &None);
+    let wallet = factory.deploy(&key_id, &public_key, &None);
+    assert_eq!(wallet, expected_address);
+
+    let expected = WalletDeployed {
+        wallet: wallet.clone(),
+        key_id: key_id.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&factory.address),
+        vec![
+            &env,
+            (
+                factory.address.clone(),
+                expected.topics(&env),
+                expected.data(&env)
+            )
+        ]
+    );
+
+    assert_eq!(genesis_hash(&wallet), wasm_hash);
+    assert_eq!(
+        ContractClient::new(&env, &wallet).get_signer(&passkey.signer_key(&env)),
+        Some(SignerVal::Secp256r1(
+            passkey.public_key(&env),
+            SignerExpiration(None),
+            SignerLimits(None)
+        ))
+    );
+    assert_eq!(
+        ContractClient::new(&env, &wallet).get_signer_options(&passkey.signer_key(&env)),
+        Some(SignerOptions::default())
+    );
+
+    let cached = env.as_contract(&wallet, || {
+        env.storage()
+            .instance()
+            .get::<Symbol, BytesN<32>>(&symbol_short!("wasm_hash"))
+    });
+    assert_eq!(cached, Some(wasm_hash));
+}
+
+/// The salt is `sha256(xdr((key_id, public_key, settings)))`, so a client
+/// derives the address from the credential and the settings it deploys with.
+#[test]
+fn factory_address_binds_key_id_and_public_key() {
+    let env = test_env();
+    let (factory, _) = setup(&env);
+    let passkey = Passkey::new(1);
+    let key_id = passkey.key_id(&env);
+    let public_key: Bytes = passkey.public_key(&env).into();
+    let address = factory.get_wallet_address(&key_id, &public_key, &None);
+
+    let salt: BytesN<32> = env
+        .crypto()
+        .sha256(&(key_id.clone(), public_key.clone(), None::<WalletSettings>).to_xdr(&env))
+        .into();
+    let derived = env.as_contract(&factory.address, || {
+        env.deployer()
+            .with_current_contract(salt)
+            .deployed_address()
+    });
+    assert_eq!(derived, address);
+
+    let other_key: Bytes = Passkey::new(2).public_key(&env).into();
+    assert_ne!(
+        factory.get_wallet_address(&key_id, &other_key, &None),
+        address
+    );
+
+    let other_id = Bytes::from_array(&env, &[2; 16]);
+    assert_ne!(
+        factory.get_wallet_address(&other_id, &public_key, &None),
+        address
+    );
+}
+
+/// Settings are part of the address: a front-runner deploying the passkey
+/// with other settings lands elsewhere, and the owner's wallet is born with
+/// the owner's settings.
+#[test]
+fn factory_address_binds_settings() {
+    let env = test_env();
+    let (factory, _) = setup(&env);
+    let passkey = Passkey::new(1);
+    let key_id = passkey.key_id(&env);
+    let public_key: Bytes = passkey.public_key(&env).into();
+    let settings = Some(WalletSettings(vec![&env, WalletSetting::FreezeQuorum(1)]));
+    let address = factory.get_wallet_address(&key_id, &public_key, &settings);
+
+    let hostile = Some(WalletSettings(vec![&env, WalletSetting::FreezeQuorum(9)]));
+    assert_ne!(factory.deploy(&key_id, &public_key, &hostile), address);
+    assert_ne!(factory.deploy(&key_id, &public_key, &None), address);
+
+    let wallet = factory.deploy(&key_id, &public_key, &settings);
+    assert_eq!(wallet, address);
+    assert_eq!(ContractClient::new(&env, &wallet).get_freeze_quorum(), 1);
+}
+
+/// A wallet deploys once: redeploying the same passkey with the same
+/// settings fails.
+#[test]
+fn factory_redeploy_fails() {
+    let env = test_env();
+    let (factory, _) = setup(&env);
+    let passkey = Passkey::new(1);
+    let key_id = passkey.key_id(&env);
+    let public_key: Bytes = passkey.public_key(&env).into();
+    let settings = Some(WalletSettings(vec![&env, WalletSetting::FreezeQuorum(1)]));
+
+    factory.deploy(&key_id, &public_key, &None);
+    assert!(factory.try_deploy(&key_id, &public_key, &None).is_err());
+
+    factory.deploy(&key_id, &public_key, &settings);
+    assert!(factory.try_deploy(&key_id, &public_key, &settings).is_err());
+}
+
+/// The getters renew the factory instance like `deploy` does, so a factory
+/// that is only read does not archive.
+#[test]
+fn factory_reads_renew_instance() {
+    let env = test_env();
+    let (factory, _) = setup(&env);
+    let passkey = Passkey::new(1);
+    let key_id = passkey.key_id(&env);
+    let public_key: Bytes = passkey.public_key(&env).into();
+    let ttl = || env.as_contract(&factory.address, || env.storage().instance().get_ttl());
+
+    let initial = ttl();
+    factory.get_wasm_hash();
+    let renewed = ttl();
+    assert!(renewed > initial);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + renewed);
+    factory.get_wallet_address(&key_id, &public_key, &None);
+    assert_eq!(ttl(), renewed);
+}
+
+/// The factory builds a real P-256 signer: a public key that is not one
+/// fails the deploy.
+#[test]
+fn factory_rejects_invalid_public_key() {
+    let env = test_env();
+    let (factory, _) = setup(&env);
+    let key_id = Bytes::from_array(&env, &[1; 16]);
+    let public_key = Bytes::from_array(&env, &[4; 65]);
+
+    assert!(factory.try_deploy(&key_id, &public_key, &None).is_err());
+}
@@ -0,0 +1,366 @@
+#![cfg(test)]
+//! Wallet freeze: the `can_freeze` permission, the frozen-mode context
+//! allowlist, the durable-admin quorum, and the `freeze`/`unfreeze`/
+//! `set_freeze_quorum` entrypoints.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::{WalletFrozen, WalletUnfrozen},
+    types::{
+        Error, Signatures, Signer, SignerExpiration, SignerLimits, SignerOptions, SignerStorage,
+        FREEZE_TIMELOCK,
+    },
+};
+use soroban_sdk::{
+    auth::Context,
+    map,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, Event as _, IntoVal,
+};
+
+use crate::tests::test_common::*;
+
+fn admin(env: &Env, signer: &Ed25519Signer) -> Signer {
+    signer.signer(
+        env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    )
+}
+
+/// A panic key: covers nothing through its limits, but may freeze.
+fn panic_key(env: &Env, signer: &Ed25519Signer) -> Signer {
+    with_options(
+        signer.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(Some(map![env])),
+            SignerStorage::Persistent,
+        ),
+        SignerOptions {
+            can_freeze: true,
+            ..Default::default()
+        },
+    )
+}
+
+fn setup<'a>(env: &Env) -> (Ed25519Signer, Address, crate::ContractClient<'a>) {
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(env, &admin(env, &owner));
+
+    (owner, wallet, client)
+}
+
+fn check(
+    env: &Env,
+    wallet: &Address,
+    signers: &[&Ed25519Signer],
+    context: Context,
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let payload = payload(env, 9);
+    let mut signatures = map![env];
+    for signer in signers {
+        signatures.set(signer.signer_key(env), signer.sign(env, &payload));
+    }
+
+    env.try_invoke_contract_check_auth::<Error>(
+        wallet,
+        &payload,
+        Signatures(signatures).into_val(env),
+        &vec![env, context],
+    )
+}
+
+fn wallet_call(env: &Env, wallet: &Address, fn_name: &str) -> Context {
+    contract_context(env, wallet, fn_name, vec![env])
+}
+
+/// The panic key can freeze, and nothing else.
+#[test]
+fn panic_key_can_only_freeze() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let panic = Ed25519Signer::new(2);
+    let plain = Ed25519Signer::new(3);
+
+    client.mock_all_auths().add_signer(&panic_key(&env, &panic));
+    client.mock_all_auths().add_signer(&plain.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env])),
+        SignerStorage::Persistent,
+    ));
+
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &[&panic],
+            wallet_call(&env, &wallet, "freeze")
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &[&plain],
+            wallet_call(&env, &wallet, "freeze")
+        ),
+        Err(Ok(Error::MissingContext))
+    );
+
+    for fn_name in ["unfreeze", "add_signer", "upgrade"] {
+        assert_eq!(
+            check(
+                &env,
+                &wallet,
+                &[&panic],
+                wallet_call(&env, &wallet, fn_name)
+            ),
+            Err(Ok(Error::MissingContext))
+        );
+    }
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &[&panic],
+            transfer_context(&env, &Address::generate(&env), &wallet, 1)
+        ),
+        Err(Ok(Error::MissingContext))
+    );
+
+    // A suspended panic key cannot freeze.
+    client
+        .mock_all_auths()
+        .suspend_signer(&panic.signer_key(&env));
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &[&panic],
+            wallet_call(&env, &wallet, "freeze")
+        ),
+        Err(Ok(Error::MissingContext))
+    );
+}
+
+/// While frozen, only unfreeze and signer management pass — even for an
+/// unlimited admin.
+#[test]
+fn frozen_wallet_refuses_other_contexts() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+
+    client.mock_all_auths().freeze();
+    assert!(client.is_frozen());
+    // A single-admin wallet: its admin acts only after the timelock.
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + FREEZE_TIMELOCK);
+
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &[&owner],
+            transfer_context(&env, &Address::generate(&env), &wallet, 1)
+        ),
+        Err(Ok(Error::WalletFrozen))
+    );
+    for fn_name in ["upgrade", "set_freeze_quorum", "freeze"] {
+        assert_eq!(
+            check(
+                &env,
+                &wallet,
+                &[&owner],
+                wallet_call(&env, &wallet, fn_name)
+            ),
+            Err(Ok(Error::WalletFrozen))
+        );
+    }
+    // A permitted-looking call on another contract is still refused.
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &[&owner],
+            wallet_call(&env, &Address::generate(&env), "unfreeze")
+        ),
+        Err(Ok(Error::WalletFrozen))
+    );
+
+    for fn_name in [
+        "unfreeze",
+        "add_signer",
+        "update_signer",
+        "remove_signer",
+        "apply_signer_changes",
+        "suspend_signer",
+        "resume_signer",
+    ] {
+        assert_eq!(
+            check(
+                &env,
+                &wallet,
+                &[&owner],
+                wallet_call(&env, &wallet, fn_name)
+            ),
+            Ok(())
+        );
+    }
+
+    client.mock_all_auths().unfreeze();
+    assert!(!client.is_frozen());
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &[&owner],
+            transfer_context(&env, &Address::generate(&env), &wallet, 1)
+        ),
+        Ok(())
+    );
+}
+
+/// The default quorum of two durable admins applies once the wallet has
+/// two; suspended and non-admin signers do not count toward it.
+#[test]
+fn frozen_wallet_requires_admin_quorum() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let second = Ed25519Signer::new(2);
+    let third = Ed25519Signer::new(3);
+    let limited = Ed25519Signer::new(4);
+    let unfreeze = || wallet_call(&env, &wallet, "unfreeze");
+
+    client.mock_all_auths().add_signer(&admin(&env, &second));
+    client.mock_all_auths().add_signer(&admin(&env, &third));
+    client.mock_all_auths().add_signer(&limited.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env, (wallet.clone(), None)])),
+        SignerStorage::Temporary,
+    ));
+    client.mock_all_auths().freeze();
+
+    assert_eq!(
+        check(&env, &wallet, &[&owner], unfreeze()),
+        Err(Ok(Error::FreezeQuorumNotMet))
+    );
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &limited], unfreeze()),
+        Err(Ok(Error::FreezeQuorumNotMet))
+    );
+    assert_eq!(check(&env, &wallet, &[&owner, &second], unfreeze()), Ok(()));
+
+    client
+        .mock_all_auths()
+        .suspend_signer(&second.signer_key(&env));
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &second], unfreeze()),
+        Err(Ok(Error::FreezeQuorumNotMet))
+    );
+    assert_eq!(check(&env, &wallet, &[&owner, &third], unfreeze()), Ok(()));
+}
+
+/// A wallet with fewer admins than its quorum needs all of them, and only
+/// once `FREEZE_TIMELOCK` has passed since the freeze: a single-admin wallet
+/// cannot be unfrozen at once by its one (possibly stolen) key.
+#[test]
+fn understaffed_wallet_waits_out_timelock() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let second = Ed25519Signer::new(2);
+    let third = Ed25519Signer::new(3);
+    let unfreeze = || wallet_call(&env, &wallet, "unfreeze");
+    let wait = |seconds: u64| {
+        env.ledger()
+            .set_timestamp(env.ledger().timestamp() + seconds)
+    };
+
+    client.mock_all_auths().freeze();
+    assert_eq!(
+        check(&env, &wallet, &[&owner], unfreeze()),
+        Err(Ok(Error::FreezeTimelockActive))
+    );
+    wait(FREEZE_TIMELOCK - 1);
+    assert_eq!(
+        check(&env, &wallet, &[&owner], unfreeze()),
+        Err(Ok(Error::FreezeTimelockActive))
+    );
+    wait(1);
+    assert_eq!(check(&env, &wallet, &[&owner], unfreeze()), Ok(()));
+    client.mock_all_auths().unfreeze();
+
+    // Re-freezing restarts the timelock.
+    client.mock_all_auths().set_freeze_quorum(&3);
+    assert_eq!(client.get_freeze_quorum(), 3);
+    client.mock_all_auths().add_signer(&admin(&env, &second));
+    client.mock_all_auths().freeze();
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &second], unfreeze()),
+        Err(Ok(Error::FreezeTimelockActive))
+    );
+    wait(FREEZE_TIMELOCK);
+    assert_eq!(
+        check(&env, &wallet, &[&owner], unfreeze()),
+        Err(Ok(Error::FreezeQuorumNotMet))
+    );
+    assert_eq!(check(&env, &wallet, &[&owner, &second], unfreeze()), Ok(()));
+
+    // At quorum, no timelock.
+    client.mock_all_auths().unfreeze();
+    client.mock_all_auths().add_signer(&admin(&env, &third));
+    client.mock_all_auths().freeze();
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &second], unfreeze()),
+        Err(Ok(Error::FreezeQuorumNotMet))
+    );
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &second, &third], unfreeze()),
+        Ok(())
+    );
+}
+
+#[test]
+fn freeze_entrypoints_auth_events_and_idempotence() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+
+    assert!(client.try_freeze().is_err());
+    assert!(client.try_unfreeze().is_err());
+    assert!(client.try_set_freeze_quorum(&1).is_err());
+    assert_eq!(
+        client.mock_all_auths().try_set_freeze_quorum(&0),
+        Err(Ok(Error::InvalidFreezeQuorum))
+    );
+    assert_eq!(client.get_freeze_quorum(), 2);
+
+    client.mock_all_auths().freeze();
+    let frozen = WalletFrozen {};
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), frozen.topics(&env), frozen.data(&env))
+        ]
+    );
+    client.mock_all_auths().freeze();
+    assert_eq!(env.events().all(), []);
+
+    client.mock_all_auths().unfreeze();
+    let unfrozen = WalletUnfrozen {};
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), unfrozen.topics(&env), unfrozen.data(&env))
+        ]
+    );
+    client.mock_all_auths().unfreeze();
+    assert_eq!(env.events().all(), []);
+    assert!(!client.is_frozen());
+}
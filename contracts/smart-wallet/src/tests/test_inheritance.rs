@@ -0,0 +1,295 @@
+#![cfg(test)]
+//! Inheritance: a dormant beneficiary that becomes an active admin after the
+//! owner's inactivity period, the activity clock, and admin accounting.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::InheritanceUpdated,
+    types::{
+        Error, InheritanceConfig, Signature, Signatures, Signer, SignerExpiration, SignerKey,
+        SignerLimits, SignerOptions, SignerStorage,
+    },
+};
+use soroban_sdk::{
+    auth::Context,
+    map,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, Event as _, IntoVal,
+};
+
+use crate::tests::test_auth::CountingPolicy;
+use crate::tests::test_common::*;
+
+const NOW: u64 = 1_000_000;
+const PERIOD: u64 = 365 * 24 * 60 * 60;
+
+fn admin(env: &Env, signer: &Ed25519Signer) -> Signer {
+    signer.signer(
+        env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    )
+}
+
+fn config(env: &Env, beneficiary: &Ed25519Signer) -> Option<InheritanceConfig> {
+    Some(InheritanceConfig {
+        beneficiary: beneficiary.signer_key(env),
+        inactivity_period: PERIOD,
+    })
+}
+
+/// An owner wallet with an heir that holds no rights of its own (empty
+/// limits) and is named as beneficiary at `NOW`.
+fn setup<'a>(
+    env: &Env,
+) -> (
+    Ed25519Signer,
+    Ed25519Signer,
+    Address,
+    crate::ContractClient<'a>,
+) {
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Ed25519Signer::new(1);
+    let heir = Ed25519Signer::new(2);
+    let (wallet, client) = register_wallet(env, &admin(env, &owner));
+
+    client.mock_all_auths().add_signer(&heir.signer(
+        env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![env])),
+        SignerStorage::Persistent,
+    ));
+    client.mock_all_auths().set_inheritance(&config(env, &heir));
+
+    (owner, heir, wallet, client)
+}
+
+fn check_with(
+    env: &Env,
+    wallet: &Address,
+    signers: &[&Ed25519Signer],
+    context: Context,
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let payload = payload(env, 3);
+    let mut signatures = map![env];
+    for signer in signers {
+        signatures.set(signer.signer_key(env), signer.sign(env, &payload));
+    }
+
+    env.try_invoke_contract_check_auth::<Error>(
+        wallet,
+        &payload,
+        Signatures(signatures).into_val(env),
+        &vec![env, context],
+    )
+}
+
+fn check(
+    env: &Env,
+    wallet: &Address,
+    signers: &[&Ed25519Signer],
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    check_with(
+        env,
+        wallet,
+        signers,
+        transfer_context(env, &Address::generate(env), wallet, 1),
+    )
+}
+
+/// The heir cannot sign until the owner has been inactive for MORE than the
+/// period; then it is an admin whatever its own limits.
+#[test]
+fn beneficiary_activates_after_inactivity() {
+    let env = test_env();
+    let (owner, heir, wallet, _) = setup(&env);
+
+    assert_eq!(
+        check(&env, &wallet, &[&heir]),
+        Err(Ok(Error::MissingContext))
+    );
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &heir]),
+        Err(Ok(Error::BeneficiaryDormant))
+    );
+
+    env.ledger().set_timestamp(NOW + PERIOD);
+    assert_eq!(
+        check(&env, &wallet, &[&heir]),
+        Err(Ok(Error::MissingContext))
+    );
+
+    env.ledger().set_timestamp(NOW + PERIOD + 1);
+    assert_eq!(check(&env, &wallet, &[&heir]), Ok(()));
+    assert_eq!(
+        check_with(
+            &env,
+            &wallet,
+            &[&heir],
+            contract_context(&env, &wallet, "remove_signer", vec![&env])
+        ),
+        Ok(())
+    );
+}
+
+/// Owner activity resets the clock, and puts an activated heir back to
+/// sleep; the heir's own authorizations do not touch the clock.
+#[test]
+fn owner_activity_resets_clock() {
+    let env = test_env();
+    let (owner, heir, wallet, client) = setup(&env);
+    assert_eq!(client.get_last_owner_activity(), Some(NOW));
+
+    env.ledger().set_timestamp(NOW + PERIOD - 10);
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+    assert_eq!(client.get_last_owner_activity(), Some(NOW + PERIOD - 10));
+
+    env.ledger().set_timestamp(NOW + PERIOD + 1);
+    assert_eq!(
+        check(&env, &wallet, &[&heir]),
+        Err(Ok(Error::MissingContext))
+    );
+
+    env.ledger().set_timestamp(NOW + 2 * PERIOD);
+    assert_eq!(check(&env, &wallet, &[&heir]), Ok(()));
+    assert_eq!(client.get_last_owner_activity(), Some(NOW + PERIOD - 10));
+
+    // The owner returns.
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+    assert_eq!(
+        check(&env, &wallet, &[&heir]),
+        Err(Ok(Error::MissingContext))
+    );
+}
+
+/// Policy signatures carry no secret, so they never count as activity.
+#[test]
+fn policy_signatures_are_not_activity() {
+    let env = test_env();
+    let (_, _, wallet, client) = setup(&env);
+    let policy = env.register(CountingPolicy, ());
+
+    client.mock_all_auths().add_signer(&Signer::Policy(
+        policy.clone(),
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+        SignerOptions::default(),
+    ));
+
+    // A successful authorization by the (approving) policy alone.
+    env.ledger().set_timestamp(NOW + 10);
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload(&env, 1),
+            Signatures(map![&env, (SignerKey::Policy(policy), Signature::Policy)]).into_val(&env),
+            &vec![
+                &env,
+                transfer_context(&env, &Address::generate(&env), &wallet, 1)
+            ],
+        ),
+        Ok(())
+    );
+    assert_eq!(client.get_last_owner_activity(), Some(NOW));
+}
+
+/// The beneficiary never counts as a durable admin while named.
+#[test]
+fn beneficiary_not_counted_as_admin() {
+    let env = test_env();
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Ed25519Signer::new(1);
+    let heir = Ed25519Signer::new(2);
+    let (_, client) = register_wallet(&env, &admin(&env, &owner));
+
+    // Naming the only admin is refused.
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_set_inheritance(&config(&env, &owner)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+
+    client.mock_all_auths().add_signer(&admin(&env, &heir));
+    client
+        .mock_all_auths()
+        .set_inheritance(&config(&env, &heir));
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&owner.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+
+    // Removing and re-adding the named heir keeps it excluded.
+    client
+        .mock_all_auths()
+        .remove_signer(&heir.signer_key(&env));
+    client.mock_all_auths().add_signer(&admin(&env, &heir));
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&owner.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+
+    // Clearing the configuration makes it a counted admin again.
+    client.mock_all_auths().set_inheritance(&None);
+    client
+        .mock_all_auths()
+        .remove_signer(&owner.signer_key(&env));
+    assert_eq!(client.get_signer(&owner.signer_key(&env)), None);
+}
+
+#[test]
+fn set_inheritance_validation_and_event() {
+    let env = test_env();
+    let (_, heir, wallet, client) = setup(&env);
+    let stranger = Ed25519Signer::new(9);
+
+    assert!(client.try_set_inheritance(&None).is_err());
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_set_inheritance(&config(&env, &stranger)),
+        Err(Ok(Error::SignerNotFound))
+    );
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_set_inheritance(&Some(InheritanceConfig {
+                beneficiary: heir.signer_key(&env),
+                inactivity_period: 0,
+            })),
+        Err(Ok(Error::InvalidInactivityPeriod))
+    );
+    assert_eq!(client.get_inheritance(), config(&env, &heir));
+
+    env.ledger().set_timestamp(NOW + 100);
+    client
+        .mock_all_auths()
+        .set_inheritance(&config(&env, &heir));
+    let updated = InheritanceUpdated {
+        config: config(&env, &heir),
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), updated.topics(&env), updated.data(&env))
+        ]
+    );
+    assert_eq!(client.get_last_owner_activity(), Some(NOW + 100));
+
+    client.mock_all_auths().set_inheritance(&None);
+    assert_eq!(client.get_inheritance(), None);
+    assert_eq!(
+        check(&env, &wallet, &[&heir]),
+        Err(Ok(Error::MissingContext))
+    );
+}
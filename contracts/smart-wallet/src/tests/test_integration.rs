@@ -2,6 +2,7 @@
 //! Full-stack integration: real `SorobanAuthorizationEntry` credentials
 //! through `set_auths`, exercising the wallet as a custom account end-to-end
 //! for both signer types, plus a budget regression print.
+#![allow(clippy::unnecessary_fallible_conversions)]
 
 use std::println;
 extern crate std;
@@ -9,7 +10,7 @@ extern crate std;
 use example_contract::{Contract as ExampleContract, ContractClient as ExampleContractClient};
 use sample_policy::{Contract as PolicyContract, ContractClient as PolicyContractClient};
 use smart_wallet_interface::types::{
-    Signatures, Signer, SignerExpiration, SignerKey, SignerLimits, SignerStorage,
+    Signatures, Signer, SignerExpiration, SignerKey, SignerLimits, SignerOptions, SignerStorage,
 };
 use soroban_sdk::{
     map,
@@ -105,6 +106,7 @@ fn ed25519_with_policy_limits_full_stack() {
             ),
         ])),
         SignerStorage::Temporary,
+        SignerOptions::default(),
     ));
 
     let root_invocation = SorobanAuthorizedInvocation {
@@ -257,6 +259,7 @@ fn sample_policy_rejects_spoofed_caller() {
         SignerExpiration(None),
         SignerLimits(None),
         SignerStorage::Temporary,
+        SignerOptions::default(),
     ));
 
     let token = Address::generate(&env);
@@ -305,6 +308,7 @@ fn sample_policy_cumulative_allowance() {
         SignerExpiration(None),
         SignerLimits(None),
         SignerStorage::Temporary,
+        SignerOptions::default(),
     ));
 
     let token = Address::generate(&env);
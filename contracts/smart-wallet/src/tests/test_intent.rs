@@ -0,0 +1,444 @@
+#![cfg(test)]
+//! Signed intents: `execute_intent` verifying an off-chain signed call from
+//! any submitter, nonce replay protection, `cancel_intent` and submitter
+//! fees.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::{FeePaid, IntentCancelled, IntentExecuted},
+    types::{
+        Error, FeeConfig, FeePayment, Intent, IntentFee, Signatures, SignerExpiration,
+        SignerLimits, SignerStorage,
+    },
+};
+use soroban_sdk::{
+    map,
+    testutils::{storage::Temporary as _, Address as _, Events as _, Ledger as _},
+    token, vec, Address, Env, Event as _, IntoVal, Symbol,
+};
+
+use crate::storage::StorageKey;
+use crate::tests::test_common::*;
+
+const NOW: u64 = 1_000_000;
+
+fn register_sac(env: &Env) -> (Address, token::StellarAssetClient<'_>, token::Client<'_>) {
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let sac_address = sac.address();
+
+    (
+        sac_address.clone(),
+        token::StellarAssetClient::new(env, &sac_address),
+        token::Client::new(env, &sac_address),
+    )
+}
+
+fn setup<'a>(env: &Env) -> (Ed25519Signer, Address, crate::ContractClient<'a>) {
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(
+        env,
+        &owner.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    (owner, wallet, client)
+}
+
+fn transfer_intent(token: &Address, from: &Address, to: &Address, nonce: u64) -> Intent {
+    let env = token.env();
+
+    Intent {
+        contract: token.clone(),
+        fn_name: Symbol::new(env, "transfer"),
+        args: (from.clone(), to.clone(), 10i128).into_val(env),
+        nonce,
+        deadline: NOW + 60,
+        fee: IntentFee::None,
+    }
+}
+
+fn sign_intent(
+    client: &crate::ContractClient,
+    signer: &Ed25519Signer,
+    intent: &Intent,
+) -> Signatures {
+    let env = &client.env;
+    let hash = client.get_intent_hash(intent);
+
+    Signatures(map![env, (signer.signer_key(env), signer.sign(env, &hash))])
+}
+
+/// A limited signer pre-signs a transfer; anyone can submit it, without any
+/// authorization of their own, exactly once.
+#[test]
+fn execute_intent_runs_signed_call_once() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let session = Ed25519Signer::new(2);
+    let (token, token_admin, token_client) = register_sac(&env);
+    let merchant = Address::generate(&env);
+    token_admin.mock_all_auths().mint(&wallet, &100);
+    client.mock_all_auths().add_signer(&session.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env, (token.clone(), None)])),
+        SignerStorage::Persistent,
+    ));
+
+    let intent = transfer_intent(&token, &wallet, &merchant, 7);
+    let signatures = sign_intent(&client, &session, &intent);
+
+    env.set_auths(&[]);
+    client.execute_intent(&intent, &signatures);
+
+    let expected = IntentExecuted {
+        nonce: 7,
+        contract: token.clone(),
+        fn_name: Symbol::new(&env, "transfer"),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&wallet),
+        vec![
+            &env,
+            (wallet.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+    assert_eq!(token_client.balance(&merchant), 10);
+    assert!(client.is_intent_nonce_used(&7));
+
+    assert_eq!(
+        client.try_execute_intent(&intent, &signatures).err(),
+        Some(Ok(Error::IntentNonceUsed))
+    );
+}
+
+/// Intents are checked as `__check_auth` checks the call: deadline, signer
+/// limits and the digest, which is bound to the wallet and the intent.
+#[test]
+fn execute_intent_enforces_deadline_limits_and_digest() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let restricted = Ed25519Signer::new(2);
+    let token = Address::generate(&env);
+    let merchant = Address::generate(&env);
+    client.mock_all_auths().add_signer(&restricted.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env, (Address::generate(&env), None)])),
+        SignerStorage::Persistent,
+    ));
+
+    let intent = transfer_intent(&token, &wallet, &merchant, 1);
+
+    assert_eq!(
+        client
+            .try_execute_intent(&intent, &sign_intent(&client, &restricted, &intent))
+            .err(),
+        Some(Ok(Error::MissingContext))
+    );
+
+    // Signed for a different nonce: the signature does not verify.
+    let other = Intent {
+        nonce: 2,
+        ..intent.clone()
+    };
+    assert!(client
+        .try_execute_intent(&intent, &sign_intent(&client, &owner, &other))
+        .is_err());
+
+    // The same intent hashes differently for another wallet.
+    let (_, other_client) = register_wallet(
+        &env,
+        &owner.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+    assert_ne!(
+        client.get_intent_hash(&intent),
+        other_client.get_intent_hash(&intent)
+    );
+
+    env.ledger().set_timestamp(intent.deadline + 1);
+    assert_eq!(
+        client
+            .try_execute_intent(&intent, &sign_intent(&client, &owner, &intent))
+            .err(),
+        Some(Ok(Error::IntentExpired))
+    );
+
+    let self_call = Intent {
+        contract: wallet.clone(),
+        fn_name: Symbol::new(&env, "revoke_sessions"),
+        args: vec![&env],
+        ..intent
+    };
+    assert_eq!(
+        client
+            .try_execute_intent(&self_call, &sign_intent(&client, &owner, &self_call))
+            .err(),
+        Some(Ok(Error::ExecuteSelfCall))
+    );
+}
+
+/// Cancelling consumes the nonce: the intent can no longer execute, and the
+/// nonce cannot be cancelled twice.
+#[test]
+fn cancel_intent_consumes_nonce() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let intent = transfer_intent(
+        &Address::generate(&env),
+        &wallet,
+        &Address::generate(&env),
+        3,
+    );
+
+    assert!(client.try_cancel_intent(&3, &intent.deadline).is_err());
+
+    client.mock_all_auths().cancel_intent(&3, &intent.deadline);
+
+    let expected = IntentCancelled { nonce: 3 };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+    assert!(client.is_intent_nonce_used(&3));
+
+    assert_eq!(
+        client
+            .try_execute_intent(&intent, &sign_intent(&client, &owner, &intent))
+            .err(),
+        Some(Ok(Error::IntentNonceUsed))
+    );
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_cancel_intent(&3, &intent.deadline),
+        Err(Ok(Error::IntentNonceUsed))
+    );
+}
+
+/// A consumed nonce is a temporary entry living at least until its
+/// deadline, at one ledger per second; a deadline beyond the maximum TTL
+/// could outlive it and is rejected.
+#[test]
+fn intent_nonce_lives_until_deadline() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let intent = transfer_intent(
+        &Address::generate(&env),
+        &wallet,
+        &Address::generate(&env),
+        5,
+    );
+
+    client.mock_all_auths().cancel_intent(&5, &intent.deadline);
+    let ttl = env.as_contract(&wallet, || {
+        env.storage()
+            .temporary()
+            .get_ttl(&StorageKey::IntentNonce(5))
+    });
+    assert!(ttl as u64 >= intent.deadline - NOW);
+
+    let max_ttl = env.as_contract(&wallet, || env.storage().max_ttl());
+    let far = Intent {
+        nonce: 6,
+        deadline: NOW + max_ttl as u64 + 1,
+        ..intent
+    };
+    assert_eq!(
+        client
+            .try_execute_intent(&far, &sign_intent(&client, &owner, &far))
+            .err(),
+        Some(Ok(Error::IntentDeadlineTooFar))
+    );
+    assert_eq!(
+        client.mock_all_auths().try_cancel_intent(&6, &far.deadline),
+        Err(Ok(Error::IntentDeadlineTooFar))
+    );
+    assert!(!client.is_intent_nonce_used(&6));
+}
+
+/// A fee within the wallet's maximum is authorized with the call and paid to
+/// its signed recipient; fees in other tokens, above the maximum, or outside
+/// the signer's limits are rejected.
+#[test]
+fn execute_intent_pays_capped_fee() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let session = Ed25519Signer::new(2);
+    let (token, token_admin, token_client) = register_sac(&env);
+    let (fee_token, fee_admin, fee_client) = register_sac(&env);
+    let merchant = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    token_admin.mock_all_auths().mint(&wallet, &100);
+    fee_admin.mock_all_auths().mint(&wallet, &100);
+    client.mock_all_auths().add_signer(&session.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env, (token.clone(), None)])),
+        SignerStorage::Persistent,
+    ));
+
+    let with_fee = |nonce: u64, token: &Address, amount: i128| Intent {
+        fee: IntentFee::Token(FeePayment {
+            token: token.clone(),
+            amount,
+            recipient: relayer.clone(),
+        }),
+        ..transfer_intent(&token_client.address, &wallet, &merchant, nonce)
+    };
+    let submit = |intent: &Intent, signer: &Ed25519Signer| {
+        client.try_execute_intent(intent, &sign_intent(&client, signer, intent))
+    };
+
+    // No fee config: no fee token is allowed.
+    let intent = with_fee(1, &fee_token, 2);
+    assert_eq!(
+        submit(&intent, &session).err(),
+        Some(Ok(Error::FeeTokenNotAllowed))
+    );
+
+    assert!(client
+        .try_set_fee_config(&Some(FeeConfig {
+            max_fees: map![&env, (fee_token.clone(), 5)],
+        }))
+        .is_err());
+    client.mock_all_auths().set_fee_config(&Some(FeeConfig {
+        max_fees: map![&env, (fee_token.clone(), 5)],
+    }));
+    assert_eq!(
+        client
+            .get_fee_config()
+            .unwrap()
+            .max_fees
+            .get(fee_token.clone()),
+        Some(5)
+    );
+
+    assert_eq!(
+        submit(&with_fee(1, &token, 2), &session).err(),
+        Some(Ok(Error::FeeTokenNotAllowed))
+    );
+    assert_eq!(
+        submit(&with_fee(1, &fee_token, 6), &session).err(),
+        Some(Ok(Error::FeeTooHigh))
+    );
+    assert_eq!(
+        submit(&with_fee(1, &fee_token, -1), &session).err(),
+        Some(Ok(Error::FeeTooHigh))
+    );
+
+    // The session signer may move `token` but not `fee_token`.
+    assert_eq!(
+        submit(&intent, &session).err(),
+        Some(Ok(Error::MissingContext))
+    );
+
+    client.mock_all_auths().update_signer(&session.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![
+            &env,
+            (token.clone(), None),
+            (fee_token.clone(), None)
+        ])),
+        SignerStorage::Persistent,
+    ));
+    env.set_auths(&[]);
+    submit(&intent, &session).unwrap().unwrap();
+
+    let executed = IntentExecuted {
+        nonce: 1,
+        contract: token.clone(),
+        fn_name: Symbol::new(&env, "transfer"),
+    };
+    let paid = FeePaid {
+        nonce: 1,
+        token: fee_token.clone(),
+        amount: 2,
+        recipient: relayer.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&wallet),
+        vec![
+            &env,
+            (wallet.clone(), executed.topics(&env), executed.data(&env)),
+            (wallet.clone(), paid.topics(&env), paid.data(&env)),
+        ]
+    );
+    assert_eq!(token_client.balance(&merchant), 10);
+    assert_eq!(fee_client.balance(&relayer), 2);
+    assert_eq!(fee_client.balance(&wallet), 98);
+
+    assert_eq!(
+        client.mock_all_auths().try_set_fee_config(&Some(FeeConfig {
+            max_fees: map![&env, (fee_token.clone(), -1)],
+        })),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+}
+
+/// The fee's recipient and token are signed with the intent: a submitter
+/// redirecting the fee to itself, or swapping its token, breaks the
+/// signature.
+#[test]
+fn execute_intent_rejects_tampered_fee() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let (fee_token, fee_admin, fee_client) = register_sac(&env);
+    let (other_token, other_admin, _) = register_sac(&env);
+    let relayer = Address::generate(&env);
+    let thief = Address::generate(&env);
+    fee_admin.mock_all_auths().mint(&wallet, &100);
+    other_admin.mock_all_auths().mint(&wallet, &100);
+    client.mock_all_auths().set_fee_config(&Some(FeeConfig {
+        max_fees: map![&env, (fee_token.clone(), 5), (other_token.clone(), 5)],
+    }));
+
+    let fee = FeePayment {
+        token: fee_token.clone(),
+        amount: 2,
+        recipient: relayer.clone(),
+    };
+    let intent = Intent {
+        fee: IntentFee::Token(fee.clone()),
+        ..transfer_intent(&fee_token, &wallet, &Address::generate(&env), 1)
+    };
+    let signatures = sign_intent(&client, &owner, &intent);
+    env.set_auths(&[]);
+
+    for tampered in [
+        FeePayment {
+            recipient: thief.clone(),
+            ..fee.clone()
+        },
+        FeePayment {
+            token: other_token,
+            ..fee
+        },
+    ] {
+        let tampered = Intent {
+            fee: IntentFee::Token(tampered),
+            ..intent.clone()
+        };
+        assert!(client.try_execute_intent(&tampered, &signatures).is_err());
+    }
+    assert_eq!(fee_client.balance(&thief), 0);
+
+    client.execute_intent(&intent, &signatures);
+    assert_eq!(fee_client.balance(&relayer), 2);
+}
@@ -0,0 +1,304 @@
+#![cfg(test)]
+//! Storage migrations: the schema version and `migrate`, and
+//! `migrate_legacy_signers` rewriting pre-1.0 signer entries (ledger
+//! sequence expirations, empty-means-unlimited limits) to v1 semantics.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::{SchemaMigrated, SignerUpdated},
+    types::{
+        Error, LedgerReference, Signatures, SignerExpiration, SignerKey, SignerLimits,
+        SignerOptions, SignerStorage, SignerVal,
+    },
+};
+use soroban_sdk::{
+    map, symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, Event as _, IntoVal,
+};
+
+use crate::migration::{
+    migrate_schema_step, LegacySignerExpiration, LegacySignerVal, CURRENT_SCHEMA_VERSION,
+};
+use crate::tests::test_common::*;
+
+const NOW: u64 = 1_000_000;
+const SEQUENCE: u32 = 50_000;
+
+fn reference() -> LedgerReference {
+    LedgerReference {
+        sequence: SEQUENCE,
+        timestamp: NOW,
+    }
+}
+
+fn setup<'a>(env: &Env) -> (Address, crate::ContractClient<'a>) {
+    env.ledger().with_mut(|l| {
+        l.timestamp = NOW;
+        l.sequence_number = SEQUENCE;
+    });
+
+    register_wallet(
+        env,
+        &Ed25519Signer::new(1).signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    )
+}
+
+/// Write a signer entry as the pre-1.0 contract did.
+fn store_legacy(env: &Env, wallet: &Address, key: &SignerKey, val: &LegacySignerVal) {
+    env.as_contract(wallet, || {
+        env.storage()
+            .persistent()
+            .set::<SignerKey, LegacySignerVal>(key, val)
+    });
+}
+
+/// Ledger expirations become timestamps around the reference, and legacy
+/// empty limits become unlimited — after which the signer works as its
+/// owner expects.
+#[test]
+fn migrate_legacy_signers_rewrites_to_v1() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+    let expiring = Ed25519Signer::new(2);
+    let lapsed = Ed25519Signer::new(3);
+
+    store_legacy(
+        &env,
+        &wallet,
+        &expiring.signer_key(&env),
+        &LegacySignerVal::Ed25519(
+            LegacySignerExpiration(Some(SEQUENCE + 720)),
+            SignerLimits(Some(map![&env])),
+        ),
+    );
+    store_legacy(
+        &env,
+        &wallet,
+        &lapsed.signer_key(&env),
+        &LegacySignerVal::Ed25519(
+            LegacySignerExpiration(Some(SEQUENCE - 10)),
+            SignerLimits(None),
+        ),
+    );
+
+    // v1 cannot read a ledger-sequence expiration at all.
+    assert!(client.try_get_signer(&expiring.signer_key(&env)).is_err());
+
+    client.mock_all_auths().migrate_legacy_signers(
+        &vec![&env, expiring.signer_key(&env), lapsed.signer_key(&env)],
+        &reference(),
+    );
+
+    let updated = |signer: &Ed25519Signer, val: SignerVal| SignerUpdated {
+        key: signer.signer_key(&env),
+        val,
+        storage: SignerStorage::Persistent,
+        old_storage: SignerStorage::Persistent,
+        options: SignerOptions::default(),
+    };
+    let events = [
+        updated(
+            &expiring,
+            SignerVal::Ed25519(SignerExpiration(Some(NOW + 3_600)), SignerLimits(None)),
+        ),
+        updated(
+            &lapsed,
+            SignerVal::Ed25519(SignerExpiration(Some(NOW - 50)), SignerLimits(None)),
+        ),
+    ];
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), events[0].topics(&env), events[0].data(&env)),
+            (wallet.clone(), events[1].topics(&env), events[1].data(&env))
+        ]
+    );
+
+    for event in &events {
+        assert_eq!(client.get_signer(&event.key), Some(event.val.clone()));
+    }
+
+    let payload = payload(&env, 1);
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (expiring.signer_key(&env), expiring.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &vec![
+                &env,
+                transfer_context(&env, &Address::generate(&env), &wallet, 1),
+            ],
+        ),
+        Ok(())
+    );
+}
+
+/// A non-expiring entry with empty limits reads the same in both versions:
+/// it is refused, not lifted to unlimited, and keeps no permissions until
+/// `update_signer` names its limits.
+#[test]
+fn migrate_legacy_signers_refuses_ambiguous_limits() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let val = SignerVal::Ed25519(SignerExpiration(None), SignerLimits(Some(map![&env])));
+
+    store_legacy(
+        &env,
+        &wallet,
+        &device.signer_key(&env),
+        &LegacySignerVal::Ed25519(LegacySignerExpiration(None), SignerLimits(Some(map![&env]))),
+    );
+
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_migrate_legacy_signers(&vec![&env, device.signer_key(&env)], &reference()),
+        Err(Ok(Error::AmbiguousLegacyLimits))
+    );
+    assert_eq!(client.get_signer(&device.signer_key(&env)), Some(val));
+
+    client.mock_all_auths().update_signer(&device.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    assert_eq!(
+        client.get_signer(&device.signer_key(&env)),
+        Some(SignerVal::Ed25519(
+            SignerExpiration(None),
+            SignerLimits(None)
+        ))
+    );
+}
+
+/// An entry neither version can decode is a typed error, not a trap.
+#[test]
+fn migrate_legacy_signers_rejects_undecodable_entry() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+    let key = Ed25519Signer::new(2).signer_key(&env);
+
+    env.as_contract(&wallet, || {
+        env.storage().persistent().set::<SignerKey, u32>(&key, &7)
+    });
+
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_migrate_legacy_signers(&vec![&env, key], &reference()),
+        Err(Ok(Error::InvalidLegacySigner))
+    );
+}
+
+/// Entries with nothing to rewrite are skipped; unknown keys and missing
+/// auth are rejected.
+#[test]
+fn migrate_legacy_signers_skips_v1_and_rejects_unknown() {
+    let env = test_env();
+    let (_, client) = setup(&env);
+    let session = Ed25519Signer::new(2);
+    let unknown = Ed25519Signer::new(3);
+    let val = SignerVal::Ed25519(SignerExpiration(Some(NOW + 100)), SignerLimits(None));
+
+    client.mock_all_auths().add_signer(&session.signer(
+        &env,
+        SignerExpiration(Some(NOW + 100)),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+
+    assert!(client
+        .try_migrate_legacy_signers(&vec![&env, session.signer_key(&env)], &reference())
+        .is_err());
+
+    client
+        .mock_all_auths()
+        .migrate_legacy_signers(&vec![&env, session.signer_key(&env)], &reference());
+    assert_eq!(env.events().all(), []);
+    assert_eq!(client.get_signer(&session.signer_key(&env)), Some(val));
+
+    assert_eq!(
+        client.mock_all_auths().try_migrate_legacy_signers(
+            &vec![&env, session.signer_key(&env), unknown.signer_key(&env)],
+            &reference(),
+        ),
+        Err(Ok(Error::SignerNotFound))
+    );
+}
+
+/// A new wallet is born at the current schema version.
+#[test]
+fn migrate_refuses_current_schema() {
+    let env = test_env();
+    let (_, client) = setup(&env);
+
+    assert_eq!(client.get_schema_version(), CURRENT_SCHEMA_VERSION);
+    assert_eq!(
+        client.mock_all_auths().try_migrate(),
+        Err(Ok(Error::AlreadyMigrated))
+    );
+}
+
+/// A wallet deployed before schema versioning reads as version 0 and
+/// migrates one step per call, exactly once.
+#[test]
+fn migrate_steps_pre_versioning_wallet() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+
+    env.as_contract(&wallet, || {
+        env.storage().instance().remove(&symbol_short!("schema"))
+    });
+    assert_eq!(client.get_schema_version(), 0);
+
+    assert!(client.try_migrate().is_err());
+
+    client.mock_all_auths().migrate();
+
+    let expected = SchemaMigrated {
+        from_version: 0,
+        to_version: 1,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+    assert_eq!(client.get_schema_version(), 1);
+
+    assert_eq!(
+        client.mock_all_auths().try_migrate(),
+        Err(Ok(Error::AlreadyMigrated))
+    );
+}
+
+/// A version with no migration step is a typed error, not a trap.
+#[test]
+fn migrate_schema_step_rejects_unknown_version() {
+    let env = test_env();
+    let (wallet, _) = setup(&env);
+
+    env.as_contract(&wallet, || {
+        assert_eq!(migrate_schema_step(&env, 0), Ok(()));
+        assert_eq!(
+            migrate_schema_step(&env, CURRENT_SCHEMA_VERSION),
+            Err(Error::UnsupportedSchemaVersion)
+        );
+    });
+}
@@ -0,0 +1,147 @@
+#![cfg(test)]
+//! `prune_expired`: permissionless removal of expired and revoked signers,
+//! policies included.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::SignerRemoved,
+    types::{
+        Error, Signer, SignerExpiration, SignerKey, SignerLimits, SignerOptions, SignerStorage,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events as _, Ledger as _},
+    vec, Address, Env, Event as _,
+};
+
+use crate::tests::test_auth::CountingPolicy;
+use crate::tests::test_common::*;
+
+const NOW: u64 = 1_000_000;
+
+fn setup<'a>(env: &Env) -> (Address, crate::ContractClient<'a>) {
+    env.ledger().set_timestamp(NOW);
+
+    register_wallet(
+        env,
+        &Ed25519Signer::new(1).signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    )
+}
+
+/// Expired and revoked signers are removed without any auth; live, unknown
+/// and repeated keys are skipped.
+#[test]
+fn prune_expired_removes_only_dead_signers() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+    let expired = Ed25519Signer::new(2);
+    let revoked = Ed25519Signer::new(3);
+    let live = Ed25519Signer::new(4);
+    let unknown = Ed25519Signer::new(5);
+
+    client.mock_all_auths().add_signer(&revoked.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+    client.mock_all_auths().revoke_sessions();
+    client.mock_all_auths().add_signer(&expired.signer(
+        &env,
+        SignerExpiration(Some(NOW + 10)),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    client.mock_all_auths().add_signer(&live.signer(
+        &env,
+        SignerExpiration(Some(NOW + 100)),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+
+    // Inclusive expiration: not yet prunable.
+    env.ledger().set_timestamp(NOW + 10);
+    client.prune_expired(&vec![&env, expired.signer_key(&env)]);
+    assert!(client.get_signer(&expired.signer_key(&env)).is_some());
+
+    env.ledger().set_timestamp(NOW + 11);
+    client.prune_expired(&vec![
+        &env,
+        expired.signer_key(&env),
+        live.signer_key(&env),
+        unknown.signer_key(&env),
+        revoked.signer_key(&env),
+        expired.signer_key(&env),
+    ]);
+
+    assert_eq!(env.auths(), std::vec![]);
+    let first = SignerRemoved {
+        key: expired.signer_key(&env),
+        storage: SignerStorage::Persistent,
+    };
+    let second = SignerRemoved {
+        key: revoked.signer_key(&env),
+        storage: SignerStorage::Temporary,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), first.topics(&env), first.data(&env)),
+            (wallet.clone(), second.topics(&env), second.data(&env))
+        ]
+    );
+
+    assert_eq!(client.get_signer(&expired.signer_key(&env)), None);
+    assert_eq!(client.get_signer(&revoked.signer_key(&env)), None);
+    assert!(client.get_signer(&live.signer_key(&env)).is_some());
+}
+
+/// An expired policy is pruned like any other signer, and leaves the
+/// counters alone.
+#[test]
+fn prune_expired_removes_expired_policies() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+    let policy = env.register(CountingPolicy, ());
+    let key = SignerKey::Policy(policy.clone());
+
+    client.mock_all_auths().add_signer(&Signer::Policy(
+        policy,
+        SignerExpiration(Some(NOW + 10)),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+        SignerOptions::default(),
+    ));
+
+    env.ledger().set_timestamp(NOW + 11);
+    client.prune_expired(&vec![&env, key.clone()]);
+
+    let expected = SignerRemoved {
+        key: key.clone(),
+        storage: SignerStorage::Persistent,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+    assert_eq!(client.get_signer(&key), None);
+
+    // The owner is still the wallet's last admin.
+    let owner = Ed25519Signer::new(1);
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&owner.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+}
@@ -0,0 +1,225 @@
+#![cfg(test)]
+//! The signer registry: an opted-in wallet reporting its added and removed
+//! signers, `sync` for everything else, squatters never keeping a wallet
+//! out, and a failed report never blocking the wallet.
+
+extern crate std;
+
+use signer_registry::{Contract as RegistryContract, ContractClient as RegistryClient, MAX_PAGE};
+use smart_wallet_interface::{
+    events::{SignerAdded, SignerRegistryFailed},
+    types::{SignerExpiration, SignerLimits, SignerOptions, SignerStorage, SignerVal},
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, Event as _,
+};
+
+use crate::tests::test_common::*;
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    Ed25519Signer,
+    Address,
+    crate::ContractClient<'a>,
+    RegistryClient<'a>,
+) {
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(
+        env,
+        &owner.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+    let registry = env.register(RegistryContract, ());
+
+    (owner, wallet, client, RegistryClient::new(env, &registry))
+}
+
+/// Once opted in, added signers are registered and removed or pruned ones
+/// unregistered.
+#[test]
+fn opted_in_wallet_reports_signer_changes() {
+    let env = test_env();
+    let (_, wallet, client, registry) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let session = Ed25519Signer::new(3);
+
+    client
+        .mock_all_auths()
+        .set_signer_registry(&Some(registry.address.clone()));
+    assert_eq!(client.get_signer_registry(), Some(registry.address.clone()));
+
+    client.mock_all_auths().add_signer(&device.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    let expiration = env.ledger().timestamp() + 100;
+    client.mock_all_auths().add_signer(&session.signer(
+        &env,
+        SignerExpiration(Some(expiration)),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+    assert_eq!(
+        registry.lookup(&device.signer_key(&env), &0, &10),
+        vec![&env, wallet.clone()]
+    );
+    assert_eq!(
+        registry.lookup(&session.signer_key(&env), &0, &10),
+        vec![&env, wallet.clone()]
+    );
+
+    client
+        .mock_all_auths()
+        .remove_signer(&device.signer_key(&env));
+    assert_eq!(
+        registry.lookup(&device.signer_key(&env), &0, &10),
+        vec![&env]
+    );
+
+    env.ledger().set_timestamp(expiration + 1);
+    client.prune_expired(&vec![&env, session.signer_key(&env)]);
+    assert_eq!(
+        registry.lookup(&session.signer_key(&env), &0, &10),
+        vec![&env]
+    );
+}
+
+/// `sync` registers signers held before opting in, and drops entries once
+/// the wallet no longer holds the signer or reports elsewhere.
+#[test]
+fn sync_reconciles_with_wallet() {
+    let env = test_env();
+    let (owner, wallet, client, registry) = setup(&env);
+    let owner_key = owner.signer_key(&env);
+
+    // Not opted in: sync records nothing.
+    registry.sync(&wallet, &owner_key);
+    assert_eq!(registry.lookup(&owner_key, &0, &10), vec![&env]);
+
+    client
+        .mock_all_auths()
+        .set_signer_registry(&Some(registry.address.clone()));
+    registry.sync(&wallet, &owner_key);
+    assert_eq!(
+        registry.lookup(&owner_key, &0, &10),
+        vec![&env, wallet.clone()]
+    );
+
+    // Opting out leaves the entry stale until synced.
+    client.mock_all_auths().set_signer_registry(&None);
+    assert_eq!(
+        registry.lookup(&owner_key, &0, &10),
+        vec![&env, wallet.clone()]
+    );
+    registry.sync(&wallet, &owner_key);
+    assert_eq!(registry.lookup(&owner_key, &0, &10), vec![&env]);
+}
+
+/// Any number of wallets claiming a key cannot keep another out: each is
+/// its own entry, and `lookup` pages through them. Unregistering moves the
+/// last wallet into the freed position.
+#[test]
+fn squatters_do_not_block_wallet() {
+    let env = test_env();
+    let (_, wallet, client, registry) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let device_key = device.signer_key(&env);
+
+    let squatters: std::vec::Vec<Address> = (0..60).map(|_| Address::generate(&env)).collect();
+    for squatter in squatters.iter() {
+        registry.mock_all_auths().register(squatter, &device_key);
+    }
+
+    client
+        .mock_all_auths()
+        .set_signer_registry(&Some(registry.address.clone()));
+    client.mock_all_auths().add_signer(&device.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    assert_eq!(registry.count(&device_key), 61);
+
+    // Pages are capped at `MAX_PAGE`.
+    let first = registry.lookup(&device_key, &0, &100);
+    assert_eq!(first.len(), MAX_PAGE);
+    let rest = registry.lookup(&device_key, &MAX_PAGE, &100);
+    assert_eq!(rest.len(), 61 - MAX_PAGE);
+    assert_eq!(rest.last(), Some(wallet.clone()));
+    assert_eq!(registry.lookup(&device_key, &61, &10), vec![&env]);
+
+    // Re-registering is idempotent.
+    registry
+        .mock_all_auths()
+        .register(&squatters[0], &device_key);
+    assert_eq!(registry.count(&device_key), 61);
+
+    registry
+        .mock_all_auths()
+        .unregister(&squatters[0], &device_key);
+    assert_eq!(registry.count(&device_key), 60);
+    assert_eq!(
+        registry.lookup(&device_key, &0, &1),
+        vec![&env, wallet.clone()]
+    );
+
+    for squatter in squatters.iter().skip(1) {
+        registry.mock_all_auths().unregister(squatter, &device_key);
+    }
+    assert_eq!(
+        registry.lookup(&device_key, &0, &10),
+        vec![&env, wallet.clone()]
+    );
+    client.mock_all_auths().remove_signer(&device_key);
+    assert_eq!(registry.count(&device_key), 0);
+}
+
+/// A registry that fails the report does not block the signer change, and
+/// the failure is announced.
+#[test]
+fn failed_report_emits_event() {
+    let env = test_env();
+    let (_, wallet, client, _) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let broken = Address::generate(&env);
+
+    client
+        .mock_all_auths()
+        .set_signer_registry(&Some(broken.clone()));
+    client.mock_all_auths().add_signer(&device.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+
+    let added = SignerAdded {
+        key: device.signer_key(&env),
+        val: SignerVal::Ed25519(SignerExpiration(None), SignerLimits(None)),
+        storage: SignerStorage::Persistent,
+        options: SignerOptions::default(),
+    };
+    let failed = SignerRegistryFailed {
+        key: device.signer_key(&env),
+        registry: broken,
+        registered: true,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), failed.topics(&env), failed.data(&env)),
+            (wallet, added.topics(&env), added.data(&env)),
+        ]
+    );
+    assert!(client.get_signer(&device.signer_key(&env)).is_some());
+}
@@ -0,0 +1,292 @@
+#![cfg(test)]
+//! P-256 public key validation and decompression (`secp256r1.rs`), checked
+//! against the `p256` crate, plus the registration paths that use it.
+
+extern crate std;
+
+use p256::{
+    elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint},
+    AffinePoint, EncodedPoint, SecretKey,
+};
+use smart_wallet_interface::types::{
+    Error, Signatures, Signer, SignerExpiration, SignerLimits, SignerOptions, SignerStorage,
+    SignerVal,
+};
+use soroban_sdk::{map, testutils::Address as _, Address, Bytes, Env, IntoVal};
+
+use crate::secp256r1::decode_public_key;
+use crate::tests::test_common::*;
+
+fn decode(env: &Env, bytes: &[u8]) -> Result<std::vec::Vec<u8>, Error> {
+    decode_public_key(env, &Bytes::from_slice(env, bytes)).map(|key| key.to_array().to_vec())
+}
+
+fn uncompressed(secret: &SecretKey) -> std::vec::Vec<u8> {
+    secret
+        .public_key()
+        .to_encoded_point(false)
+        .as_bytes()
+        .to_vec()
+}
+
+fn compressed(secret: &SecretKey) -> std::vec::Vec<u8> {
+    secret
+        .public_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec()
+}
+
+/// Both encodings of many keys (both y parities) decode to the reference
+/// uncompressed point.
+#[test]
+fn decodes_both_encodings_like_reference() {
+    let env = test_env();
+
+    for seed in 1u8..=64 {
+        let secret = SecretKey::from_slice(&[seed; 32]).unwrap();
+        let expected = uncompressed(&secret);
+
+        assert_eq!(decode(&env, &expected), Ok(expected.clone()), "seed {seed}");
+        assert_eq!(
+            decode(&env, &compressed(&secret)),
+            Ok(expected),
+            "seed {seed}"
+        );
+    }
+}
+
+/// The generator point, from its published coordinates.
+#[test]
+fn decompresses_generator() {
+    let env = test_env();
+    let gx = "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+    let gy = "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+
+    let mut compressed = std::vec![0x03u8];
+    compressed.extend(hex_bytes(gx));
+    let mut expected = std::vec![0x04u8];
+    expected.extend(hex_bytes(gx));
+    expected.extend(hex_bytes(gy));
+
+    assert_eq!(decode(&env, &compressed), Ok(expected));
+}
+
+fn hex_bytes(hex: &str) -> std::vec::Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn rejects_off_curve_point() {
+    let env = test_env();
+    let secret = SecretKey::from_slice(&[7; 32]).unwrap();
+    let mut key = uncompressed(&secret);
+    key[64] ^= 0x01;
+
+    assert_eq!(decode(&env, &key), Err(Error::InvalidPublicKey));
+}
+
+/// Roughly half of all x values have no curve point; find one and check the
+/// compressed decoder rejects it (and that the reference agrees).
+#[test]
+fn rejects_compressed_x_without_point() {
+    let env = test_env();
+
+    let mut found = 0;
+    for seed in 0u8..=255 {
+        let mut key = std::vec![0x02u8];
+        key.extend([seed; 32]);
+
+        let reference = EncodedPoint::from_bytes(&key)
+            .ok()
+            .and_then(|point| Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&point)));
+
+        match reference {
+            Some(point) => assert_eq!(
+                decode(&env, &key),
+                Ok(point.to_encoded_point(false).as_bytes().to_vec()),
+                "seed {seed}"
+            ),
+            None => {
+                found += 1;
+                assert_eq!(
+                    decode(&env, &key),
+                    Err(Error::InvalidPublicKey),
+                    "seed {seed}"
+                );
+            }
+        }
+    }
+
+    assert!(found > 0);
+}
+
+#[test]
+fn rejects_identity_and_malformed_encodings() {
+    let env = test_env();
+    let secret = SecretKey::from_slice(&[9; 32]).unwrap();
+
+    // The SEC-1 identity encoding, and an all-zero "point".
+    assert_eq!(decode(&env, &[0x00]), Err(Error::InvalidPublicKey));
+    assert_eq!(
+        decode(
+            &env,
+            &[0x04; 1]
+                .iter()
+                .copied()
+                .chain([0u8; 64])
+                .collect::<std::vec::Vec<_>>()
+        ),
+        Err(Error::InvalidPublicKey)
+    );
+
+    // Wrong tag for the length.
+    let mut key = uncompressed(&secret);
+    key[0] = 0x02;
+    assert_eq!(decode(&env, &key), Err(Error::InvalidPublicKey));
+    let mut key = compressed(&secret);
+    key[0] = 0x04;
+    assert_eq!(decode(&env, &key), Err(Error::InvalidPublicKey));
+
+    // Hybrid (0x06/0x07) and truncated encodings.
+    let mut key = uncompressed(&secret);
+    key[0] = 0x06;
+    assert_eq!(decode(&env, &key), Err(Error::InvalidPublicKey));
+    assert_eq!(
+        decode(&env, &uncompressed(&secret)[..64]),
+        Err(Error::InvalidPublicKey)
+    );
+    assert_eq!(decode(&env, &[]), Err(Error::InvalidPublicKey));
+}
+
+/// x >= p is rejected even when x - p is a valid abscissa.
+#[test]
+fn rejects_non_canonical_coordinate() {
+    let env = test_env();
+    let mut key = std::vec![0x02u8];
+    key.extend([0xff; 32]);
+
+    assert_eq!(decode(&env, &key), Err(Error::InvalidPublicKey));
+}
+
+// --- Registration ------------------------------------------------------------
+
+/// A wallet registered with a COMPRESSED key stores the uncompressed form and
+/// verifies real assertions from that passkey.
+#[test]
+fn compressed_key_registers_and_verifies() {
+    let env = test_env();
+    let passkey = Passkey::new(3);
+
+    let (wallet, client) = register_wallet(
+        &env,
+        &Signer::Secp256r1(
+            passkey.key_id(&env),
+            passkey.compressed_public_key(&env),
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+            SignerOptions::default(),
+        ),
+    );
+
+    assert_eq!(
+        client.get_signer(&passkey.signer_key(&env)),
+        Some(SignerVal::Secp256r1(
+            passkey.public_key(&env),
+            SignerExpiration(None),
+            SignerLimits(None)
+        ))
+    );
+
+    let payload = payload(&env, 7);
+    let token = Address::generate(&env);
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (passkey.signer_key(&env), passkey.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &soroban_sdk::vec![&env, transfer_context(&env, &token, &wallet, 1)],
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn add_and_update_reject_invalid_key() {
+    let env = test_env();
+    let passkey = Passkey::new(4);
+
+    let (_, client) = register_wallet(
+        &env,
+        &Ed25519Signer::new(1).signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    let mut bad_key = passkey.public_key_bytes;
+    bad_key[64] ^= 0x01;
+    let bad_signer = Signer::Secp256r1(
+        passkey.key_id(&env),
+        Bytes::from_slice(&env, &bad_key),
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+        SignerOptions::default(),
+    );
+
+    assert_eq!(
+        client.mock_all_auths().try_add_signer(&bad_signer),
+        Err(Ok(Error::InvalidPublicKey))
+    );
+    assert_eq!(client.get_signer(&passkey.signer_key(&env)), None);
+
+    client.mock_all_auths().add_signer(&passkey.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+
+    assert_eq!(
+        client.mock_all_auths().try_update_signer(&bad_signer),
+        Err(Ok(Error::InvalidPublicKey))
+    );
+    assert_eq!(
+        client.get_signer(&passkey.signer_key(&env)),
+        Some(SignerVal::Secp256r1(
+            passkey.public_key(&env),
+            SignerExpiration(None),
+            SignerLimits(None)
+        ))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")]
+fn constructor_rejects_invalid_key() {
+    let env = test_env();
+    let passkey = Passkey::new(5);
+
+    register_wallet(
+        &env,
+        &Signer::Secp256r1(
+            passkey.key_id(&env),
+            Bytes::from_slice(&env, &[0x04; 65]),
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+            SignerOptions::default(),
+        ),
+    );
+}
@@ -0,0 +1,256 @@
+#![cfg(test)]
+//! `revoke_sessions`: generation-based mass revocation of non-durable
+//! signers, and how the recorded generation survives updates.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::SessionsRevoked,
+    types::{Error, Signatures, SignerExpiration, SignerKey, SignerLimits, SignerStorage},
+};
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Events as _},
+    vec, Address, Env, Event as _, IntoVal,
+};
+
+use crate::storage::StorageKey;
+use crate::tests::test_auth::CountingPolicy;
+use crate::tests::test_common::*;
+
+fn setup<'a>(env: &Env) -> (Ed25519Signer, Address, crate::ContractClient<'a>) {
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(
+        env,
+        &owner.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    (owner, wallet, client)
+}
+
+fn check(
+    env: &Env,
+    wallet: &Address,
+    signers: &[&Ed25519Signer],
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let payload = payload(env, 5);
+    let mut signatures = map![env];
+    for signer in signers {
+        signatures.set(signer.signer_key(env), signer.sign(env, &payload));
+    }
+
+    env.try_invoke_contract_check_auth::<Error>(
+        wallet,
+        &payload,
+        Signatures(signatures).into_val(env),
+        &vec![
+            env,
+            transfer_context(env, &Address::generate(env), wallet, 1),
+        ],
+    )
+}
+
+/// Temporary and expiring signers are revoked; durable ones are not, and
+/// sessions added afterwards work.
+#[test]
+fn revoke_sessions_expires_existing_sessions_only() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let temporary = Ed25519Signer::new(2);
+    let expiring = Ed25519Signer::new(3);
+    let later = Ed25519Signer::new(4);
+
+    client.mock_all_auths().add_signer(&temporary.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+    client.mock_all_auths().add_signer(&expiring.signer(
+        &env,
+        SignerExpiration(Some(u64::MAX)),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    assert_eq!(check(&env, &wallet, &[&temporary]), Ok(()));
+    assert_eq!(check(&env, &wallet, &[&expiring]), Ok(()));
+
+    client.mock_all_auths().revoke_sessions();
+    assert_eq!(client.get_session_generation(), 1);
+
+    assert_eq!(
+        check(&env, &wallet, &[&temporary]),
+        Err(Ok(Error::SignerExpired))
+    );
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &expiring]),
+        Err(Ok(Error::SignerExpired))
+    );
+    assert_eq!(check(&env, &wallet, &[&owner]), Ok(()));
+
+    client.mock_all_auths().add_signer(&later.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+    assert_eq!(check(&env, &wallet, &[&later]), Ok(()));
+
+    // Each call revokes everything added before it.
+    client.mock_all_auths().revoke_sessions();
+    assert_eq!(
+        check(&env, &wallet, &[&later]),
+        Err(Ok(Error::SignerExpired))
+    );
+}
+
+#[test]
+fn revoke_sessions_requires_auth_and_emits_event() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+
+    assert!(client.try_revoke_sessions().is_err());
+    assert_eq!(client.get_session_generation(), 0);
+
+    client.mock_all_auths().revoke_sessions();
+    let revoked = SessionsRevoked { generation: 1 };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), revoked.topics(&env), revoked.data(&env))
+        ]
+    );
+
+    // Permitted while frozen: it passes the context allowlist and reaches
+    // the admin check (a single-admin wallet, so the timelock first).
+    client.mock_all_auths().freeze();
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload(&env, 1),
+            Signatures(map![&env]).into_val(&env),
+            &vec![
+                &env,
+                contract_context(&env, &wallet, "revoke_sessions", vec![&env])
+            ],
+        ),
+        Err(Ok(Error::FreezeTimelockActive))
+    );
+}
+
+/// An update keeps a session's generation — it never revives a revoked
+/// session — while a durable signer demoted to a session joins the current
+/// generation. Re-adding re-grants.
+#[test]
+fn update_keeps_session_generation() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let session = Ed25519Signer::new(2);
+    let promoted = Ed25519Signer::new(3);
+    let temporary = |signer: &Ed25519Signer| {
+        signer.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Temporary,
+        )
+    };
+
+    client.mock_all_auths().add_signer(&temporary(&session));
+    client.mock_all_auths().add_signer(&promoted.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    client.mock_all_auths().revoke_sessions();
+
+    // Durability flip to an expiring Persistent entry: still revoked.
+    client.mock_all_auths().update_signer(&session.signer(
+        &env,
+        SignerExpiration(Some(u64::MAX)),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &session]),
+        Err(Ok(Error::SignerExpired))
+    );
+
+    // A durable signer demoted after the revocation is a current session.
+    client.mock_all_auths().update_signer(&temporary(&promoted));
+    assert_eq!(check(&env, &wallet, &[&promoted]), Ok(()));
+    let recorded: Option<u32> = env.as_contract(&wallet, || {
+        env.storage()
+            .temporary()
+            .get(&StorageKey::SessionGeneration(promoted.signer_key(&env)))
+    });
+    assert_eq!(recorded, Some(1));
+
+    client
+        .mock_all_auths()
+        .remove_signer(&session.signer_key(&env));
+    client.mock_all_auths().add_signer(&temporary(&session));
+    assert_eq!(check(&env, &wallet, &[&session]), Ok(()));
+}
+
+/// A revoked session policy required as a co-signer rejects the candidate
+/// like an expired one, without being consulted.
+#[test]
+fn revoked_required_policy_rejects_candidate() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let policy = env.register(CountingPolicy, ());
+    let token = Address::generate(&env);
+    let limited = Ed25519Signer::new(2);
+
+    client
+        .mock_all_auths()
+        .add_signer(&smart_wallet_interface::types::Signer::Policy(
+            policy.clone(),
+            SignerExpiration(None),
+            SignerLimits(Some(map![&env])),
+            SignerStorage::Temporary,
+            Default::default(),
+        ));
+    client.mock_all_auths().add_signer(&limited.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![
+            &env,
+            (
+                token.clone(),
+                Some(vec![&env, SignerKey::Policy(policy.clone())])
+            )
+        ])),
+        SignerStorage::Persistent,
+    ));
+    client.mock_all_auths().revoke_sessions();
+
+    let payload = payload(&env, 4);
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (limited.signer_key(&env), limited.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &vec![&env, transfer_context(&env, &token, &wallet, 1)],
+        ),
+        Err(Ok(Error::MissingContext))
+    );
+    let count: Option<u32> = env.as_contract(&policy, || {
+        env.storage()
+            .instance()
+            .get(&soroban_sdk::symbol_short!("count"))
+    });
+    assert_eq!(count, None);
+}
@@ -0,0 +1,254 @@
+#![cfg(test)]
+//! `is_valid_signature`: pass-2 verification against a domain-separated
+//! digest of an arbitrary hash, gated on authority over the wallet itself,
+//! with nothing recorded.
+
+extern crate std;
+
+use smart_wallet_interface::types::{
+    Signature, Signatures, Signer, SignerExpiration, SignerKey, SignerLimits, SignerOptions,
+    SignerStorage,
+};
+use soroban_sdk::{
+    map, symbol_short, testutils::Address as _, vec, xdr::ToXdr, Address, BytesN, Env,
+};
+
+use crate::tests::{test_auth::CountingPolicy, test_common::*};
+
+fn owner_signer(env: &Env, owner: &Ed25519Signer) -> Signer {
+    owner.signer(
+        env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    )
+}
+
+fn policy_count(env: &Env, policy: &Address) -> u32 {
+    env.as_contract(policy, || {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("count"))
+            .unwrap_or(0)
+    })
+}
+
+/// Signers sign the `sw_1271` digest binding the hash to the network and
+/// the wallet, not the hash itself.
+#[test]
+fn signature_hash_is_domain_separated() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(&env, &owner_signer(&env, &owner));
+    let (_, other_client) = register_wallet(&env, &owner_signer(&env, &owner));
+    let hash = payload(&env, 7);
+
+    let preimage = (
+        symbol_short!("sw_1271"),
+        env.ledger().network_id(),
+        wallet.clone(),
+        hash.clone(),
+    )
+        .to_xdr(&env);
+    let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+    assert_eq!(client.get_signature_hash(&hash), digest);
+    assert_ne!(other_client.get_signature_hash(&hash), digest);
+
+    // A signature over the raw hash, or another wallet's digest, does not
+    // verify (a host trap).
+    for signed in [hash.clone(), other_client.get_signature_hash(&hash)] {
+        let signatures = Signatures(map![
+            &env,
+            (owner.signer_key(&env), owner.sign(&env, &signed))
+        ]);
+        assert!(client.try_is_valid_signature(&hash, &signatures).is_err());
+    }
+
+    let signatures = Signatures(map![
+        &env,
+        (owner.signer_key(&env), owner.sign(&env, &digest))
+    ]);
+    assert!(client.is_valid_signature(&hash, &signatures));
+}
+
+/// Ed25519 and WebAuthn admins sign a digest, and checking it records no use.
+#[test]
+fn is_valid_signature_accepts_wallet_signers() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let passkey = Passkey::new(2);
+    let (_, client) = register_wallet(&env, &owner_signer(&env, &owner));
+    client.mock_all_auths().add_signer(&passkey.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    let hash = payload(&env, 7);
+    let digest = client.get_signature_hash(&hash);
+
+    let signatures = Signatures(map![
+        &env,
+        (owner.signer_key(&env), owner.sign(&env, &digest))
+    ]);
+    assert!(client.is_valid_signature(&hash, &signatures));
+
+    let signatures = Signatures(map![
+        &env,
+        (passkey.signer_key(&env), passkey.sign(&env, &digest))
+    ]);
+    assert!(client.is_valid_signature(&hash, &signatures));
+
+    for signer_key in [owner.signer_key(&env), passkey.signer_key(&env)] {
+        let activity = client.get_signer_activity(&signer_key).unwrap();
+        assert_eq!(activity.use_count, 0);
+    }
+    assert_eq!(client.get_last_active(), None);
+}
+
+/// A signature over another digest is invalid: a WebAuthn challenge mismatch
+/// returns `false`, while Ed25519 and secp256r1 signatures that do not verify
+/// trap in the host. An unknown signer is `false`.
+#[test]
+fn is_valid_signature_rejects_other_digest() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let passkey = Passkey::new(2);
+    let (_, client) = register_wallet(&env, &owner_signer(&env, &owner));
+    client.mock_all_auths().add_signer(&passkey.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    let hash = payload(&env, 7);
+    let digest = client.get_signature_hash(&hash);
+    let other = payload(&env, 8);
+
+    let signatures = Signatures(map![
+        &env,
+        (passkey.signer_key(&env), passkey.sign(&env, &other))
+    ]);
+    assert!(!client.is_valid_signature(&hash, &signatures));
+
+    let signatures = Signatures(map![
+        &env,
+        (owner.signer_key(&env), owner.sign(&env, &other))
+    ]);
+    assert!(client.try_is_valid_signature(&hash, &signatures).is_err());
+
+    // The right challenge, but signature bytes that do not verify.
+    let Signature::Secp256r1(mut assertion) = passkey.sign(&env, &digest) else {
+        unreachable!()
+    };
+    let mut bytes = assertion.signature.to_array();
+    bytes[10] ^= 1;
+    assertion.signature = BytesN::from_array(&env, &bytes);
+    let signatures = Signatures(map![
+        &env,
+        (passkey.signer_key(&env), Signature::Secp256r1(assertion))
+    ]);
+    assert!(client.try_is_valid_signature(&hash, &signatures).is_err());
+
+    let stranger = Ed25519Signer::new(3);
+    let signatures = Signatures(map![
+        &env,
+        (stranger.signer_key(&env), stranger.sign(&env, &digest))
+    ]);
+    assert!(!client.is_valid_signature(&hash, &signatures));
+}
+
+/// Only a signer with authority over the wallet itself signs for it: a
+/// signer limited to a token does not, and a frozen wallet signs nothing.
+#[test]
+fn is_valid_signature_requires_wallet_authority() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let session = Ed25519Signer::new(2);
+    let token = Address::generate(&env);
+    let (_, client) = register_wallet(&env, &owner_signer(&env, &owner));
+    client.mock_all_auths().add_signer(&session.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env, (token, None)])),
+        SignerStorage::Persistent,
+    ));
+    let hash = payload(&env, 7);
+    let digest = client.get_signature_hash(&hash);
+
+    let signatures = Signatures(map![
+        &env,
+        (session.signer_key(&env), session.sign(&env, &digest))
+    ]);
+    assert!(!client.is_valid_signature(&hash, &signatures));
+
+    let signatures = Signatures(map![
+        &env,
+        (owner.signer_key(&env), owner.sign(&env, &digest))
+    ]);
+    assert!(client.is_valid_signature(&hash, &signatures));
+
+    client.mock_all_auths().freeze();
+    assert!(!client.is_valid_signature(&hash, &signatures));
+}
+
+/// Policies are consulted as in pass 2: a policy entry and a signer whose
+/// wallet-self limit needs a policy's approval are valid once the policy
+/// approves. An `Address` entry without its authorization traps.
+#[test]
+fn is_valid_signature_consults_policies() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let guarded = Ed25519Signer::new(2);
+    let (wallet, client) = register_wallet(&env, &owner_signer(&env, &owner));
+    let policy = env.register(CountingPolicy, ());
+    let delegate = Address::generate(&env);
+    client.mock_all_auths().add_signer(&Signer::Policy(
+        policy.clone(),
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+        SignerOptions::default(),
+    ));
+    client.mock_all_auths().add_signer(&guarded.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![
+            &env,
+            (
+                wallet.clone(),
+                Some(vec![&env, SignerKey::Policy(policy.clone())])
+            )
+        ])),
+        SignerStorage::Persistent,
+    ));
+    client.mock_all_auths().add_signer(&Signer::Address(
+        delegate.clone(),
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+        SignerOptions::default(),
+    ));
+    let hash = payload(&env, 7);
+    let digest = client.get_signature_hash(&hash);
+
+    let signatures = Signatures(map![
+        &env,
+        (SignerKey::Policy(policy.clone()), Signature::Policy)
+    ]);
+    assert!(client.is_valid_signature(&hash, &signatures));
+    assert_eq!(policy_count(&env, &policy), 1);
+
+    let signatures = Signatures(map![
+        &env,
+        (guarded.signer_key(&env), guarded.sign(&env, &digest))
+    ]);
+    assert!(client.is_valid_signature(&hash, &signatures));
+    assert_eq!(policy_count(&env, &policy), 2);
+
+    let signatures = Signatures(map![
+        &env,
+        (SignerKey::Address(delegate), Signature::Address)
+    ]);
+    assert!(client.try_is_valid_signature(&hash, &signatures).is_err());
+}
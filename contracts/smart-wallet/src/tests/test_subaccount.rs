@@ -0,0 +1,241 @@
+#![cfg(test)]
+//! Subaccounts: `create_subaccount` deploying a child wallet whose admin is
+//! the parent, through an `Address` signer, and the parent authorizing for
+//! it.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::SubaccountCreated,
+    types::{
+        Error, Signature, Signatures, Signer, SignerExpiration, SignerKey, SignerLimits,
+        SignerOptions, SignerStorage, SignerVal, SubaccountConfig,
+    },
+};
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Events as _},
+    vec,
+    xdr::{
+        InvokeContractArgs, ScVal, SorobanAddressCredentials, SorobanAuthorizationEntry,
+        SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials, VecM,
+    },
+    Address, BytesN, Env, Event as _, IntoVal, TryFromVal, Val, Vec,
+};
+
+use crate::tests::test_common::*;
+
+fn invocation(
+    env: &Env,
+    contract: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+) -> SorobanAuthorizedInvocation {
+    let args: std::vec::Vec<ScVal> = args
+        .iter()
+        .map(|arg| ScVal::try_from_val(env, &arg).unwrap())
+        .collect();
+
+    SorobanAuthorizedInvocation {
+        function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+            contract_address: contract.clone().into(),
+            function_name: fn_name.try_into().unwrap(),
+            args: args.try_into().unwrap(),
+        }),
+        sub_invocations: VecM::default(),
+    }
+}
+
+fn address_entry(
+    address: &Address,
+    nonce: i64,
+    root_invocation: SorobanAuthorizedInvocation,
+    signatures: Signatures,
+) -> SorobanAuthorizationEntry {
+    SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: address.clone().into(),
+            nonce,
+            signature_expiration_ledger: address.env().ledger().sequence(),
+            signature: signatures.try_into().unwrap(),
+        }),
+        root_invocation,
+    }
+}
+
+/// The parent deploys a subaccount at a salt-derived address, with itself as
+/// an unlimited durable `Address` signer ahead of the configured signers,
+/// and records the link.
+#[test]
+fn create_subaccount_deploys_child_with_parent_admin() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let spender = Ed25519Signer::new(2);
+    let (parent, client) = register_wallet(
+        &env,
+        &owner.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+    let wasm_hash = upload_wallet_wasm(&env);
+    let token = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+
+    let config = SubaccountConfig {
+        wasm_hash: wasm_hash.clone(),
+        signers: vec![
+            &env,
+            spender.signer(
+                &env,
+                SignerExpiration(None),
+                SignerLimits(Some(map![&env, (token.clone(), None)])),
+                SignerStorage::Persistent,
+            ),
+        ],
+    };
+
+    assert!(client.try_create_subaccount(&salt, &config).is_err());
+
+    let subaccount = client.mock_all_auths().create_subaccount(&salt, &config);
+
+    let expected = SubaccountCreated {
+        subaccount: subaccount.clone(),
+        wasm_hash,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&parent),
+        vec![
+            &env,
+            (parent.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+    assert!(client.is_subaccount(&subaccount));
+    assert!(!client.is_subaccount(&Address::generate(&env)));
+
+    let child = crate::ContractClient::new(&env, &subaccount);
+    assert_eq!(
+        child.get_signer(&SignerKey::Address(parent.clone())),
+        Some(SignerVal::Address(
+            SignerExpiration(None),
+            SignerLimits(None)
+        ))
+    );
+    assert!(child.get_signer(&spender.signer_key(&env)).is_some());
+
+    // The salt is spent: the same address cannot be deployed twice.
+    assert!(client
+        .mock_all_auths()
+        .try_create_subaccount(&salt, &config)
+        .is_err());
+}
+
+/// The parent's signers administer the subaccount: the subaccount's
+/// `Address` entry is satisfied by the parent authorizing the subaccount's
+/// `__check_auth` for the payload, in the parent's own entry.
+#[test]
+fn parent_authorizes_for_subaccount() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let (parent, client) = register_wallet(
+        &env,
+        &owner.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+    let subaccount = client.mock_all_auths().create_subaccount(
+        &BytesN::from_array(&env, &[7; 32]),
+        &SubaccountConfig {
+            wasm_hash: upload_wallet_wasm(&env),
+            signers: vec![&env],
+        },
+    );
+    let child = crate::ContractClient::new(&env, &subaccount);
+
+    let recovery = Ed25519Signer::new(3).signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    );
+    let child_invocation = invocation(
+        &env,
+        &subaccount,
+        "add_signer",
+        vec![&env, recovery.into_val(&env)],
+    );
+    let child_payload = auth_payload(&env, 1, env.ledger().sequence(), &child_invocation);
+    let child_entry = address_entry(
+        &subaccount,
+        1,
+        child_invocation,
+        Signatures(map![
+            &env,
+            (SignerKey::Address(parent.clone()), Signature::Address)
+        ]),
+    );
+
+    // Without the parent's own authorization the delegation fails.
+    assert!(child
+        .set_auths(core::slice::from_ref(&child_entry))
+        .try_add_signer(&recovery)
+        .is_err());
+
+    let parent_invocation = invocation(
+        &env,
+        &subaccount,
+        "__check_auth",
+        vec![&env, child_payload.into_val(&env)],
+    );
+    let parent_payload = auth_payload(&env, 2, env.ledger().sequence(), &parent_invocation);
+    let parent_entry = address_entry(
+        &parent,
+        2,
+        parent_invocation,
+        Signatures(map![
+            &env,
+            (owner.signer_key(&env), owner.sign(&env, &parent_payload))
+        ]),
+    );
+
+    child
+        .set_auths(&[child_entry, parent_entry])
+        .add_signer(&recovery);
+
+    assert!(child
+        .get_signer(&Ed25519Signer::new(3).signer_key(&env))
+        .is_some());
+}
+
+/// An `Address` signer may not be the wallet itself: it could never sign,
+/// yet would count as an admin.
+#[test]
+fn address_signer_cannot_be_wallet() {
+    let env = test_env();
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(
+        &env,
+        &owner.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    assert_eq!(
+        client.mock_all_auths().try_add_signer(&Signer::Address(
+            wallet,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+            SignerOptions::default(),
+        )),
+        Err(Ok(Error::SelfAddressSigner))
+    );
+}
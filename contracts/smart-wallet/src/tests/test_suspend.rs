@@ -0,0 +1,359 @@
+#![cfg(test)]
+//! `suspend_signer`/`resume_signer`: pass-1 skipping, pass-2 rejection,
+//! required-policy checks, admin accounting, idempotence, events, and the
+//! suspension flag's lifecycle across updates and removal.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::{SignerResumed, SignerSuspended},
+    types::{
+        Error, Signatures, Signer, SignerExpiration, SignerKey, SignerLimits, SignerOptions,
+        SignerStorage,
+    },
+};
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Events as _},
+    vec, Address, Env, Event as _, IntoVal,
+};
+
+use crate::storage::StorageKey;
+use crate::tests::test_admin::LifecyclePolicy;
+use crate::tests::test_auth::CountingPolicy;
+use crate::tests::test_common::*;
+
+fn admin(env: &Env, signer: &Ed25519Signer) -> Signer {
+    signer.signer(
+        env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    )
+}
+
+fn setup<'a>(env: &Env) -> (Ed25519Signer, Address, crate::ContractClient<'a>) {
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(env, &admin(env, &owner));
+
+    (owner, wallet, client)
+}
+
+fn check(
+    env: &Env,
+    wallet: &Address,
+    signers: &[&Ed25519Signer],
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let payload = payload(env, 7);
+    let mut signatures = map![env];
+    for signer in signers {
+        signatures.set(signer.signer_key(env), signer.sign(env, &payload));
+    }
+
+    env.try_invoke_contract_check_auth::<Error>(
+        wallet,
+        &payload,
+        Signatures(signatures).into_val(env),
+        &vec![
+            env,
+            transfer_context(env, &Address::generate(env), wallet, 1),
+        ],
+    )
+}
+
+/// The compromised-device flow: a suspended signer covers nothing and is
+/// rejected even as an extra entry; resuming restores it unchanged.
+#[test]
+fn suspended_signer_cannot_sign_until_resumed() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let key = device.signer_key(&env);
+
+    client.mock_all_auths().add_signer(&admin(&env, &device));
+    assert_eq!(check(&env, &wallet, &[&device]), Ok(()));
+
+    client.mock_all_auths().suspend_signer(&key);
+    assert_eq!(client.is_signer_suspended(&key), Some(true));
+    assert!(client.get_signer(&key).is_some());
+
+    // Alone: skipped as a candidate, so nothing covers the context.
+    assert_eq!(
+        check(&env, &wallet, &[&device]),
+        Err(Ok(Error::MissingContext))
+    );
+    // Next to a covering signer: pass 2 rejects the suspended entry.
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &device]),
+        Err(Ok(Error::SignerSuspended))
+    );
+
+    client.mock_all_auths().resume_signer(&key);
+    assert_eq!(client.is_signer_suspended(&key), Some(false));
+    assert_eq!(check(&env, &wallet, &[&device]), Ok(()));
+}
+
+#[test]
+fn suspend_and_resume_require_wallet_auth_and_stored_signer() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let unknown = Ed25519Signer::new(2);
+
+    assert!(client.try_suspend_signer(&owner.signer_key(&env)).is_err());
+    assert!(client.try_resume_signer(&owner.signer_key(&env)).is_err());
+
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_suspend_signer(&unknown.signer_key(&env)),
+        Err(Ok(Error::SignerNotFound))
+    );
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_resume_signer(&unknown.signer_key(&env)),
+        Err(Ok(Error::SignerNotFound))
+    );
+    assert_eq!(client.is_signer_suspended(&unknown.signer_key(&env)), None);
+
+    client.mock_all_auths().add_signer(&admin(&env, &unknown));
+    client
+        .mock_all_auths()
+        .suspend_signer(&unknown.signer_key(&env));
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, wallet);
+}
+
+/// Each transition emits its event; repeating one is a silent no-op.
+#[test]
+fn suspend_resume_events_and_idempotence() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let key = device.signer_key(&env);
+
+    client.mock_all_auths().add_signer(&admin(&env, &device));
+
+    client.mock_all_auths().suspend_signer(&key);
+    let suspended = SignerSuspended { key: key.clone() };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), suspended.topics(&env), suspended.data(&env))
+        ]
+    );
+
+    client.mock_all_auths().suspend_signer(&key);
+    assert_eq!(env.events().all(), []);
+    assert_eq!(client.is_signer_suspended(&key), Some(true));
+
+    client.mock_all_auths().resume_signer(&key);
+    let resumed = SignerResumed { key: key.clone() };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), resumed.topics(&env), resumed.data(&env))
+        ]
+    );
+
+    client.mock_all_auths().resume_signer(&key);
+    assert_eq!(env.events().all(), []);
+}
+
+/// A suspended admin is not counted: suspending the last one is refused,
+/// and while one is suspended the other cannot be removed.
+#[test]
+fn suspended_admin_not_counted() {
+    let env = test_env();
+    let (owner, _, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_suspend_signer(&owner.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+    assert_eq!(
+        client.is_signer_suspended(&owner.signer_key(&env)),
+        Some(false)
+    );
+
+    client.mock_all_auths().add_signer(&admin(&env, &device));
+    client
+        .mock_all_auths()
+        .suspend_signer(&device.signer_key(&env));
+
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&owner.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+
+    // Removing or updating the suspended admin leaves the count alone.
+    client.mock_all_auths().update_signer(&device.signer(
+        &env,
+        SignerExpiration(Some(u64::MAX)),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    ));
+    client
+        .mock_all_auths()
+        .remove_signer(&device.signer_key(&env));
+
+    // Resuming restores the count.
+    client.mock_all_auths().add_signer(&admin(&env, &device));
+    client
+        .mock_all_auths()
+        .suspend_signer(&device.signer_key(&env));
+    client
+        .mock_all_auths()
+        .resume_signer(&device.signer_key(&env));
+    client
+        .mock_all_auths()
+        .remove_signer(&owner.signer_key(&env));
+    assert_eq!(client.get_signer(&owner.signer_key(&env)), None);
+}
+
+/// A suspended required co-signer policy rejects the candidate without
+/// being consulted, like an expired one.
+#[test]
+fn suspended_required_policy_rejects_candidate() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let policy = env.register(CountingPolicy, ());
+    let token = Address::generate(&env);
+    let limited = Ed25519Signer::new(2);
+
+    client.mock_all_auths().add_signer(&Signer::Policy(
+        policy.clone(),
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env])),
+        SignerStorage::Persistent,
+        SignerOptions::default(),
+    ));
+    client.mock_all_auths().add_signer(&limited.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![
+            &env,
+            (
+                token.clone(),
+                Some(vec![&env, SignerKey::Policy(policy.clone())])
+            )
+        ])),
+        SignerStorage::Persistent,
+    ));
+    client
+        .mock_all_auths()
+        .suspend_signer(&SignerKey::Policy(policy.clone()));
+
+    let payload = payload(&env, 4);
+    let check = || {
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (limited.signer_key(&env), limited.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &vec![&env, transfer_context(&env, &token, &wallet, 1)],
+        )
+    };
+
+    assert_eq!(check(), Err(Ok(Error::MissingContext)));
+    let count: Option<u32> = env.as_contract(&policy, || {
+        env.storage()
+            .instance()
+            .get(&soroban_sdk::symbol_short!("count"))
+    });
+    assert_eq!(count, None);
+
+    client
+        .mock_all_auths()
+        .resume_signer(&SignerKey::Policy(policy.clone()));
+    assert_eq!(check(), Ok(()));
+}
+
+/// Suspending a policy runs no policy code and keeps its install-state.
+#[test]
+fn suspending_policy_keeps_it_installed() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let policy = env.register(LifecyclePolicy, ());
+
+    client.mock_all_auths().add_signer(&Signer::Policy(
+        policy.clone(),
+        SignerExpiration(None),
+        SignerLimits(Some(map![&env])),
+        SignerStorage::Persistent,
+        SignerOptions::default(),
+    ));
+    client
+        .mock_all_auths()
+        .suspend_signer(&SignerKey::Policy(policy.clone()));
+
+    let installed: Option<bool> = env.as_contract(&policy, || {
+        env.storage()
+            .persistent()
+            .get(&crate::tests::test_admin::LifecycleKey::Installed(wallet))
+    });
+    assert_eq!(installed, Some(true));
+}
+
+fn has_suspended_entry(env: &Env, wallet: &Address, key: &SignerKey, persistent: bool) -> bool {
+    let suspended_key = StorageKey::Suspended(key.clone());
+
+    env.as_contract(wallet, || {
+        if persistent {
+            env.storage().persistent().has(&suspended_key)
+        } else {
+            env.storage().temporary().has(&suspended_key)
+        }
+    })
+}
+
+/// An update never resumes a signer: the flag follows it across durability
+/// moves. Removal clears it, so a re-added signer starts active.
+#[test]
+fn suspension_survives_update_cleared_by_removal() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let key = device.signer_key(&env);
+    let plain = |storage: SignerStorage| {
+        device.signer(&env, SignerExpiration(None), SignerLimits(None), storage)
+    };
+
+    client
+        .mock_all_auths()
+        .add_signer(&plain(SignerStorage::Persistent));
+    client.mock_all_auths().suspend_signer(&key);
+    assert!(has_suspended_entry(&env, &wallet, &key, true));
+
+    client
+        .mock_all_auths()
+        .update_signer(&plain(SignerStorage::Temporary));
+    assert_eq!(client.is_signer_suspended(&key), Some(true));
+    assert!(!has_suspended_entry(&env, &wallet, &key, true));
+    assert!(has_suspended_entry(&env, &wallet, &key, false));
+    assert_eq!(
+        check(&env, &wallet, &[&device]),
+        Err(Ok(Error::MissingContext))
+    );
+
+    client.mock_all_auths().remove_signer(&key);
+    assert!(!has_suspended_entry(&env, &wallet, &key, false));
+
+    client
+        .mock_all_auths()
+        .add_signer(&plain(SignerStorage::Persistent));
+    assert_eq!(client.is_signer_suspended(&key), Some(false));
+    assert_eq!(check(&env, &wallet, &[&device]), Ok(()));
+}
@@ -0,0 +1,163 @@
+#![cfg(test)]
+//! `TtlConfig`: per-trigger (auth / mutation) and per-class (instance /
+//! persistent / temporary) extension rules, clamping to `max_ttl`, and the
+//! `set_ttl_config` entrypoint.
+
+extern crate std;
+
+use smart_wallet_interface::types::{
+    Error, Signatures, SignerExpiration, SignerLimits, SignerStorage, TtlConfig, TtlRule,
+    TtlSchedule,
+};
+use soroban_sdk::{
+    map,
+    testutils::{
+        storage::{Persistent as _, Temporary as _},
+        Address as _, Ledger as _,
+    },
+    vec, Address, Env, IntoVal,
+};
+
+use crate::storage::StorageKey;
+use crate::tests::test_common::*;
+
+fn schedule(threshold: u32, extend_to: u32) -> TtlSchedule {
+    let rule = TtlRule {
+        threshold,
+        extend_to,
+    };
+
+    TtlSchedule {
+        instance: rule.clone(),
+        persistent: rule.clone(),
+        temporary: rule,
+    }
+}
+
+fn setup<'a>(env: &Env) -> (Ed25519Signer, Address, crate::ContractClient<'a>) {
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(
+        env,
+        &owner.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+
+    (owner, wallet, client)
+}
+
+fn check(
+    env: &Env,
+    wallet: &Address,
+    signer: &Ed25519Signer,
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let payload = payload(env, 8);
+
+    env.try_invoke_contract_check_auth::<Error>(
+        wallet,
+        &payload,
+        Signatures(map![
+            env,
+            (signer.signer_key(env), signer.sign(env, &payload))
+        ])
+        .into_val(env),
+        &vec![
+            env,
+            transfer_context(env, &Address::generate(env), wallet, 1),
+        ],
+    )
+}
+
+/// Mutations and authorizations extend on their own schedules, and a
+/// signer's companions follow its rule.
+#[test]
+fn ttl_config_applies_per_trigger() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let session = Ed25519Signer::new(2);
+    let key = session.signer_key(&env);
+
+    client.mock_all_auths().set_ttl_config(&Some(TtlConfig {
+        auth: schedule(50_000, 100_000),
+        mutation: schedule(20_000, 30_000),
+    }));
+
+    client.mock_all_auths().add_signer(&session.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Temporary,
+    ));
+    let ttl = || env.as_contract(&wallet, || env.storage().temporary().get_ttl(&key));
+    assert_eq!(ttl(), 30_000);
+
+    assert_eq!(check(&env, &wallet, &session), Ok(()));
+    assert_eq!(ttl(), 100_000);
+    let activity_ttl = env.as_contract(&wallet, || {
+        env.storage()
+            .temporary()
+            .get_ttl(&StorageKey::SignerActivity(key.clone()))
+    });
+    assert_eq!(activity_ttl, 100_000);
+
+    // Above the threshold: left alone.
+    env.ledger().with_mut(|l| l.sequence_number += 10);
+    assert_eq!(check(&env, &wallet, &session), Ok(()));
+    assert_eq!(ttl(), 100_000 - 10);
+}
+
+/// Targets are clamped to `max_ttl`; a threshold at the target extends on
+/// every use, which the default (one week below max) does not.
+#[test]
+fn ttl_config_clamps_to_max_ttl() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let key = owner.signer_key(&env);
+    let max_ttl = env.as_contract(&wallet, || env.storage().max_ttl());
+    let ttl = || env.as_contract(&wallet, || env.storage().persistent().get_ttl(&key));
+
+    env.ledger().with_mut(|l| l.sequence_number += 10);
+    assert_eq!(check(&env, &wallet, &owner), Ok(()));
+    assert_eq!(ttl(), max_ttl - 10);
+
+    client.mock_all_auths().set_ttl_config(&Some(TtlConfig {
+        auth: schedule(u32::MAX, u32::MAX),
+        mutation: schedule(u32::MAX, u32::MAX),
+    }));
+    assert_eq!(check(&env, &wallet, &owner), Ok(()));
+    assert_eq!(ttl(), max_ttl);
+}
+
+#[test]
+fn set_ttl_config_auth_validation_and_reset() {
+    let env = test_env();
+    let (_, _, client) = setup(&env);
+    let config = TtlConfig {
+        auth: schedule(1_000, 2_000),
+        mutation: schedule(1_000, 2_000),
+    };
+
+    assert!(client.try_set_ttl_config(&Some(config.clone())).is_err());
+    assert_eq!(client.get_ttl_config(), None);
+
+    let mut invalid = config.clone();
+    invalid.mutation.temporary = TtlRule {
+        threshold: 2_001,
+        extend_to: 2_000,
+    };
+    assert_eq!(
+        client.mock_all_auths().try_set_ttl_config(&Some(invalid)),
+        Err(Ok(Error::InvalidTtlConfig))
+    );
+
+    client
+        .mock_all_auths()
+        .set_ttl_config(&Some(config.clone()));
+    assert_eq!(client.get_ttl_config(), Some(config));
+
+    client.mock_all_auths().set_ttl_config(&None);
+    assert_eq!(client.get_ttl_config(), None);
+}
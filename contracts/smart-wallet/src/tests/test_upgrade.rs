@@ -0,0 +1,371 @@
+#![cfg(test)]
+//! Staged upgrades: `confirm_upgrade` and its self-test, `rollback_upgrade`
+//! and the upgrade guardian.
+
+extern crate std;
+
+use smart_wallet_interface::{
+    events::{UpgradeConfirmed, UpgradeRolledBack},
+    types::{
+        Error, PendingUpgrade, Signatures, SignerExpiration, SignerLimits, SignerStorage,
+        UPGRADE_CONFIRM_WINDOW,
+    },
+};
+use soroban_sdk::{
+    map, symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec,
+    xdr::{
+        InvokeContractArgs, SorobanAddressCredentials, SorobanAuthorizationEntry,
+        SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials, VecM,
+    },
+    Address, BytesN, Env, Event as _,
+};
+
+use crate::tests::test_common::*;
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    Ed25519Signer,
+    Address,
+    crate::ContractClient<'a>,
+    BytesN<32>,
+) {
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(
+        env,
+        &owner.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    );
+    let wasm_hash = upload_wallet_wasm(env);
+
+    (owner, wallet, client, wasm_hash)
+}
+
+/// An upgrade stays pending until the new code's self-test passes, and the
+/// last confirmed hash becomes the next upgrade's rollback target.
+#[test]
+fn staged_upgrade_confirm_lifecycle() {
+    let env = test_env();
+    let (_, wallet, client, wasm_hash) = setup(&env);
+    let genesis = genesis_hash(&wallet);
+    let deadline = env.ledger().timestamp() + UPGRADE_CONFIRM_WINDOW;
+
+    assert_eq!(
+        client.mock_all_auths().try_confirm_upgrade(),
+        Err(Ok(Error::UpgradeNotPending))
+    );
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    assert_eq!(
+        client.get_pending_upgrade(),
+        Some(PendingUpgrade {
+            hash: wasm_hash.clone(),
+            rollback_hash: Some(genesis),
+            deadline,
+            schema_version: 1,
+        })
+    );
+
+    // Storage behind the code's schema: migrate before confirming.
+    env.as_contract(&wallet, || {
+        env.storage().instance().remove(&symbol_short!("schema"))
+    });
+    assert_eq!(
+        client.mock_all_auths().try_confirm_upgrade(),
+        Err(Ok(Error::UpgradeSelfTestFailed))
+    );
+    client.mock_all_auths().migrate();
+
+    client.mock_all_auths().confirm_upgrade();
+
+    let expected = UpgradeConfirmed {
+        hash: wasm_hash.clone(),
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+    assert_eq!(client.get_pending_upgrade(), None);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    client.mock_all_auths().upgrade(&wasm_hash);
+    assert_eq!(
+        client.get_pending_upgrade(),
+        Some(PendingUpgrade {
+            hash: wasm_hash.clone(),
+            rollback_hash: Some(wasm_hash),
+            deadline: deadline + 1,
+            schema_version: 1,
+        })
+    );
+}
+
+/// `confirm_upgrade` takes no signer to look up: the wallet's own auth is
+/// the self-test, so only an entry a real signer signed confirms it.
+#[test]
+fn confirm_upgrade_requires_a_real_signature() {
+    let env = test_env();
+    let (owner, wallet, client, wasm_hash) = setup(&env);
+    let stranger = Ed25519Signer::new(2);
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+
+    let root_invocation = SorobanAuthorizedInvocation {
+        function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+            contract_address: wallet.clone().into(),
+            function_name: "confirm_upgrade".try_into().unwrap(),
+            args: VecM::default(),
+        }),
+        sub_invocations: VecM::default(),
+    };
+    let signature_expiration_ledger = env.ledger().sequence();
+    let entry = |signer: &Ed25519Signer, nonce: i64| {
+        let payload = auth_payload(&env, nonce, signature_expiration_ledger, &root_invocation);
+
+        SorobanAuthorizationEntry {
+            credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+                address: wallet.clone().into(),
+                nonce,
+                signature_expiration_ledger,
+                signature: Signatures(map![
+                    &env,
+                    (signer.signer_key(&env), signer.sign(&env, &payload))
+                ])
+                .try_into()
+                .unwrap(),
+            }),
+            root_invocation: root_invocation.clone(),
+        }
+    };
+
+    assert!(client.try_confirm_upgrade().is_err());
+    assert!(client
+        .set_auths(&[entry(&stranger, 1)])
+        .try_confirm_upgrade()
+        .is_err());
+    assert!(client.get_pending_upgrade().is_some());
+
+    client.set_auths(&[entry(&owner, 2)]).confirm_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+/// Past its deadline a pending upgrade can no longer be confirmed, only
+/// rolled back, while each upgrade before it restarts the window.
+#[test]
+fn pending_upgrade_expires() {
+    let env = test_env();
+    let (_, wallet, client, wasm_hash) = setup(&env);
+    let genesis = genesis_hash(&wallet);
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    let deadline = client.get_pending_upgrade().unwrap().deadline;
+
+    env.ledger().set_timestamp(deadline);
+    client.mock_all_auths().upgrade(&wasm_hash);
+    let deadline = client.get_pending_upgrade().unwrap().deadline;
+    assert_eq!(deadline, env.ledger().timestamp() + UPGRADE_CONFIRM_WINDOW);
+
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        client.mock_all_auths().try_confirm_upgrade(),
+        Err(Ok(Error::UpgradeExpired))
+    );
+
+    client.mock_all_auths().rollback_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+    assert_eq!(genesis_hash(&wallet), genesis);
+}
+
+/// Upgrading an expired upgrade again gives the new code a full window to be
+/// confirmed in, keeping the original rollback target.
+#[test]
+fn expired_upgrade_upgraded_again() {
+    let env = test_env();
+    let (_, wallet, client, wasm_hash) = setup(&env);
+    let genesis = genesis_hash(&wallet);
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    let deadline = client.get_pending_upgrade().unwrap().deadline;
+    env.ledger().set_timestamp(deadline + 1);
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    assert_eq!(
+        client.get_pending_upgrade(),
+        Some(PendingUpgrade {
+            hash: wasm_hash.clone(),
+            rollback_hash: Some(genesis),
+            deadline: deadline + 1 + UPGRADE_CONFIRM_WINDOW,
+            schema_version: 1,
+        })
+    );
+
+    client.mock_all_auths().confirm_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+/// An expired upgrade with nothing to roll back to is not stuck: upgrading
+/// again restarts the window, and the confirm clears it.
+#[test]
+fn expired_upgrade_without_rollback_target() {
+    let env = test_env();
+    let (_, wallet, client, wasm_hash) = setup(&env);
+    env.as_contract(&wallet, || {
+        env.storage().instance().remove(&symbol_short!("wasm_hash"))
+    });
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    let deadline = client.get_pending_upgrade().unwrap().deadline;
+    env.ledger().set_timestamp(deadline + 1);
+
+    assert_eq!(
+        client.mock_all_auths().try_confirm_upgrade(),
+        Err(Ok(Error::UpgradeExpired))
+    );
+    assert_eq!(
+        client.mock_all_auths().try_rollback_upgrade(),
+        Err(Ok(Error::NoRollbackTarget))
+    );
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    client.mock_all_auths().confirm_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+/// Once `migrate` moves storage past the rollback target's schema version,
+/// the previous code could no longer read it, so the rollback is refused.
+#[test]
+fn rollback_upgrade_refused_after_migrate() {
+    let env = test_env();
+    let (_, wallet, client, wasm_hash) = setup(&env);
+    env.as_contract(&wallet, || {
+        env.storage().instance().remove(&symbol_short!("schema"))
+    });
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    assert_eq!(client.get_pending_upgrade().unwrap().schema_version, 0);
+
+    client.mock_all_auths().migrate();
+    assert_eq!(
+        client.mock_all_auths().try_rollback_upgrade(),
+        Err(Ok(Error::RollbackAfterMigration))
+    );
+
+    client.mock_all_auths().confirm_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+/// With a guardian set, the guardian — not the wallet — authorizes the
+/// rollback, so a broken `__check_auth` cannot block it.
+#[test]
+fn rollback_upgrade_by_guardian() {
+    let env = test_env();
+    let (_, wallet, client, wasm_hash) = setup(&env);
+    let guardian = Address::generate(&env);
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    client.mock_all_auths().confirm_upgrade();
+    client
+        .mock_all_auths()
+        .set_upgrade_guardian(&Some(guardian.clone()));
+    assert_eq!(client.get_upgrade_guardian(), Some(guardian.clone()));
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    client.mock_all_auths().rollback_upgrade();
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, guardian);
+
+    let expected = UpgradeRolledBack {
+        from_hash: wasm_hash.clone(),
+        to_hash: wasm_hash,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (wallet.clone(), expected.topics(&env), expected.data(&env))
+        ]
+    );
+    assert_eq!(client.get_pending_upgrade(), None);
+
+    assert_eq!(
+        client.mock_all_auths().try_rollback_upgrade(),
+        Err(Ok(Error::UpgradeNotPending))
+    );
+}
+
+/// The guardian's right ends with the confirm window: past the deadline the
+/// wallet itself authorizes the rollback.
+#[test]
+fn rollback_upgrade_guardian_expires() {
+    let env = test_env();
+    let (_, wallet, client, wasm_hash) = setup(&env);
+    let guardian = Address::generate(&env);
+    client
+        .mock_all_auths()
+        .set_upgrade_guardian(&Some(guardian));
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    let deadline = client.get_pending_upgrade().unwrap().deadline;
+    env.ledger().set_timestamp(deadline + 1);
+
+    client.mock_all_auths().rollback_upgrade();
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, wallet);
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+/// While the wallet is frozen nobody rolls back, not even the guardian
+/// whose auth skips the frozen gate of `__check_auth`.
+#[test]
+fn rollback_upgrade_refused_while_frozen() {
+    let env = test_env();
+    let (_, _, client, wasm_hash) = setup(&env);
+    let guardian = Address::generate(&env);
+    client
+        .mock_all_auths()
+        .set_upgrade_guardian(&Some(guardian));
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+    client.mock_all_auths().freeze();
+    assert_eq!(
+        client.mock_all_auths().try_rollback_upgrade(),
+        Err(Ok(Error::WalletFrozen))
+    );
+
+    client.mock_all_auths().unfreeze();
+    client.mock_all_auths().rollback_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+/// Without a guardian the wallet authorizes the rollback, and the first
+/// upgrade of a wallet that never cached its genesis hash (deployed by older
+/// code) has no hash to roll back to.
+#[test]
+fn rollback_upgrade_requires_auth_and_target() {
+    let env = test_env();
+    let (_, wallet, client, wasm_hash) = setup(&env);
+    env.as_contract(&wallet, || {
+        env.storage().instance().remove(&symbol_short!("wasm_hash"))
+    });
+
+    client.mock_all_auths().upgrade(&wasm_hash);
+
+    assert!(client.try_rollback_upgrade().is_err());
+    assert_eq!(
+        client.mock_all_auths().try_rollback_upgrade(),
+        Err(Ok(Error::NoRollbackTarget))
+    );
+}
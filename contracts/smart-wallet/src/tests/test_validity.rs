@@ -0,0 +1,320 @@
+#![cfg(test)]
+//! `SignerOptions::valid_after` (not-before): pass-1 skipping, pass-2
+//! rejection, the inclusive boundary, required-policy checks, the options
+//! companion entry's lifecycle, and counter semantics for delayed signers.
+
+extern crate std;
+
+use smart_wallet_interface::types::{
+    Error, Signatures, Signer, SignerExpiration, SignerKey, SignerLimits, SignerOptions,
+    SignerStorage,
+};
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env, IntoVal,
+};
+
+use crate::storage::StorageKey;
+use crate::tests::test_auth::CountingPolicy;
+use crate::tests::test_common::*;
+
+const NOW: u64 = 1_000_000;
+const DELAY: u64 = 48 * 60 * 60;
+
+fn delayed(valid_after: u64) -> SignerOptions {
+    SignerOptions {
+        valid_after: Some(valid_after),
+        ..Default::default()
+    }
+}
+
+fn admin(env: &Env, signer: &Ed25519Signer) -> Signer {
+    signer.signer(
+        env,
+        SignerExpiration(None),
+        SignerLimits(None),
+        SignerStorage::Persistent,
+    )
+}
+
+fn setup<'a>(env: &Env) -> (Ed25519Signer, Address, crate::ContractClient<'a>) {
+    env.ledger().set_timestamp(NOW);
+
+    let owner = Ed25519Signer::new(1);
+    let (wallet, client) = register_wallet(env, &admin(env, &owner));
+
+    (owner, wallet, client)
+}
+
+fn check(
+    env: &Env,
+    wallet: &Address,
+    signers: &[&Ed25519Signer],
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let payload = payload(env, 7);
+    let mut signatures = map![env];
+    for signer in signers {
+        signatures.set(signer.signer_key(env), signer.sign(env, &payload));
+    }
+
+    env.try_invoke_contract_check_auth::<Error>(
+        wallet,
+        &payload,
+        Signatures(signatures).into_val(env),
+        &vec![
+            env,
+            transfer_context(env, &Address::generate(env), wallet, 1),
+        ],
+    )
+}
+
+/// The security-delay flow: a delayed admin covers nothing before its start
+/// time, and is rejected even as an extra entry; from `valid_after` on
+/// (inclusive) it is a normal signer.
+#[test]
+fn delayed_signer_unusable_until_valid_after() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let delayed_admin = Ed25519Signer::new(2);
+
+    client.mock_all_auths().add_signer(&with_options(
+        admin(&env, &delayed_admin),
+        delayed(NOW + DELAY),
+    ));
+
+    // Alone: skipped as a candidate, so nothing covers the context.
+    assert_eq!(
+        check(&env, &wallet, &[&delayed_admin]),
+        Err(Ok(Error::MissingContext))
+    );
+    // Next to a covering signer: pass 2 rejects the not-yet-valid entry.
+    assert_eq!(
+        check(&env, &wallet, &[&owner, &delayed_admin]),
+        Err(Ok(Error::SignerNotYetValid))
+    );
+
+    env.ledger().set_timestamp(NOW + DELAY - 1);
+    assert_eq!(
+        check(&env, &wallet, &[&delayed_admin]),
+        Err(Ok(Error::MissingContext))
+    );
+
+    // Inclusive start.
+    env.ledger().set_timestamp(NOW + DELAY);
+    assert_eq!(check(&env, &wallet, &[&delayed_admin]), Ok(()));
+    assert_eq!(check(&env, &wallet, &[&owner, &delayed_admin]), Ok(()));
+}
+
+/// A not-yet-valid signer cannot even remove itself; the owner can remove it
+/// at any time during the delay.
+#[test]
+fn delayed_signer_cannot_self_remove_owner_can_remove_it() {
+    let env = test_env();
+    let (owner, wallet, client) = setup(&env);
+    let delayed_signer = Ed25519Signer::new(2);
+
+    client.mock_all_auths().add_signer(&with_options(
+        delayed_signer.signer(
+            &env,
+            SignerExpiration(None),
+            SignerLimits(Some(map![&env])),
+            SignerStorage::Persistent,
+        ),
+        delayed(NOW + DELAY),
+    ));
+
+    let payload = payload(&env, 3);
+    let removal = vec![
+        &env,
+        remove_signer_context(&env, &wallet, &delayed_signer.signer_key(&env)),
+    ];
+
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (
+                    delayed_signer.signer_key(&env),
+                    delayed_signer.sign(&env, &payload)
+                )
+            ])
+            .into_val(&env),
+            &removal,
+        ),
+        Err(Ok(Error::MissingContext))
+    );
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (owner.signer_key(&env), owner.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &removal,
+        ),
+        Ok(())
+    );
+
+    client
+        .mock_all_auths()
+        .remove_signer(&delayed_signer.signer_key(&env));
+    assert_eq!(client.get_signer(&delayed_signer.signer_key(&env)), None);
+}
+
+/// A stored required policy that is not yet valid is treated like an expired
+/// one: the candidate is rejected without the policy being consulted.
+#[test]
+fn not_yet_valid_required_policy_rejects_candidate() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let policy = env.register(CountingPolicy, ());
+    let token = Address::generate(&env);
+    let limited = Ed25519Signer::new(2);
+
+    client.mock_all_auths().add_signer(&with_options(
+        Signer::Policy(
+            policy.clone(),
+            SignerExpiration(None),
+            SignerLimits(Some(map![&env])),
+            SignerStorage::Persistent,
+            SignerOptions::default(),
+        ),
+        delayed(NOW + DELAY),
+    ));
+    client.mock_all_auths().add_signer(&limited.signer(
+        &env,
+        SignerExpiration(None),
+        SignerLimits(Some(map![
+            &env,
+            (
+                token.clone(),
+                Some(vec![&env, SignerKey::Policy(policy.clone())])
+            )
+        ])),
+        SignerStorage::Persistent,
+    ));
+
+    let payload = payload(&env, 4);
+    let check = || {
+        env.try_invoke_contract_check_auth::<Error>(
+            &wallet,
+            &payload,
+            Signatures(map![
+                &env,
+                (limited.signer_key(&env), limited.sign(&env, &payload))
+            ])
+            .into_val(&env),
+            &vec![&env, transfer_context(&env, &token, &wallet, 1)],
+        )
+    };
+
+    assert_eq!(check(), Err(Ok(Error::MissingContext)));
+    let count: Option<u32> = env.as_contract(&policy, || {
+        env.storage()
+            .instance()
+            .get(&soroban_sdk::symbol_short!("count"))
+    });
+    assert_eq!(count, None);
+
+    env.ledger().set_timestamp(NOW + DELAY);
+    assert_eq!(check(), Ok(()));
+}
+
+/// A delayed durable admin counts toward the guards: it becomes usable by
+/// time alone, so the wallet is never left permanently without an admin.
+#[test]
+fn delayed_durable_admin_is_counted() {
+    let env = test_env();
+    let (owner, _, client) = setup(&env);
+    let delayed_admin = Ed25519Signer::new(2);
+
+    client.mock_all_auths().add_signer(&with_options(
+        admin(&env, &delayed_admin),
+        delayed(NOW + DELAY),
+    ));
+
+    client
+        .mock_all_auths()
+        .remove_signer(&owner.signer_key(&env));
+    assert_eq!(
+        client
+            .mock_all_auths()
+            .try_remove_signer(&delayed_admin.signer_key(&env)),
+        Err(Ok(Error::LastAdminSigner))
+    );
+}
+
+fn has_options_entry(env: &Env, wallet: &Address, key: &SignerKey, persistent: bool) -> bool {
+    let options_key = StorageKey::SignerOptions(key.clone());
+
+    env.as_contract(wallet, || {
+        if persistent {
+            env.storage().persistent().has(&options_key)
+        } else {
+            env.storage().temporary().has(&options_key)
+        }
+    })
+}
+
+/// The options companion is stored only when non-default, follows the
+/// signer across durability moves, and is removed with it.
+#[test]
+fn options_entry_lifecycle() {
+    let env = test_env();
+    let (_, wallet, client) = setup(&env);
+    let signer = Ed25519Signer::new(2);
+    let key = signer.signer_key(&env);
+    let plain = |storage: SignerStorage| {
+        signer.signer(&env, SignerExpiration(None), SignerLimits(None), storage)
+    };
+
+    assert_eq!(client.get_signer_options(&key), None);
+
+    client
+        .mock_all_auths()
+        .add_signer(&plain(SignerStorage::Persistent));
+    assert_eq!(
+        client.get_signer_options(&key),
+        Some(SignerOptions::default())
+    );
+    assert!(!has_options_entry(&env, &wallet, &key, true));
+
+    client.mock_all_auths().update_signer(&with_options(
+        plain(SignerStorage::Persistent),
+        delayed(NOW + DELAY),
+    ));
+    assert_eq!(client.get_signer_options(&key), Some(delayed(NOW + DELAY)));
+    assert!(has_options_entry(&env, &wallet, &key, true));
+
+    // Durability flip: the companion moves with the signer.
+    client.mock_all_auths().update_signer(&with_options(
+        plain(SignerStorage::Temporary),
+        delayed(NOW + DELAY),
+    ));
+    assert_eq!(client.get_signer_options(&key), Some(delayed(NOW + DELAY)));
+    assert!(!has_options_entry(&env, &wallet, &key, true));
+    assert!(has_options_entry(&env, &wallet, &key, false));
+
+    // Updating back to defaults drops the companion: the delay is lifted.
+    client
+        .mock_all_auths()
+        .update_signer(&plain(SignerStorage::Temporary));
+    assert_eq!(
+        client.get_signer_options(&key),
+        Some(SignerOptions::default())
+    );
+    assert!(!has_options_entry(&env, &wallet, &key, false));
+
+    client.mock_all_auths().update_signer(&with_options(
+        plain(SignerStorage::Temporary),
+        delayed(NOW + DELAY),
+    ));
+    client.mock_all_auths().remove_signer(&key);
+    assert_eq!(client.get_signer_options(&key), None);
+    assert!(!has_options_entry(&env, &wallet, &key, false));
+}
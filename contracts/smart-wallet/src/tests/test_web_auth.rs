@@ -0,0 +1,287 @@
+#![cfg(test)]
+//! SEP-45 web authentication: the `can_web_auth` permission covering a
+//! `web_auth_verify` login that names the wallet on a trusted web-auth
+//! contract for its home domain, and nothing else.
+
+extern crate std;
+
+use example_contract::{WebAuthContract, WebAuthContractClient};
+use smart_wallet_interface::types::{
+    Error, Signatures, Signer, SignerExpiration, SignerLimits, SignerOptions, SignerStorage,
+    WebAuthConfig,
+};
+use soroban_sdk::{
+    auth::Context,
+    map,
+    testutils::Address as _,
+    vec,
+    xdr::{
+        InvokeContractArgs, ScVal, SorobanAddressCredentials, SorobanAuthorizationEntry,
+        SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials, VecM,
+    },
+    Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
+};
+
+use crate::tests::test_common::*;
+
+/// A device key with an empty limits map: it authorizes nothing but its own
+/// removal, plus logins when `can_web_auth` is set.
+fn device_key(env: &Env, signer: &Ed25519Signer, can_web_auth: bool) -> Signer {
+    with_options(
+        signer.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(Some(map![env])),
+            SignerStorage::Persistent,
+        ),
+        SignerOptions {
+            can_web_auth,
+            ..Default::default()
+        },
+    )
+}
+
+fn setup<'a>(env: &Env) -> (Address, crate::ContractClient<'a>) {
+    let owner = Ed25519Signer::new(1);
+
+    register_wallet(
+        env,
+        &owner.signer(
+            env,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+        ),
+    )
+}
+
+/// Trust `web_auth` as the web-auth contract of `anchor.example.com`.
+fn trust(env: &Env, client: &crate::ContractClient, web_auth: &Address) {
+    client
+        .mock_all_auths()
+        .set_web_auth_config(&Some(WebAuthConfig {
+            contracts: map![
+                env,
+                (
+                    web_auth.clone(),
+                    String::from_str(env, "anchor.example.com")
+                )
+            ],
+        }));
+}
+
+/// SEP-45 `web_auth_verify` arguments naming `account`.
+fn web_auth_args(env: &Env, account: &Address) -> Map<Symbol, String> {
+    map![
+        env,
+        (Symbol::new(env, "account"), account.to_string()),
+        (
+            Symbol::new(env, "home_domain"),
+            String::from_str(env, "anchor.example.com")
+        ),
+        (
+            Symbol::new(env, "web_auth_domain"),
+            String::from_str(env, "auth.anchor.example.com")
+        ),
+        (Symbol::new(env, "nonce"), String::from_str(env, "42")),
+    ]
+}
+
+fn check(
+    env: &Env,
+    wallet: &Address,
+    signer: &Ed25519Signer,
+    context: Context,
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    let payload = payload(env, 9);
+
+    env.try_invoke_contract_check_auth::<Error>(
+        wallet,
+        &payload,
+        Signatures(map![
+            env,
+            (signer.signer_key(env), signer.sign(env, &payload))
+        ])
+        .into_val(env),
+        &vec![env, context],
+    )
+}
+
+/// A login through the host: a `can_web_auth` device key authorizes the
+/// wallet's `web_auth_verify` entry, one without the permission cannot.
+#[test]
+fn web_auth_signer_logs_in() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    let plain = Ed25519Signer::new(3);
+    client
+        .mock_all_auths()
+        .add_signer(&device_key(&env, &device, true));
+    client
+        .mock_all_auths()
+        .add_signer(&device_key(&env, &plain, false));
+    let web_auth = env.register(WebAuthContract, ());
+    let web_auth_client = WebAuthContractClient::new(&env, &web_auth);
+    trust(&env, &client, &web_auth);
+    let args = web_auth_args(&env, &wallet);
+
+    let args_val: Val = args.into_val(&env);
+    let root_invocation = SorobanAuthorizedInvocation {
+        function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+            contract_address: web_auth.clone().into(),
+            function_name: "web_auth_verify".try_into().unwrap(),
+            args: std::vec![ScVal::try_from_val(&env, &args_val).unwrap()]
+                .try_into()
+                .unwrap(),
+        }),
+        sub_invocations: VecM::default(),
+    };
+    let signature_expiration_ledger = env.ledger().sequence();
+    let entry = |signer: &Ed25519Signer, nonce: i64| {
+        let payload = auth_payload(&env, nonce, signature_expiration_ledger, &root_invocation);
+
+        SorobanAuthorizationEntry {
+            credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+                address: wallet.clone().into(),
+                nonce,
+                signature_expiration_ledger,
+                signature: Signatures(map![
+                    &env,
+                    (signer.signer_key(&env), signer.sign(&env, &payload))
+                ])
+                .try_into()
+                .unwrap(),
+            }),
+            root_invocation: root_invocation.clone(),
+        }
+    };
+
+    assert!(web_auth_client
+        .set_auths(&[entry(&plain, 1)])
+        .try_web_auth_verify(&args)
+        .is_err());
+
+    web_auth_client
+        .set_auths(&[entry(&device, 2)])
+        .web_auth_verify(&args);
+}
+
+/// The permission covers a login naming this wallet and nothing else: not a
+/// login for another account, not a malformed call, not a transfer.
+#[test]
+fn web_auth_permission_grants_nothing_else() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    client
+        .mock_all_auths()
+        .add_signer(&device_key(&env, &device, true));
+    let web_auth = Address::generate(&env);
+    let token = Address::generate(&env);
+    trust(&env, &client, &web_auth);
+
+    let login = |account: &Address| {
+        contract_context(
+            &env,
+            &web_auth,
+            "web_auth_verify",
+            vec![&env, web_auth_args(&env, account).into_val(&env)],
+        )
+    };
+
+    assert_eq!(check(&env, &wallet, &device, login(&wallet)), Ok(()));
+
+    assert_eq!(
+        check(&env, &wallet, &device, login(&Address::generate(&env))),
+        Err(Ok(Error::MissingContext))
+    );
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &device,
+            contract_context(
+                &env,
+                &web_auth,
+                "web_auth_verify",
+                vec![&env, 7i128.into_val(&env)],
+            )
+        ),
+        Err(Ok(Error::MissingContext))
+    );
+    assert_eq!(
+        check(
+            &env,
+            &wallet,
+            &device,
+            transfer_context(&env, &token, &wallet, 100)
+        ),
+        Err(Ok(Error::MissingContext))
+    );
+}
+
+/// Only a web-auth contract the wallet trusts qualifies, and only for the
+/// home domain it is listed with; a login without a `home_domain` argument
+/// is judged by the contract alone. Without a `WebAuthConfig` no login is
+/// covered.
+#[test]
+fn web_auth_requires_trusted_contract_and_domain() {
+    let env = test_env();
+    let (wallet, client) = setup(&env);
+    let device = Ed25519Signer::new(2);
+    client
+        .mock_all_auths()
+        .add_signer(&device_key(&env, &device, true));
+    let web_auth = Address::generate(&env);
+    let rogue = Address::generate(&env);
+
+    let login = |contract: &Address, args: Map<Symbol, String>| {
+        contract_context(
+            &env,
+            contract,
+            "web_auth_verify",
+            vec![&env, args.into_val(&env)],
+        )
+    };
+    let args = web_auth_args(&env, &wallet);
+
+    assert_eq!(
+        check(&env, &wallet, &device, login(&web_auth, args.clone())),
+        Err(Ok(Error::MissingContext))
+    );
+
+    trust(&env, &client, &web_auth);
+    assert_eq!(
+        check(&env, &wallet, &device, login(&web_auth, args.clone())),
+        Ok(())
+    );
+    assert_eq!(
+        check(&env, &wallet, &device, login(&rogue, args.clone())),
+        Err(Ok(Error::MissingContext))
+    );
+
+    let mut other_domain = args.clone();
+    other_domain.set(
+        Symbol::new(&env, "home_domain"),
+        String::from_str(&env, "evil.example.com"),
+    );
+    assert_eq!(
+        check(&env, &wallet, &device, login(&web_auth, other_domain)),
+        Err(Ok(Error::MissingContext))
+    );
+
+    let mut no_domain = args.clone();
+    no_domain.remove(Symbol::new(&env, "home_domain"));
+    assert_eq!(
+        check(&env, &wallet, &device, login(&web_auth, no_domain)),
+        Ok(())
+    );
+
+    client.mock_all_auths().set_web_auth_config(&None);
+    assert_eq!(client.get_web_auth_config(), None);
+    assert_eq!(
+        check(&env, &wallet, &device, login(&web_auth, args)),
+        Err(Ok(Error::MissingContext))
+    );
+}
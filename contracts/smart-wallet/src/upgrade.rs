@@ -0,0 +1,42 @@
+use smart_wallet_interface::types::PendingUpgrade;
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+/// Instance storage key of the upgrade awaiting `confirm_upgrade`. Present
+/// only while one is pending.
+const PENDING_UPGRADE: Symbol = symbol_short!("pend_upg");
+
+/// Instance storage key of the address that authorizes `rollback_upgrade`
+/// (`set_upgrade_guardian`). Absent = the wallet itself.
+const UPGRADE_GUARDIAN: Symbol = symbol_short!("upg_guard");
+
+pub fn get_pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+    env.storage()
+        .instance()
+        .get::<Symbol, PendingUpgrade>(&PENDING_UPGRADE)
+}
+
+pub fn set_pending_upgrade(env: &Env, pending: &Option<PendingUpgrade>) {
+    match pending {
+        Some(pending) => env
+            .storage()
+            .instance()
+            .set::<Symbol, PendingUpgrade>(&PENDING_UPGRADE, pending),
+        None => env.storage().instance().remove(&PENDING_UPGRADE),
+    }
+}
+
+pub fn get_upgrade_guardian(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get::<Symbol, Address>(&UPGRADE_GUARDIAN)
+}
+
+pub fn set_upgrade_guardian(env: &Env, guardian: &Option<Address>) {
+    match guardian {
+        Some(guardian) => env
+            .storage()
+            .instance()
+            .set::<Symbol, Address>(&UPGRADE_GUARDIAN, guardian),
+        None => env.storage().instance().remove(&UPGRADE_GUARDIAN),
+    }
+}
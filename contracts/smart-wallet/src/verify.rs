@@ -1,8 +1,13 @@
 use smart_wallet_interface::types::{Error, Secp256r1Signature};
-use soroban_sdk::{crypto::Hash, BytesN, Env};
+use soroban_sdk::{crypto::Hash, symbol_short, xdr::ToXdr, BytesN, Env, Symbol};
 
 use crate::base64_url;
 
+/// Domain tag of the `is_valid_signature` digest preimage. Like the intent
+/// digest's, the preimage is an `ScVal` vector, so a message signature can
+/// never be replayed as an authorization or an intent, and vice versa.
+const SIGNATURE_DOMAIN: Symbol = symbol_short!("sw_1271");
+
 /// WebAuthn authenticatorData minimum length: rpIdHash (32) + flags (1) +
 /// signCount (4).
 const AUTHENTICATOR_DATA_MIN_LEN: u32 = 37;
@@ -45,7 +50,7 @@ struct ClientDataJson<'a> {
 ///    spec (host panics on an invalid signature).
 pub fn verify_secp256r1_signature(
     env: &Env,
-    signature_payload: &Hash<32>,
+    signature_payload: &BytesN<32>,
     public_key: &BytesN<65>,
     signature: Secp256r1Signature,
 ) -> Result<(), Error> {
@@ -102,3 +107,17 @@ pub fn verify_secp256r1_signature(
 
     Ok(())
 }
+
+/// The digest signed for `is_valid_signature(hash)`: SHA-256 over the XDR of
+/// `(SIGNATURE_DOMAIN, network id, this wallet, hash)`.
+pub fn signature_hash(env: &Env, hash: &BytesN<32>) -> Hash<32> {
+    let preimage = (
+        SIGNATURE_DOMAIN,
+        env.ledger().network_id(),
+        env.current_contract_address(),
+        hash.clone(),
+    )
+        .to_xdr(env);
+
+    env.crypto().sha256(&preimage)
+}
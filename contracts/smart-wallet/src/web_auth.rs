@@ -0,0 +1,22 @@
+use smart_wallet_interface::types::WebAuthConfig;
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+/// Instance storage key of the wallet's `WebAuthConfig`. Absent = no
+/// web-auth contract is trusted.
+const WEB_AUTH_CONFIG: Symbol = symbol_short!("web_auth");
+
+pub fn get_web_auth_config(env: &Env) -> Option<WebAuthConfig> {
+    env.storage()
+        .instance()
+        .get::<Symbol, WebAuthConfig>(&WEB_AUTH_CONFIG)
+}
+
+pub fn set_web_auth_config(env: &Env, config: &Option<WebAuthConfig>) {
+    match config {
+        Some(config) => env
+            .storage()
+            .instance()
+            .set::<Symbol, WebAuthConfig>(&WEB_AUTH_CONFIG, config),
+        None => env.storage().instance().remove(&WEB_AUTH_CONFIG),
+    }
+}
@@ -0,0 +1,165 @@
+//! Wallet factory: deploys smart wallets from one pinned wasm hash at
+//! addresses bound to their passkey.
+//!
+//! Wallets deployed through the shared deployer account are exposed to
+//! front-running: the deployer is public and the wasm is not part of the
+//! address preimage, so anyone who learns a `keyId` first can deploy other
+//! code at the derived address. The factory closes that gap:
+//!
+//! - **Pinned code.** The wasm hash is fixed in `__constructor` and the
+//!   factory has no admin and no upgrade, so every wallet it deploys runs
+//!   exactly that wasm.
+//! - **Bound salt.** The salt is the SHA-256 of the XDR of
+//!   `(key_id, public_key, settings)`, and the factory itself builds the
+//!   wallet's first signer from the passkey (an unlimited, persistent,
+//!   non-expiring Secp256r1 admin), so a given address can only ever hold a
+//!   wallet whose first signer is that passkey, configured with exactly those
+//!   settings. `deploy` is permissionless: whoever submits it first can only
+//!   deploy the wallet the owner asked for.
+//!
+//! The address is `(factory, salt)`, so a client that knows the credential
+//! id, the public key and the settings (`None` for none) derives it
+//! off-chain, or reads it from `get_wallet_address`, before the wallet
+//! exists. The same passkey with other settings is another wallet.
+
+#![no_std]
+
+use smart_wallet_interface::types::{
+    Signer, SignerExpiration, SignerLimits, SignerOptions, SignerStorage, WalletSettings,
+};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, symbol_short, vec, xdr::ToXdr, Address,
+    Bytes, BytesN, Env, Symbol,
+};
+
+/// Instance storage key holding the pinned wallet wasm hash.
+const WASM_HASH: Symbol = symbol_short!("wasm_hash");
+
+/// TTL renewal parameters (in ledgers at the historical 5s close time): bump
+/// to ~30 days whenever remaining TTL drops below ~1 week. Both are well under
+/// any real network's `max_ttl`. Every entrypoint renews, the getters too, but
+/// a getter only renews when submitted in a transaction: simulated reads do
+/// not. A factory nobody deploys through or submits a read to for 30 days
+/// archives and must be restored before its next use.
+const RENEW_THRESHOLD: u32 = 60 * 60 * 24 / 5 * 7;
+const RENEW_TO: u32 = 60 * 60 * 24 / 5 * 30;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum FactoryError {
+    /// The factory has no pinned wasm hash: its instance was not created by
+    /// `__constructor`.
+    NotInitialized = 1,
+}
+
+/// A wallet was deployed by `deploy`. The wallet itself announces its first
+/// signer with `SignerAdded`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WalletDeployed {
+    #[topic]
+    pub wallet: Address,
+    pub key_id: Bytes,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    /// Pin the wallet wasm every deploy uses. It can never change.
+    pub fn __constructor(env: Env, wasm_hash: BytesN<32>) {
+        env.storage()
+            .instance()
+            .set::<Symbol, BytesN<32>>(&WASM_HASH, &wasm_hash);
+    }
+
+    /// Deploy a wallet whose only signer is the passkey `(key_id,
+    /// public_key)`, as an unlimited persistent admin, configured with
+    /// `settings`, at the address derived from all three. Permissionless.
+    /// Fails if that wallet was already deployed, or as the wallet's
+    /// `__constructor` does (e.g. a public key that is not a P-256 point).
+    pub fn deploy(
+        env: Env,
+        key_id: Bytes,
+        public_key: Bytes,
+        settings: Option<WalletSettings>,
+    ) -> Result<Address, FactoryError> {
+        let wasm_hash = get_wasm_hash(&env)?;
+        let salt = salt(&env, &key_id, &public_key, &settings);
+        let signer = Signer::Secp256r1(
+            key_id.clone(),
+            public_key,
+            SignerExpiration(None),
+            SignerLimits(None),
+            SignerStorage::Persistent,
+            SignerOptions::default(),
+        );
+
+        let wallet = env
+            .deployer()
+            .with_current_contract(salt)
+            .deploy_v2(wasm_hash, (vec![&env, signer], settings));
+
+        renew_instance(&env);
+
+        WalletDeployed {
+            wallet: wallet.clone(),
+            key_id,
+        }
+        .publish(&env);
+
+        Ok(wallet)
+    }
+
+    /// The address `deploy` uses for `key_id`, `public_key` and `settings`,
+    /// whether or not the wallet exists yet.
+    pub fn get_wallet_address(
+        env: Env,
+        key_id: Bytes,
+        public_key: Bytes,
+        settings: Option<WalletSettings>,
+    ) -> Address {
+        let salt = salt(&env, &key_id, &public_key, &settings);
+
+        renew_instance(&env);
+
+        env.deployer()
+            .with_current_contract(salt)
+            .deployed_address()
+    }
+
+    /// The pinned wallet wasm hash.
+    pub fn get_wasm_hash(env: Env) -> Result<BytesN<32>, FactoryError> {
+        let wasm_hash = get_wasm_hash(&env)?;
+
+        renew_instance(&env);
+
+        Ok(wasm_hash)
+    }
+}
+
+fn get_wasm_hash(env: &Env) -> Result<BytesN<32>, FactoryError> {
+    env.storage()
+        .instance()
+        .get::<Symbol, BytesN<32>>(&WASM_HASH)
+        .ok_or(FactoryError::NotInitialized)
+}
+
+fn salt(
+    env: &Env,
+    key_id: &Bytes,
+    public_key: &Bytes,
+    settings: &Option<WalletSettings>,
+) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(key_id.clone(), public_key.clone(), settings.clone()).to_xdr(env))
+        .to_bytes()
+}
+
+fn renew_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(RENEW_THRESHOLD, RENEW_TO);
+}
@@ -42,8 +42,14 @@ if (typeof window !== "undefined") {
  * 
  * Ranges:
  * - 100-109: signer storage / management
- * - 110-119: auth (`__check_auth`)
+ * - 110-119: auth (`__check_auth`) and wallet settings
  * - 120-129: WebAuthn (secp256r1) verification
+ * - 130-139: upgrades
+ * - 140-149: execution (`execute`, intents, fees)
+ * - 150-159: freeze timelock and storage migration, added once 110-119 was
+ * full
+ * 
+ * A code is never reassigned once released.
  */
 export const Errors = {
   /**
@@ -55,65 +61,97 @@ export const Errors = {
    */
   101: {message:"SignerAlreadyExists"},
   /**
-   * The signer's expiration timestamp is in the past.
+   * The signer has expired, or is a session signer revoked by
+   * `revoke_sessions`.
    */
   102: {message:"SignerExpired"},
   /**
-   * The operation would remove — or demote via `update_signer` — the
-   * wallet's LAST durable admin signer: a signer stored `Persistent`,
-   * non-expiring (`SignerExpiration(None)`), and independently
-   * admin-capable — either unlimited (`SignerLimits(None)`) or holding a
-   * limits entry for the wallet's own address with no required co-signers
-   * (`None` or an empty list). With zero such signers no `add_signer` or
-   * `upgrade` could ever be authorized again, permanently locking the
-   * wallet on an immutable network, so the transition is rejected.
-   * To retire the last admin signer, add (or promote) a replacement
-   * durable admin signer first.
-   * 
-   * Case this guard CANNOT catch (statically undecidable): a POLICY
-   * signer with an admin-shaped grant counts as an admin even if its
-   * `policy__` rejects every request. If such a policy is your only
-   * remaining admin, the wallet's admin surface is unrecoverable even
-   * though the signer still exists. Keep a non-policy admin (or a second
-   * admin) at all times.
+   * The operation would remove or demote the wallet's last durable admin
+   * signer. Add or promote a replacement first, or in the same
+   * `apply_signer_changes` batch.
    */
   103: {message:"LastAdminSigner"},
   /**
-   * The operation would leave the wallet without any DURABLE signer — one
-   * stored `Persistent` with `SignerExpiration(None)`, any limits. Fired
-   * by `remove_signer` (removing the last durable signer), `update_signer`
-   * (demoting it to `Temporary` storage or to an expiring value), and
-   * `__constructor` (the wallet's first signer must be durable).
-   * Non-durable signers can evict or expire with NO contract
-   * call, so only a durable signer guarantees the wallet always keeps at
-   * least one live signer; with zero live signers nothing — not even
-   * `add_signer` — can ever be authorized again. This is the
-   * classification-independent backstop beneath `LastAdminSigner`. To
-   * retire the last durable signer, add a durable replacement first.
+   * The operation would leave the wallet without a durable signer
+   * (`Persistent`, `SignerExpiration(None)`, any limits). Add a durable
+   * replacement first.
    */
   104: {message:"LastSigner"},
   /**
-   * No signer in the signatures map is permitted to authorize one of the
-   * requested auth contexts.
+   * A Secp256r1 signer's public key is not a valid SEC-1 encoded P-256
+   * point, compressed or uncompressed.
+   */
+  105: {message:"InvalidPublicKey"},
+  /**
+   * The signer's `SignerOptions::valid_after` has not been reached yet.
+   */
+  106: {message:"SignerNotYetValid"},
+  /**
+   * The signer is suspended (`suspend_signer`) until it is resumed.
+   */
+  107: {message:"SignerSuspended"},
+  /**
+   * The signer is the inheritance beneficiary and the owner has not been
+   * inactive for the configured period yet.
+   */
+  108: {message:"BeneficiaryDormant"},
+  /**
+   * `set_inheritance` was called with an inactivity period of zero.
+   */
+  109: {message:"InvalidInactivityPeriod"},
+  /**
+   * No signer in the signatures map may authorize one of the requested
+   * auth contexts.
    */
   110: {message:"MissingContext"},
   /**
-   * A signature's variant does not match the stored signer it claims to be
-   * for (e.g. an Ed25519 signature submitted for a Policy signer key).
+   * A signature's variant does not match the stored signer it is for.
    */
   111: {message:"SignatureKeyValueMismatch"},
+  /**
+   * The wallet is frozen and an auth context is neither `unfreeze` nor
+   * signer management on the wallet itself.
+   */
+  112: {message:"WalletFrozen"},
+  /**
+   * The wallet is frozen and fewer active durable admins signed than the
+   * freeze quorum.
+   */
+  113: {message:"FreezeQuorumNotMet"},
+  /**
+   * `set_freeze_quorum` was called with a quorum of zero.
+   */
+  114: {message:"InvalidFreezeQuorum"},
+  /**
+   * A signer's metadata label is longer than `MAX_SIGNER_LABEL_LEN`.
+   */
+  115: {message:"InvalidSignerMetadata"},
+  /**
+   * A `TtlConfig` rule has a `threshold` above its `extend_to`.
+   */
+  116: {message:"InvalidTtlConfig"},
+  /**
+   * `reconcile_counters` was given the same signer key more than once.
+   */
+  117: {message:"DuplicateSignerKey"},
+  /**
+   * `migrate` was called on storage already at the code's schema version.
+   */
+  118: {message:"AlreadyMigrated"},
+  /**
+   * An `Address` signer names the wallet itself.
+   */
+  119: {message:"SelfAddressSigner"},
   /**
    * clientDataJSON exceeds the 1024 byte parse buffer.
    */
   120: {message:"ClientDataJsonTooLarge"},
   /**
-   * clientDataJSON is not parseable JSON (or is missing required fields).
+   * clientDataJSON is not parseable JSON or lacks a required field.
    */
   121: {message:"ClientDataJsonParseError"},
   /**
-   * The challenge in clientDataJSON does not match the base64url-encoded
-   * signature payload. This binds the WebAuthn assertion to the Soroban
-   * authorization entry and MUST NOT be weakened.
+   * The clientDataJSON challenge is not the base64url signature payload.
    */
   122: {message:"ClientDataJsonChallengeIncorrect"},
   /**
@@ -121,55 +159,189 @@ export const Errors = {
    */
   123: {message:"InvalidWebAuthnType"},
   /**
-   * authenticatorData is shorter than the WebAuthn minimum of 37 bytes
-   * (rpIdHash 32 + flags 1 + signCount 4).
+   * authenticatorData is shorter than the WebAuthn minimum of 37 bytes.
    */
   124: {message:"InvalidAuthenticatorData"},
   /**
    * The authenticator did not set the User Present (UP) flag.
-   * 
-   * UP-only is the deliberate default (audit FIX-7). Requiring UP keeps
-   * silent, non-interactive assertions out while staying compatible with
-   * authenticators that cannot do User Verification (UV — biometric/PIN).
-   * UV is therefore NOT required by this contract. A deployment that wants
-   * UV-required assertions should enforce it at the client/relayer layer,
-   * or via a future per-signer flag (which would be a signer-model change,
-   * not a change to this check); the contract cannot upgrade UP-only
-   * signers to UV-required retroactively without such a flag.
    */
   125: {message:"UserPresenceRequired"},
   /**
-   * authenticatorData exceeds the 1024 byte cap (symmetric with
-   * `ClientDataJsonTooLarge`). Real assertions are ~37 bytes; the cap
-   * rejects oversized input BEFORE it is hashed, since this path is
-   * reachable without a valid signature.
+   * authenticatorData exceeds the 1024 byte cap.
+   */
+  126: {message:"AuthenticatorDataTooLarge"},
+  /**
+   * No upgrade is awaiting confirmation.
+   */
+  130: {message:"UpgradeNotPending"},
+  /**
+   * The pending upgrade has no rollback target (see `PendingUpgrade`).
+   */
+  131: {message:"NoRollbackTarget"},
+  /**
+   * The new code's `confirm_upgrade` self-test failed.
+   */
+  132: {message:"UpgradeSelfTestFailed"},
+  /**
+   * `confirm_upgrade` was called after the pending upgrade's `deadline`.
+   * Roll it back, or upgrade again to restart the confirm window.
    */
-  126: {message:"AuthenticatorDataTooLarge"}
+  133: {message:"UpgradeExpired"},
+  /**
+   * `rollback_upgrade` was called after `migrate` moved storage past the
+   * rollback target's schema version.
+   */
+  134: {message:"RollbackAfterMigration"},
+  /**
+   * An `execute` call targets the wallet itself.
+   */
+  140: {message:"ExecuteSelfCall"},
+  /**
+   * `execute_intent` was called after the intent's `deadline`.
+   */
+  141: {message:"IntentExpired"},
+  /**
+   * The intent's nonce was already consumed.
+   */
+  142: {message:"IntentNonceUsed"},
+  /**
+   * The fee token is not allowed by the wallet's `FeeConfig`, or it has
+   * none.
+   */
+  143: {message:"FeeTokenNotAllowed"},
+  /**
+   * The fee is negative or above the wallet's maximum for its token.
+   */
+  144: {message:"FeeTooHigh"},
+  /**
+   * A `FeeConfig` maximum is negative.
+   */
+  145: {message:"InvalidFeeConfig"},
+  /**
+   * The deadline is further ahead than its nonce can be tracked.
+   */
+  146: {message:"IntentDeadlineTooFar"},
+  /**
+   * The wallet lost its freeze quorum and was frozen less than
+   * `FREEZE_TIMELOCK` seconds ago.
+   */
+  150: {message:"FreezeTimelockActive"},
+  /**
+   * `migrate` has no step for the stored schema version.
+   */
+  155: {message:"UnsupportedSchemaVersion"},
+  /**
+   * A legacy entry is neither a v1 nor a pre-1.0 signer value.
+   */
+  156: {message:"InvalidLegacySigner"},
+  /**
+   * A legacy non-expiring entry has empty limits. Set its limits with
+   * `update_signer` instead.
+   */
+  157: {message:"AmbiguousLegacyLimits"}
+}
+
+
+/**
+ * A call signed off-chain by the wallet's signers, executable by anyone
+ * through `execute_intent` (e.g. a limit order or scheduled payment that a
+ * keeper submits later). The signers sign `get_intent_hash(intent)`.
+ * `nonce` is any unused value; `deadline` is the last UNIX timestamp,
+ * inclusive, at which it may execute. `fee` is signed with it.
+ */
+export interface Intent {
+  args: Array<any>;
+  contract: string;
+  deadline: u64;
+  fee: IntentFee;
+  fn_name: string;
+  nonce: u64;
 }
 
 /**
  * Full signer description used by `__constructor`, `add_signer` and
- * `update_signer`.
+ * `update_signer`. Secp256r1 carries the WebAuthn credential id (`keyId`)
+ * and a SEC-1 P-256 public key, uncompressed (65 bytes) or compressed (33
+ * bytes). Address delegates to another account, never the wallet itself.
+ */
+export type Signer = {tag: "Policy", values: readonly [string, SignerExpiration, SignerLimits, SignerStorage, SignerOptions]} | {tag: "Ed25519", values: readonly [Buffer, SignerExpiration, SignerLimits, SignerStorage, SignerOptions]} | {tag: "Secp256r1", values: readonly [Buffer, Buffer, SignerExpiration, SignerLimits, SignerStorage, SignerOptions]} | {tag: "Address", values: readonly [string, SignerExpiration, SignerLimits, SignerStorage, SignerOptions]};
+
+
+/**
+ * One TTL extension rule, in ledgers: an entry whose remaining TTL is
+ * below `threshold` is extended to `extend_to` (both clamped to the
+ * network's `max_ttl`); otherwise it is left alone, so `extend_to -
+ * threshold` bounds how often the extension is actually written.
  */
-export type Signer = {tag: "Policy", values: readonly [string, SignerExpiration, SignerLimits, SignerStorage]} | {tag: "Ed25519", values: readonly [Buffer, SignerExpiration, SignerLimits, SignerStorage]} | {tag: "Secp256r1", values: readonly [Buffer, Buffer, SignerExpiration, SignerLimits, SignerStorage]};
+export interface TtlRule {
+  extend_to: u32;
+  threshold: u32;
+}
+
+/**
+ * One step of an `apply_signer_changes` batch. Each step has exactly the
+ * semantics of the matching single-signer entrypoint (`add_signer`,
+ * `update_signer`, `remove_signer`), applied in order against the state left
+ * by the previous step.
+ */
+export type SignerOp = {tag: "Add", values: readonly [Signer]} | {tag: "Update", values: readonly [Signer]} | {tag: "Remove", values: readonly [SignerKey]};
+
+
+/**
+ * Which tokens the wallet pays intent fees in (`set_fee_config`), each
+ * with the maximum fee per intent. A wallet without one pays no fees.
+ */
+export interface FeeConfig {
+  max_fees: Map<string, i128>;
+}
+
+/**
+ * The fee an `Intent` pays its submitter, if any.
+ */
+export type IntentFee = {tag: "None", values: void} | {tag: "Token", values: readonly [FeePayment]};
 
 /**
  * A signature entry in the signatures map. `Policy` carries no signature
  * material: inclusion of the policy key authorizes an on-chain `policy__`
- * check instead.
+ * check instead. Nor does `Address`: the address must authorize the
+ * wallet's `__check_auth` with `args = [signature payload]` in its own
+ * authorization entry of the same transaction.
  */
-export type Signature = {tag: "Policy", values: void} | {tag: "Ed25519", values: readonly [Buffer]} | {tag: "Secp256r1", values: readonly [Secp256r1Signature]};
+export type Signature = {tag: "Policy", values: void} | {tag: "Ed25519", values: readonly [Buffer]} | {tag: "Secp256r1", values: readonly [Secp256r1Signature]} | {tag: "Address", values: void};
 
 /**
  * Storage key identifying a signer. Secp256r1 carries the WebAuthn
  * credential id (`keyId`).
  */
-export type SignerKey = {tag: "Policy", values: readonly [string]} | {tag: "Ed25519", values: readonly [Buffer]} | {tag: "Secp256r1", values: readonly [Buffer]};
+export type SignerKey = {tag: "Policy", values: readonly [string]} | {tag: "Ed25519", values: readonly [Buffer]} | {tag: "Secp256r1", values: readonly [Buffer]} | {tag: "Address", values: readonly [string]};
+
+/**
+ * Stored signer value. Secp256r1 carries the SEC-1 uncompressed public key
+ * (validated, and decompressed if it was registered compressed).
+ */
+export type SignerVal = {tag: "Policy", values: readonly [SignerExpiration, SignerLimits]} | {tag: "Ed25519", values: readonly [SignerExpiration, SignerLimits]} | {tag: "Secp256r1", values: readonly [Buffer, SignerExpiration, SignerLimits]} | {tag: "Address", values: readonly [SignerExpiration, SignerLimits]};
+
+
+/**
+ * The wallet's rent strategy (`set_ttl_config`). `auth` applies to
+ * `__check_auth` and `bump`; `mutation` applies to every wallet-authorized
+ * state change.
+ */
+export interface TtlConfig {
+  auth: TtlSchedule;
+  mutation: TtlSchedule;
+}
+
 
 /**
- * Stored signer value. Secp256r1 carries the SEC-1 uncompressed public key.
+ * A fee in a SEP-41 `token`, paid by the wallet to `recipient` (the
+ * relayer the intent is signed for) to reimburse the network fee it paid.
  */
-export type SignerVal = {tag: "Policy", values: readonly [SignerExpiration, SignerLimits]} | {tag: "Ed25519", values: readonly [SignerExpiration, SignerLimits]} | {tag: "Secp256r1", values: readonly [Buffer, SignerExpiration, SignerLimits]};
+export interface FeePayment {
+  amount: i128;
+  recipient: string;
+  token: string;
+}
 
 /**
  * The `__check_auth` signature object: a map of signer keys to signatures.
@@ -178,6 +350,18 @@ export type SignerVal = {tag: "Policy", values: readonly [SignerExpiration, Sign
  */
 export type Signatures = readonly [Map<SignerKey, Signature>];
 
+
+/**
+ * The `TtlRule`s applied on one kind of call, per class of entry.
+ * `persistent`/`temporary` cover signer entries of that durability together
+ * with their companion entries.
+ */
+export interface TtlSchedule {
+  instance: TtlRule;
+  persistent: TtlRule;
+  temporary: TtlRule;
+}
+
 /**
  * Restrictions on which auth contexts a signer may authorize.
  * 
@@ -193,35 +377,168 @@ export type Signatures = readonly [Map<SignerKey, Signature>];
  * - `Some({address -> Some([keys])})`: the signer may authorize invocations
  * of contract `address` only if every listed key also APPROVES. The listed
  * keys are required CO-SIGNERS.
- * 
- * ## Required co-signers are scope-independent approvers (audit FIX-5)
- * 
- * A required co-signer's OWN `SignerLimits` do NOT constrain its co-signer
- * role — a key's limits govern only its INDEPENDENT authority (whether it can
- * cover a context on its own). This is symmetric across key kinds:
- * 
- * - 
  */
 export type SignerLimits = readonly [Option<Map<string, Option<Array<SignerKey>>>>];
 
+
+/**
+ * Per-signer settings beyond the stored `SignerVal`, supplied as the last
+ * field of every `Signer`. The default is not stored.
+ */
+export interface SignerOptions {
+  /**
+ * The signer may authorize the wallet's own `freeze` call by itself,
+ * whatever its `SignerLimits`, while it is active.
+ */
+can_freeze: boolean;
+  /**
+ * The signer may authorize a SEP-45 `web_auth_verify(args)` call for
+ * this wallet by itself, whatever its `SignerLimits`, if the wallet's
+ * `WebAuthConfig` lists the web-auth contract for its home domain.
+ */
+can_web_auth: boolean;
+  /**
+ * Display metadata for wallets and indexers (every field optional).
+ * Never evaluated by the contract.
+ */
+metadata: SignerMetadata;
+  /**
+ * Optional validity start as a UNIX timestamp in seconds, inclusive.
+ * Until then the signer is skipped for coverage and rejected
+ * (`Error::SignerNotYetValid`) in a signatures map.
+ */
+valid_after: Option<u64>;
+}
+
 /**
  * Which durability a signer entry is stored under. At most one entry exists
  * per signer key; lookups check Temporary before Persistent.
  */
 export type SignerStorage = {tag: "Persistent", values: void} | {tag: "Temporary", values: void};
 
+/**
+ * One setting `__constructor` applies, validated exactly as by its setter.
+ */
+export type WalletSetting = {tag: "FreezeQuorum", values: readonly [u32]} | {tag: "TtlConfig", values: readonly [TtlConfig]} | {tag: "Inheritance", values: readonly [InheritanceConfig]} | {tag: "FeeConfig", values: readonly [FeeConfig]} | {tag: "SignerRegistry", values: readonly [string]} | {tag: "WebAuthConfig", values: readonly [WebAuthConfig]};
+
+
+/**
+ * The SEP-45 web-auth contracts a `can_web_auth` signer may log in to
+ * (`set_web_auth_config`), each with the home domain of the anchor that
+ * publishes it.
+ */
+export interface WebAuthConfig {
+  contracts: Map<string, string>;
+}
+
+
+/**
+ * An `upgrade` awaiting `confirm_upgrade` (`get_pending_upgrade`). `hash`
+ * is the wasm now running; `rollback_upgrade` reverts to `rollback_hash`,
+ * the last confirmed wasm, while storage is still at `schema_version`.
+ * `deadline` ends the confirm window; each `upgrade` restarts it.
+ */
+export interface PendingUpgrade {
+  deadline: u64;
+  hash: Buffer;
+  rollback_hash: Option<Buffer>;
+  schema_version: u32;
+}
+
+
+/**
+ * Usage record of a signer (`get_signer_activity`), updated when it signs
+ * a successful `__check_auth`, at most once per `ACTIVITY_INTERVAL`.
+ */
+export interface SignerActivity {
+  /**
+ * UNIX timestamp in seconds of the last recorded use, at most
+ * `ACTIVITY_INTERVAL` behind the last successful one; `0` if the signer
+ * has never been used.
+ */
+last_used: u64;
+  /**
+ * Number of recorded uses (saturating): successful authorizations at
+ * least `ACTIVITY_INTERVAL` after the previous recorded one.
+ */
+use_count: u32;
+}
+
+
+/**
+ * Human-readable description of a signer (`SignerOptions::metadata`), so
+ * clients and indexers can render "Alice's iPhone" instead of a credential
+ * id. Only the label's length is checked.
+ */
+export interface SignerMetadata {
+  /**
+ * When the credential was created, as a UNIX timestamp in seconds.
+ */
+created_at: Option<u64>;
+  /**
+ * Device-type tag, e.g. `iphone`, `android`, `yubikey` or `ledger`.
+ */
+device: Option<string>;
+  /**
+ * Display name, at most `MAX_SIGNER_LABEL_LEN` bytes (UTF-8 by
+ * convention).
+ */
+label: Option<string>;
+}
+
+/**
+ * Wallet settings applied by `__constructor`, so a wallet is born
+ * configured instead of needing an authorized follow-up per setting.
+ * Settings are applied in order, as successive setter calls would be; one
+ * not listed stays at its default.
+ */
+export type WalletSettings = readonly [Array<WalletSetting>];
+
+
+/**
+ * A ledger and the UNIX timestamp it closed at, supplied to
+ * `migrate_legacy_signers` to convert pre-1.0 ledger-sequence expirations
+ * into v1 timestamps.
+ */
+export interface LedgerReference {
+  sequence: u32;
+  timestamp: u64;
+}
+
 /**
  * Optional expiration for a signer as a UNIX timestamp in seconds, INCLUSIVE:
  * the signer is valid while `ledger timestamp <= expiration` and expired once
  * `ledger timestamp > expiration`. `None` never expires.
- * 
- * v1 breaking change: this was a ledger sequence number pre-1.0. Timestamps
- * don't drift with changes to ledger close time (e.g. CAP-0070 dynamic
- * timing), which ledger-sequence expirations did.
  */
 export type SignerExpiration = readonly [Option<u64>];
 
 
+/**
+ * A subaccount to deploy with `create_subaccount`: the wasm it runs and its
+ * signers besides the parent, e.g. a spending key limited to one token.
+ * The parent wallet is added first, as an unlimited durable `Address`
+ * signer, so `signers` may be empty.
+ */
+export interface SubaccountConfig {
+  signers: Array<Signer>;
+  wasm_hash: Buffer;
+}
+
+
+/**
+ * Inheritance ("dead man's switch") configuration, set with
+ * `set_inheritance`.
+ * 
+ * `beneficiary` is a stored signer that stays dormant while the owner is
+ * active, and becomes an unlimited admin once no owner activity has been
+ * recorded for more than `inactivity_period` seconds.
+ */
+export interface InheritanceConfig {
+  beneficiary: SignerKey;
+  inactivity_period: u64;
+}
+
+
 /**
  * A WebAuthn assertion over the Soroban authorization payload. The signed
  * message is `authenticator_data || sha256(client_data_json)` and the
@@ -237,12 +554,57 @@ export interface Secp256r1Signature {
 
 
 
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
 export interface Client {
+  /**
+   * Construct and simulate a bump transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  bump: ({signer_keys}: {signer_keys: Array<SignerKey>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a freeze transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  freeze: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a execute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  execute: ({calls}: {calls: Array<readonly [string, string, Array<any>]>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<any>>>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  migrate: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a unfreeze transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  unfreeze: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_frozen transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_frozen: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
   /**
    * Construct and simulate a add_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -253,21 +615,216 @@ export interface Client {
    */
   get_signer: ({signer_key}: {signer_key: SignerKey}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SignerVal>>>
 
+  /**
+   * Construct and simulate a cancel_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cancel_intent: ({nonce, deadline}: {nonce: u64, deadline: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_subaccount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_subaccount: ({address}: {address: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a prune_expired transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  prune_expired: ({signer_keys}: {signer_keys: Array<SignerKey>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a remove_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   remove_signer: ({signer_key}: {signer_key: SignerKey}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a resume_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  resume_signer: ({signer_key}: {signer_key: SignerKey}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a update_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   update_signer: ({signer}: {signer: Signer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a execute_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  execute_intent: ({intent, signatures}: {intent: Intent, signatures: Signatures}, options?: MethodOptions) => Promise<AssembledTransaction<Result<any>>>
+
+  /**
+   * Construct and simulate a get_fee_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_fee_config: (options?: MethodOptions) => Promise<AssembledTransaction<Option<FeeConfig>>>
+
+  /**
+   * Construct and simulate a get_ttl_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_ttl_config: (options?: MethodOptions) => Promise<AssembledTransaction<Option<TtlConfig>>>
+
+  /**
+   * Construct and simulate a set_fee_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_fee_config: ({config}: {config: Option<FeeConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_ttl_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_ttl_config: ({config}: {config: Option<TtlConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a suspend_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  suspend_signer: ({signer_key}: {signer_key: SignerKey}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a confirm_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  confirm_upgrade: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_inheritance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_inheritance: (options?: MethodOptions) => Promise<AssembledTransaction<Option<InheritanceConfig>>>
+
+  /**
+   * Construct and simulate a get_intent_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_intent_hash: ({intent}: {intent: Intent}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a get_last_active transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_last_active: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u64>>>
+
+  /**
+   * Construct and simulate a revoke_sessions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  revoke_sessions: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_inheritance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_inheritance: ({config}: {config: Option<InheritanceConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a rollback_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  rollback_upgrade: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a create_subaccount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  create_subaccount: ({salt, config}: {salt: Buffer, config: SubaccountConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_freeze_quorum transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_freeze_quorum: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_freeze_quorum transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_freeze_quorum: ({quorum}: {quorum: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_schema_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_signature_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_signature_hash: ({hash}: {hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a get_signer_options transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_signer_options: ({signer_key}: {signer_key: SignerKey}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SignerOptions>>>
+
+  /**
+   * Construct and simulate a is_valid_signature transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_valid_signature: ({hash, signatures}: {hash: Buffer, signatures: Signatures}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a reconcile_counters transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  reconcile_counters: ({signer_keys}: {signer_keys: Array<SignerKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_pending_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pending_upgrade: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingUpgrade>>>
+
+  /**
+   * Construct and simulate a get_signer_activity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_signer_activity: ({signer_key}: {signer_key: SignerKey}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SignerActivity>>>
+
+  /**
+   * Construct and simulate a get_signer_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_signer_registry: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_web_auth_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_web_auth_config: (options?: MethodOptions) => Promise<AssembledTransaction<Option<WebAuthConfig>>>
+
+  /**
+   * Construct and simulate a is_signer_suspended transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_signer_suspended: ({signer_key}: {signer_key: SignerKey}, options?: MethodOptions) => Promise<AssembledTransaction<Option<boolean>>>
+
+  /**
+   * Construct and simulate a set_signer_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_signer_registry: ({registry}: {registry: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_web_auth_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_web_auth_config: ({config}: {config: Option<WebAuthConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a apply_signer_changes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  apply_signer_changes: ({ops}: {ops: Array<SignerOp>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_upgrade_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_upgrade_guardian: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a is_intent_nonce_used transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_intent_nonce_used: ({nonce}: {nonce: u64}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_upgrade_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_upgrade_guardian: ({guardian}: {guardian: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_session_generation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_session_generation: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a migrate_legacy_signers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  migrate_legacy_signers: ({signer_keys, reference}: {signer_keys: Array<SignerKey>, reference: LedgerReference}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_last_owner_activity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_last_owner_activity: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u64>>>
+
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
         /** Constructor/Initialization Args for the contract's `__constructor` method */
-        {signer}: {signer: Signer},
+        {signers, settings}: {signers: Array<Signer>, settings: Option<WalletSettings>},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -279,39 +836,162 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy({signer}, options)
+    return ContractClient.deploy({signers, settings}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+      new ContractSpec([ "AAAAAAAAAAAAAAAEYnVtcAAAAAEAAAAAAAAAC3NpZ25lcl9rZXlzAAAAA+oAAAfQAAAACVNpZ25lcktleQAAAAAAAAA=",
+        "AAAAAAAAAAAAAAAGZnJlZXplAAAAAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAAHZXhlY3V0ZQAAAAABAAAAAAAAAAVjYWxscwAAAAAAA+oAAAPtAAAAAwAAABMAAAARAAAD6gAAAAAAAAABAAAD6QAAA+oAAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAAHbWlncmF0ZQAAAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAIdW5mcmVlemUAAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAAJaXNfZnJvemVuAAAAAAAAAAAAAAEAAAAB",
         "AAAAAAAAAAAAAAAKYWRkX3NpZ25lcgAAAAAAAQAAAAAAAAAGc2lnbmVyAAAAAAfQAAAABlNpZ25lcgAAAAAAAQAAA+kAAAACAAAAAw==",
         "AAAAAAAAAAAAAAAKZ2V0X3NpZ25lcgAAAAAAAQAAAAAAAAAKc2lnbmVyX2tleQAAAAAH0AAAAAlTaWduZXJLZXkAAAAAAAABAAAD6AAAB9AAAAAJU2lnbmVyVmFsAAAA",
         "AAAAAAAAAAAAAAAMX19jaGVja19hdXRoAAAAAwAAAAAAAAARc2lnbmF0dXJlX3BheWxvYWQAAAAAAAPuAAAAIAAAAAAAAAAKc2lnbmF0dXJlcwAAAAAH0AAAAApTaWduYXR1cmVzAAAAAAAAAAAADWF1dGhfY29udGV4dHMAAAAAAAPqAAAH0AAAAAdDb250ZXh0AAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAABnNpZ25lcgAAAAAH0AAAAAZTaWduZXIAAAAAAAA=",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAIAAAAAAAAAB3NpZ25lcnMAAAAD6gAAB9AAAAAGU2lnbmVyAAAAAAAAAAAACHNldHRpbmdzAAAD6AAAB9AAAAAOV2FsbGV0U2V0dGluZ3MAAAAAAAA=",
+        "AAAAAAAAAAAAAAANY2FuY2VsX2ludGVudAAAAAAAAAIAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAANaXNfc3ViYWNjb3VudAAAAAAAAAEAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAEAAAAB",
+        "AAAAAAAAAAAAAAANcHJ1bmVfZXhwaXJlZAAAAAAAAAEAAAAAAAAAC3NpZ25lcl9rZXlzAAAAA+oAAAfQAAAACVNpZ25lcktleQAAAAAAAAA=",
         "AAAAAAAAAAAAAAANcmVtb3ZlX3NpZ25lcgAAAAAAAAEAAAAAAAAACnNpZ25lcl9rZXkAAAAAB9AAAAAJU2lnbmVyS2V5AAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAANcmVzdW1lX3NpZ25lcgAAAAAAAAEAAAAAAAAACnNpZ25lcl9rZXkAAAAAB9AAAAAJU2lnbmVyS2V5AAAAAAAAAQAAA+kAAAACAAAAAw==",
         "AAAAAAAAAAAAAAANdXBkYXRlX3NpZ25lcgAAAAAAAAEAAAAAAAAABnNpZ25lcgAAAAAH0AAAAAZTaWduZXIAAAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAABAAAAVBDb250cmFjdCBlcnJvcnMuCgpEZWxpYmVyYXRlbHkgcmVudW1iZXJlZCBmb3IgdGhlIHYxIGludGVyZmFjZSBzbyB0aGUgZXJyb3Igc3BhY2UgaXMgZGlzam9pbnQKZnJvbSB0aGUgbGVnYWN5IChwcmUtMS4wKSBjb250cmFjdCdzIDEtOSByYW5nZS4gQSBjbGllbnQgZGVjb2RpbmcgYW4gZXJyb3IKY29kZSA8IDEwMCBpcyB0YWxraW5nIHRvIGEgbGVnYWN5IHdhbGxldC4KClJhbmdlczoKLSAxMDAtMTA5OiBzaWduZXIgc3RvcmFnZSAvIG1hbmFnZW1lbnQKLSAxMTAtMTE5OiBhdXRoIChgX19jaGVja19hdXRoYCkKLSAxMjAtMTI5OiBXZWJBdXRobiAoc2VjcDI1NnIxKSB2ZXJpZmljYXRpb24AAAAAAAAABUVycm9yAAAAAAAADgAAADlUaGUgcmVxdWVzdGVkIHNpZ25lciBkb2VzIG5vdCBleGlzdCBvbiB0aGlzIHNtYXJ0IHdhbGxldC4AAAAAAAAOU2lnbmVyTm90Rm91bmQAAAAAAGQAAAA+YGFkZF9zaWduZXJgIHdhcyBjYWxsZWQgd2l0aCBhIHNpZ25lciBrZXkgdGhhdCBhbHJlYWR5IGV4aXN0cy4AAAAAABNTaWduZXJBbHJlYWR5RXhpc3RzAAAAAGUAAAAxVGhlIHNpZ25lcidzIGV4cGlyYXRpb24gdGltZXN0YW1wIGlzIGluIHRoZSBwYXN0LgAAAAAAAA1TaWduZXJFeHBpcmVkAAAAAAAAZgAAA9FUaGUgb3BlcmF0aW9uIHdvdWxkIHJlbW92ZSDigJQgb3IgZGVtb3RlIHZpYSBgdXBkYXRlX3NpZ25lcmAg4oCUIHRoZQp3YWxsZXQncyBMQVNUIGR1cmFibGUgYWRtaW4gc2lnbmVyOiBhIHNpZ25lciBzdG9yZWQgYFBlcnNpc3RlbnRgLApub24tZXhwaXJpbmcgKGBTaWduZXJFeHBpcmF0aW9uKE5vbmUpYCksIGFuZCBpbmRlcGVuZGVudGx5CmFkbWluLWNhcGFibGUg4oCUIGVpdGhlciB1bmxpbWl0ZWQgKGBTaWduZXJMaW1pdHMoTm9uZSlgKSBvciBob2xkaW5nIGEKbGltaXRzIGVudHJ5IGZvciB0aGUgd2FsbGV0J3Mgb3duIGFkZHJlc3Mgd2l0aCBubyByZXF1aXJlZCBjby1zaWduZXJzCihgTm9uZWAgb3IgYW4gZW1wdHkgbGlzdCkuIFdpdGggemVybyBzdWNoIHNpZ25lcnMgbm8gYGFkZF9zaWduZXJgIG9yCmB1cGdyYWRlYCBjb3VsZCBldmVyIGJlIGF1dGhvcml6ZWQgYWdhaW4sIHBlcm1hbmVudGx5IGxvY2tpbmcgdGhlCndhbGxldCBvbiBhbiBpbW11dGFibGUgbmV0d29yaywgc28gdGhlIHRyYW5zaXRpb24gaXMgcmVqZWN0ZWQuClRvIHJldGlyZSB0aGUgbGFzdCBhZG1pbiBzaWduZXIsIGFkZCAob3IgcHJvbW90ZSkgYSByZXBsYWNlbWVudApkdXJhYmxlIGFkbWluIHNpZ25lciBmaXJzdC4KCkZvb3RndW4gdGhpcyBndWFyZCBDQU5OT1QgY2F0Y2ggKHN0YXRpY2FsbHkgdW5kZWNpZGFibGUpOiBhIFBPTElDWQpzaWduZXIgd2l0aCBhbiBhZG1pbi1zaGFwZWQgZ3JhbnQgY291bnRzIGFzIGFuIGFkbWluIGV2ZW4gaWYgaXRzCmBwb2xpY3lfX2AgcmVqZWN0cyBldmVyeSByZXF1ZXN0LiBJZiBzdWNoIGEgcG9saWN5IGlzIHlvdXIgb25seQpyZW1haW5pbmcgYWRtaW4sIHRoZSB3YWxsZXQncyBhZG1pbiBzdXJmYWNlIGlzIHVucmVjb3ZlcmFibGUgZXZlbgp0aG91Z2ggdGhlIHNpZ25lciBzdGlsbCBleGlzdHMuIEtlZXAgYSBub24tcG9saWN5IGFkbWluIChvciBhIHNlY29uZAphZG1pbikgYXQgYWxsIHRpbWVzLgAAAAAAAA9MYXN0QWRtaW5TaWduZXIAAAAAZwAAAuRUaGUgb3BlcmF0aW9uIHdvdWxkIGxlYXZlIHRoZSB3YWxsZXQgd2l0aG91dCBhbnkgRFVSQUJMRSBzaWduZXIg4oCUIG9uZQpzdG9yZWQgYFBlcnNpc3RlbnRgIHdpdGggYFNpZ25lckV4cGlyYXRpb24oTm9uZSlgLCBhbnkgbGltaXRzLiBGaXJlZApieSBgcmVtb3ZlX3NpZ25lcmAgKHJlbW92aW5nIHRoZSBsYXN0IGR1cmFibGUgc2lnbmVyKSwgYHVwZGF0ZV9zaWduZXJgCihkZW1vdGluZyBpdCB0byBgVGVtcG9yYXJ5YCBzdG9yYWdlIG9yIHRvIGFuIGV4cGlyaW5nIHZhbHVlKSwgYW5kCmBfX2NvbnN0cnVjdG9yYCAoYSB3YWxsZXQgd2hvc2UgZmlyc3Qgc2lnbmVyIGlzIG5vbi1kdXJhYmxlIGlzIGJvcm4KYnJpY2thYmxlKS4gTm9uLWR1cmFibGUgc2lnbmVycyBjYW4gZXZpY3Qgb3IgZXhwaXJlIHdpdGggTk8gY29udHJhY3QKY2FsbCwgc28gb25seSBhIGR1cmFibGUgc2lnbmVyIGd1YXJhbnRlZXMgdGhlIHdhbGxldCBhbHdheXMga2VlcHMgYXQKbGVhc3Qgb25lIGxpdmUgc2lnbmVyOyB3aXRoIHplcm8gbGl2ZSBzaWduZXJzIG5vdGhpbmcg4oCUIG5vdCBldmVuCmBhZGRfc2lnbmVyYCDigJQgY2FuIGV2ZXIgYmUgYXV0aG9yaXplZCBhZ2Fpbi4gVGhpcyBpcyB0aGUKY2xhc3NpZmljYXRpb24taW5kZXBlbmRlbnQgYmFja3N0b3AgYmVuZWF0aCBgTGFzdEFkbWluU2lnbmVyYC4gVG8KcmV0aXJlIHRoZSBsYXN0IGR1cmFibGUgc2lnbmVyLCBhZGQgYSBkdXJhYmxlIHJlcGxhY2VtZW50IGZpcnN0LgAAAApMYXN0U2lnbmVyAAAAAABoAAAAXU5vIHNpZ25lciBpbiB0aGUgc2lnbmF0dXJlcyBtYXAgaXMgcGVybWl0dGVkIHRvIGF1dGhvcml6ZSBvbmUgb2YgdGhlCnJlcXVlc3RlZCBhdXRoIGNvbnRleHRzLgAAAAAAAA5NaXNzaW5nQ29udGV4dAAAAAAAbgAAAIlBIHNpZ25hdHVyZSdzIHZhcmlhbnQgZG9lcyBub3QgbWF0Y2ggdGhlIHN0b3JlZCBzaWduZXIgaXQgY2xhaW1zIHRvIGJlCmZvciAoZS5nLiBhbiBFZDI1NTE5IHNpZ25hdHVyZSBzdWJtaXR0ZWQgZm9yIGEgUG9saWN5IHNpZ25lciBrZXkpLgAAAAAAABlTaWduYXR1cmVLZXlWYWx1ZU1pc21hdGNoAAAAAAAAbwAAADJjbGllbnREYXRhSlNPTiBleGNlZWRzIHRoZSAxMDI0IGJ5dGUgcGFyc2UgYnVmZmVyLgAAAAAAFkNsaWVudERhdGFKc29uVG9vTGFyZ2UAAAAAAHgAAABFY2xpZW50RGF0YUpTT04gaXMgbm90IHBhcnNlYWJsZSBKU09OIChvciBpcyBtaXNzaW5nIHJlcXVpcmVkIGZpZWxkcykuAAAAAAAAGENsaWVudERhdGFKc29uUGFyc2VFcnJvcgAAAHkAAAC2VGhlIGNoYWxsZW5nZSBpbiBjbGllbnREYXRhSlNPTiBkb2VzIG5vdCBtYXRjaCB0aGUgYmFzZTY0dXJsLWVuY29kZWQKc2lnbmF0dXJlIHBheWxvYWQuIFRoaXMgYmluZHMgdGhlIFdlYkF1dGhuIGFzc2VydGlvbiB0byB0aGUgU29yb2JhbgphdXRob3JpemF0aW9uIGVudHJ5IGFuZCBNVVNUIE5PVCBiZSB3ZWFrZW5lZC4AAAAAACBDbGllbnREYXRhSnNvbkNoYWxsZW5nZUluY29ycmVjdAAAAHoAAAAsY2xpZW50RGF0YUpTT04gYHR5cGVgIGlzIG5vdCAid2ViYXV0aG4uZ2V0Ii4AAAATSW52YWxpZFdlYkF1dGhuVHlwZQAAAAB7AAAAaWF1dGhlbnRpY2F0b3JEYXRhIGlzIHNob3J0ZXIgdGhhbiB0aGUgV2ViQXV0aG4gbWluaW11bSBvZiAzNyBieXRlcwoocnBJZEhhc2ggMzIgKyBmbGFncyAxICsgc2lnbkNvdW50IDQpLgAAAAAAABhJbnZhbGlkQXV0aGVudGljYXRvckRhdGEAAAB8AAACWlRoZSBhdXRoZW50aWNhdG9yIGRpZCBub3Qgc2V0IHRoZSBVc2VyIFByZXNlbnQgKFVQKSBmbGFnLgoKVVAtb25seSBpcyB0aGUgZGVsaWJlcmF0ZSBkZWZhdWx0IChhdWRpdCBGSVgtNykuIFJlcXVpcmluZyBVUCBrZWVwcwpzaWxlbnQsIG5vbi1pbnRlcmFjdGl2ZSBhc3NlcnRpb25zIG91dCB3aGlsZSBzdGF5aW5nIGNvbXBhdGlibGUgd2l0aAphdXRoZW50aWNhdG9ycyB0aGF0IGNhbm5vdCBkbyBVc2VyIFZlcmlmaWNhdGlvbiAoVVYg4oCUIGJpb21ldHJpYy9QSU4pLgpVViBpcyB0aGVyZWZvcmUgTk9UIHJlcXVpcmVkIGJ5IHRoaXMgY29udHJhY3QuIEEgZGVwbG95bWVudCB0aGF0IHdhbnRzClVWLXJlcXVpcmVkIGFzc2VydGlvbnMgc2hvdWxkIGVuZm9yY2UgaXQgYXQgdGhlIGNsaWVudC9yZWxheWVyIGxheWVyLApvciB2aWEgYSBmdXR1cmUgcGVyLXNpZ25lciBmbGFnICh3aGljaCB3b3VsZCBiZSBhIHNpZ25lci1tb2RlbCBjaGFuZ2UsCm5vdCBhIGNoYW5nZSB0byB0aGlzIGNoZWNrKTsgdGhlIGNvbnRyYWN0IGNhbm5vdCB1cGdyYWRlIFVQLW9ubHkKc2lnbmVycyB0byBVVi1yZXF1aXJlZCByZXRyb2FjdGl2ZWx5IHdpdGhvdXQgc3VjaCBhIGZsYWcuAAAAAAAUVXNlclByZXNlbmNlUmVxdWlyZWQAAAB9AAAA4mF1dGhlbnRpY2F0b3JEYXRhIGV4Y2VlZHMgdGhlIDEwMjQgYnl0ZSBjYXAgKHN5bW1ldHJpYyB3aXRoCmBDbGllbnREYXRhSnNvblRvb0xhcmdlYCkuIFJlYWwgYXNzZXJ0aW9ucyBhcmUgfjM3IGJ5dGVzOyB0aGUgY2FwCnJlamVjdHMgb3ZlcnNpemVkIGlucHV0IEJFRk9SRSBpdCBpcyBoYXNoZWQsIHNpbmNlIHRoaXMgcGF0aCBpcwpyZWFjaGFibGUgd2l0aG91dCBhIHZhbGlkIHNpZ25hdHVyZS4AAAAAABlBdXRoZW50aWNhdG9yRGF0YVRvb0xhcmdlAAAAAAAAfg==",
-        "AAAAAgAAAFJGdWxsIHNpZ25lciBkZXNjcmlwdGlvbiB1c2VkIGJ5IGBfX2NvbnN0cnVjdG9yYCwgYGFkZF9zaWduZXJgIGFuZApgdXBkYXRlX3NpZ25lcmAuAAAAAAAAAAAABlNpZ25lcgAAAAAAAwAAAAEAAAAAAAAABlBvbGljeQAAAAAABAAAABMAAAfQAAAAEFNpZ25lckV4cGlyYXRpb24AAAfQAAAADFNpZ25lckxpbWl0cwAAB9AAAAANU2lnbmVyU3RvcmFnZQAAAAAAAAEAAAAAAAAAB0VkMjU1MTkAAAAABAAAA+4AAAAgAAAH0AAAABBTaWduZXJFeHBpcmF0aW9uAAAH0AAAAAxTaWduZXJMaW1pdHMAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAABAAAAAAAAAAlTZWNwMjU2cjEAAAAAAAAFAAAADgAAA+4AAABBAAAH0AAAABBTaWduZXJFeHBpcmF0aW9uAAAH0AAAAAxTaWduZXJMaW1pdHMAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAA=",
-        "AAAAAgAAAJ1BIHNpZ25hdHVyZSBlbnRyeSBpbiB0aGUgc2lnbmF0dXJlcyBtYXAuIGBQb2xpY3lgIGNhcnJpZXMgbm8gc2lnbmF0dXJlCm1hdGVyaWFsOiBpbmNsdXNpb24gb2YgdGhlIHBvbGljeSBrZXkgYXV0aG9yaXplcyBhbiBvbi1jaGFpbiBgcG9saWN5X19gCmNoZWNrIGluc3RlYWQuAAAAAAAAAAAAAAlTaWduYXR1cmUAAAAAAAADAAAAAAAAAAAAAAAGUG9saWN5AAAAAAABAAAAAAAAAAdFZDI1NTE5AAAAAAEAAAPuAAAAQAAAAAEAAAAAAAAACVNlY3AyNTZyMQAAAAAAAAEAAAfQAAAAElNlY3AyNTZyMVNpZ25hdHVyZQAA",
-        "AAAAAgAAAFlTdG9yYWdlIGtleSBpZGVudGlmeWluZyBhIHNpZ25lci4gU2VjcDI1NnIxIGNhcnJpZXMgdGhlIFdlYkF1dGhuCmNyZWRlbnRpYWwgaWQgKGBrZXlJZGApLgAAAAAAAAAAAAAJU2lnbmVyS2V5AAAAAAAAAwAAAAEAAAAAAAAABlBvbGljeQAAAAAAAQAAABMAAAABAAAAAAAAAAdFZDI1NTE5AAAAAAEAAAPuAAAAIAAAAAEAAAAAAAAACVNlY3AyNTZyMQAAAAAAAAEAAAAO",
-        "AAAAAgAAAElTdG9yZWQgc2lnbmVyIHZhbHVlLiBTZWNwMjU2cjEgY2FycmllcyB0aGUgU0VDLTEgdW5jb21wcmVzc2VkIHB1YmxpYyBrZXkuAAAAAAAAAAAAAAlTaWduZXJWYWwAAAAAAAADAAAAAQAAAAAAAAAGUG9saWN5AAAAAAACAAAH0AAAABBTaWduZXJFeHBpcmF0aW9uAAAH0AAAAAxTaWduZXJMaW1pdHMAAAABAAAAAAAAAAdFZDI1NTE5AAAAAAIAAAfQAAAAEFNpZ25lckV4cGlyYXRpb24AAAfQAAAADFNpZ25lckxpbWl0cwAAAAEAAAAAAAAACVNlY3AyNTZyMQAAAAAAAAMAAAPuAAAAQQAAB9AAAAAQU2lnbmVyRXhwaXJhdGlvbgAAB9AAAAAMU2lnbmVyTGltaXRz",
+        "AAAAAAAAAAAAAAAOZXhlY3V0ZV9pbnRlbnQAAAAAAAIAAAAAAAAABmludGVudAAAAAAH0AAAAAZJbnRlbnQAAAAAAAAAAAAKc2lnbmF0dXJlcwAAAAAH0AAAAApTaWduYXR1cmVzAAAAAAABAAAD6QAAAAAAAAAD",
+        "AAAAAAAAAAAAAAAOZ2V0X2ZlZV9jb25maWcAAAAAAAAAAAABAAAD6AAAB9AAAAAJRmVlQ29uZmlnAAAA",
+        "AAAAAAAAAAAAAAAOZ2V0X3R0bF9jb25maWcAAAAAAAAAAAABAAAD6AAAB9AAAAAJVHRsQ29uZmlnAAAA",
+        "AAAAAAAAAAAAAAAOc2V0X2ZlZV9jb25maWcAAAAAAAEAAAAAAAAABmNvbmZpZwAAAAAD6AAAB9AAAAAJRmVlQ29uZmlnAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAAOc2V0X3R0bF9jb25maWcAAAAAAAEAAAAAAAAABmNvbmZpZwAAAAAD6AAAB9AAAAAJVHRsQ29uZmlnAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAAOc3VzcGVuZF9zaWduZXIAAAAAAAEAAAAAAAAACnNpZ25lcl9rZXkAAAAAB9AAAAAJU2lnbmVyS2V5AAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAAPY29uZmlybV91cGdyYWRlAAAAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAPZ2V0X2luaGVyaXRhbmNlAAAAAAAAAAABAAAD6AAAB9AAAAARSW5oZXJpdGFuY2VDb25maWcAAAA=",
+        "AAAAAAAAAAAAAAAPZ2V0X2ludGVudF9oYXNoAAAAAAEAAAAAAAAABmludGVudAAAAAAH0AAAAAZJbnRlbnQAAAAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAAAAAAAPZ2V0X2xhc3RfYWN0aXZlAAAAAAAAAAABAAAD6AAAAAY=",
+        "AAAAAAAAAAAAAAAPcmV2b2tlX3Nlc3Npb25zAAAAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAPc2V0X2luaGVyaXRhbmNlAAAAAAEAAAAAAAAABmNvbmZpZwAAAAAD6AAAB9AAAAARSW5oZXJpdGFuY2VDb25maWcAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAQcm9sbGJhY2tfdXBncmFkZQAAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAARY3JlYXRlX3N1YmFjY291bnQAAAAAAAACAAAAAAAAAARzYWx0AAAD7gAAACAAAAAAAAAABmNvbmZpZwAAAAAH0AAAABBTdWJhY2NvdW50Q29uZmlnAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAAAAAAARZ2V0X2ZyZWV6ZV9xdW9ydW0AAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAARc2V0X2ZyZWV6ZV9xdW9ydW0AAAAAAAABAAAAAAAAAAZxdW9ydW0AAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAASZ2V0X3NjaGVtYV92ZXJzaW9uAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAASZ2V0X3NpZ25hdHVyZV9oYXNoAAAAAAABAAAAAAAAAARoYXNoAAAD7gAAACAAAAABAAAD7gAAACA=",
+        "AAAAAAAAAAAAAAASZ2V0X3NpZ25lcl9vcHRpb25zAAAAAAABAAAAAAAAAApzaWduZXJfa2V5AAAAAAfQAAAACVNpZ25lcktleQAAAAAAAAEAAAPoAAAH0AAAAA1TaWduZXJPcHRpb25zAAAA",
+        "AAAAAAAAAAAAAAASaXNfdmFsaWRfc2lnbmF0dXJlAAAAAAACAAAAAAAAAARoYXNoAAAD7gAAACAAAAAAAAAACnNpZ25hdHVyZXMAAAAAB9AAAAAKU2lnbmF0dXJlcwAAAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAScmVjb25jaWxlX2NvdW50ZXJzAAAAAAABAAAAAAAAAAtzaWduZXJfa2V5cwAAAAPqAAAH0AAAAAlTaWduZXJLZXkAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAATZ2V0X3BlbmRpbmdfdXBncmFkZQAAAAAAAAAAAQAAA+gAAAfQAAAADlBlbmRpbmdVcGdyYWRlAAA=",
+        "AAAAAAAAAAAAAAATZ2V0X3NpZ25lcl9hY3Rpdml0eQAAAAABAAAAAAAAAApzaWduZXJfa2V5AAAAAAfQAAAACVNpZ25lcktleQAAAAAAAAEAAAPoAAAH0AAAAA5TaWduZXJBY3Rpdml0eQAA",
+        "AAAAAAAAAAAAAAATZ2V0X3NpZ25lcl9yZWdpc3RyeQAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAATZ2V0X3dlYl9hdXRoX2NvbmZpZwAAAAAAAAAAAQAAA+gAAAfQAAAADVdlYkF1dGhDb25maWcAAAA=",
+        "AAAAAAAAAAAAAAATaXNfc2lnbmVyX3N1c3BlbmRlZAAAAAABAAAAAAAAAApzaWduZXJfa2V5AAAAAAfQAAAACVNpZ25lcktleQAAAAAAAAEAAAPoAAAAAQ==",
+        "AAAAAAAAAAAAAAATc2V0X3NpZ25lcl9yZWdpc3RyeQAAAAABAAAAAAAAAAhyZWdpc3RyeQAAA+gAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAATc2V0X3dlYl9hdXRoX2NvbmZpZwAAAAABAAAAAAAAAAZjb25maWcAAAAAA+gAAAfQAAAADVdlYkF1dGhDb25maWcAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAUYXBwbHlfc2lnbmVyX2NoYW5nZXMAAAABAAAAAAAAAANvcHMAAAAD6gAAB9AAAAAIU2lnbmVyT3AAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAUZ2V0X3VwZ3JhZGVfZ3VhcmRpYW4AAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAUaXNfaW50ZW50X25vbmNlX3VzZWQAAAABAAAAAAAAAAVub25jZQAAAAAAAAYAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAUc2V0X3VwZ3JhZGVfZ3VhcmRpYW4AAAABAAAAAAAAAAhndWFyZGlhbgAAA+gAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAAWZ2V0X3Nlc3Npb25fZ2VuZXJhdGlvbgAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAWbWlncmF0ZV9sZWdhY3lfc2lnbmVycwAAAAAAAgAAAAAAAAALc2lnbmVyX2tleXMAAAAD6gAAB9AAAAAJU2lnbmVyS2V5AAAAAAAAAAAAAAlyZWZlcmVuY2UAAAAAAAfQAAAAD0xlZGdlclJlZmVyZW5jZQAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAXZ2V0X2xhc3Rfb3duZXJfYWN0aXZpdHkAAAAAAAAAAAEAAAPoAAAABg==",
+        "AAAABAAAAiFDb250cmFjdCBlcnJvcnMuCgpEZWxpYmVyYXRlbHkgcmVudW1iZXJlZCBmb3IgdGhlIHYxIGludGVyZmFjZSBzbyB0aGUgZXJyb3Igc3BhY2UgaXMgZGlzam9pbnQKZnJvbSB0aGUgbGVnYWN5IChwcmUtMS4wKSBjb250cmFjdCdzIDEtOSByYW5nZS4gQSBjbGllbnQgZGVjb2RpbmcgYW4gZXJyb3IKY29kZSA8IDEwMCBpcyB0YWxraW5nIHRvIGEgbGVnYWN5IHdhbGxldC4KClJhbmdlczoKLSAxMDAtMTA5OiBzaWduZXIgc3RvcmFnZSAvIG1hbmFnZW1lbnQKLSAxMTAtMTE5OiBhdXRoIChgX19jaGVja19hdXRoYCkgYW5kIHdhbGxldCBzZXR0aW5ncwotIDEyMC0xMjk6IFdlYkF1dGhuIChzZWNwMjU2cjEpIHZlcmlmaWNhdGlvbgotIDEzMC0xMzk6IHVwZ3JhZGVzCi0gMTQwLTE0OTogZXhlY3V0aW9uIChgZXhlY3V0ZWAsIGludGVudHMsIGZlZXMpCi0gMTUwLTE1OTogZnJlZXplIHRpbWVsb2NrIGFuZCBzdG9yYWdlIG1pZ3JhdGlvbiwgYWRkZWQgb25jZSAxMTAtMTE5IHdhcwpmdWxsCgpBIGNvZGUgaXMgbmV2ZXIgcmVhc3NpZ25lZCBvbmNlIHJlbGVhc2VkLgAAAAAAAAAAAAAFRXJyb3IAAAAAAAArAAAAOVRoZSByZXF1ZXN0ZWQgc2lnbmVyIGRvZXMgbm90IGV4aXN0IG9uIHRoaXMgc21hcnQgd2FsbGV0LgAAAAAAAA5TaWduZXJOb3RGb3VuZAAAAAAAZAAAAD5gYWRkX3NpZ25lcmAgd2FzIGNhbGxlZCB3aXRoIGEgc2lnbmVyIGtleSB0aGF0IGFscmVhZHkgZXhpc3RzLgAAAAAAE1NpZ25lckFscmVhZHlFeGlzdHMAAAAAZQAAAExUaGUgc2lnbmVyIGhhcyBleHBpcmVkLCBvciBpcyBhIHNlc3Npb24gc2lnbmVyIHJldm9rZWQgYnkKYHJldm9rZV9zZXNzaW9uc2AuAAAADVNpZ25lckV4cGlyZWQAAAAAAABmAAAAnVRoZSBvcGVyYXRpb24gd291bGQgcmVtb3ZlIG9yIGRlbW90ZSB0aGUgd2FsbGV0J3MgbGFzdCBkdXJhYmxlIGFkbWluCnNpZ25lci4gQWRkIG9yIHByb21vdGUgYSByZXBsYWNlbWVudCBmaXJzdCwgb3IgaW4gdGhlIHNhbWUKYGFwcGx5X3NpZ25lcl9jaGFuZ2VzYCBiYXRjaC4AAAAAAAAPTGFzdEFkbWluU2lnbmVyAAAAAGcAAACUVGhlIG9wZXJhdGlvbiB3b3VsZCBsZWF2ZSB0aGUgd2FsbGV0IHdpdGhvdXQgYSBkdXJhYmxlIHNpZ25lcgooYFBlcnNpc3RlbnRgLCBgU2lnbmVyRXhwaXJhdGlvbihOb25lKWAsIGFueSBsaW1pdHMpLiBBZGQgYSBkdXJhYmxlCnJlcGxhY2VtZW50IGZpcnN0LgAAAApMYXN0U2lnbmVyAAAAAABoAAAAZUEgU2VjcDI1NnIxIHNpZ25lcidzIHB1YmxpYyBrZXkgaXMgbm90IGEgdmFsaWQgU0VDLTEgZW5jb2RlZCBQLTI1Ngpwb2ludCwgY29tcHJlc3NlZCBvciB1bmNvbXByZXNzZWQuAAAAAAAAEEludmFsaWRQdWJsaWNLZXkAAABpAAAAQ1RoZSBzaWduZXIncyBgU2lnbmVyT3B0aW9uczo6dmFsaWRfYWZ0ZXJgIGhhcyBub3QgYmVlbiByZWFjaGVkIHlldC4AAAAAEVNpZ25lck5vdFlldFZhbGlkAAAAAAAAagAAAD9UaGUgc2lnbmVyIGlzIHN1c3BlbmRlZCAoYHN1c3BlbmRfc2lnbmVyYCkgdW50aWwgaXQgaXMgcmVzdW1lZC4AAAAAD1NpZ25lclN1c3BlbmRlZAAAAABrAAAAbFRoZSBzaWduZXIgaXMgdGhlIGluaGVyaXRhbmNlIGJlbmVmaWNpYXJ5IGFuZCB0aGUgb3duZXIgaGFzIG5vdCBiZWVuCmluYWN0aXZlIGZvciB0aGUgY29uZmlndXJlZCBwZXJpb2QgeWV0LgAAABJCZW5lZmljaWFyeURvcm1hbnQAAAAAAGwAAAA/YHNldF9pbmhlcml0YW5jZWAgd2FzIGNhbGxlZCB3aXRoIGFuIGluYWN0aXZpdHkgcGVyaW9kIG9mIHplcm8uAAAAABdJbnZhbGlkSW5hY3Rpdml0eVBlcmlvZAAAAABtAAAAUU5vIHNpZ25lciBpbiB0aGUgc2lnbmF0dXJlcyBtYXAgbWF5IGF1dGhvcml6ZSBvbmUgb2YgdGhlIHJlcXVlc3RlZAphdXRoIGNvbnRleHRzLgAAAAAAAA5NaXNzaW5nQ29udGV4dAAAAAAAbgAAAEFBIHNpZ25hdHVyZSdzIHZhcmlhbnQgZG9lcyBub3QgbWF0Y2ggdGhlIHN0b3JlZCBzaWduZXIgaXQgaXMgZm9yLgAAAAAAABlTaWduYXR1cmVLZXlWYWx1ZU1pc21hdGNoAAAAAAAAbwAAAGpUaGUgd2FsbGV0IGlzIGZyb3plbiBhbmQgYW4gYXV0aCBjb250ZXh0IGlzIG5laXRoZXIgYHVuZnJlZXplYCBub3IKc2lnbmVyIG1hbmFnZW1lbnQgb24gdGhlIHdhbGxldCBpdHNlbGYuAAAAAAAMV2FsbGV0RnJvemVuAAAAcAAAAFNUaGUgd2FsbGV0IGlzIGZyb3plbiBhbmQgZmV3ZXIgYWN0aXZlIGR1cmFibGUgYWRtaW5zIHNpZ25lZCB0aGFuIHRoZQpmcmVlemUgcXVvcnVtLgAAAAASRnJlZXplUXVvcnVtTm90TWV0AAAAAABxAAAANWBzZXRfZnJlZXplX3F1b3J1bWAgd2FzIGNhbGxlZCB3aXRoIGEgcXVvcnVtIG9mIHplcm8uAAAAAAAAE0ludmFsaWRGcmVlemVRdW9ydW0AAAAAcgAAAEBBIHNpZ25lcidzIG1ldGFkYXRhIGxhYmVsIGlzIGxvbmdlciB0aGFuIGBNQVhfU0lHTkVSX0xBQkVMX0xFTmAuAAAAFUludmFsaWRTaWduZXJNZXRhZGF0YQAAAAAAAHMAAAA7QSBgVHRsQ29uZmlnYCBydWxlIGhhcyBhIGB0aHJlc2hvbGRgIGFib3ZlIGl0cyBgZXh0ZW5kX3RvYC4AAAAAEEludmFsaWRUdGxDb25maWcAAAB0AAAAQmByZWNvbmNpbGVfY291bnRlcnNgIHdhcyBnaXZlbiB0aGUgc2FtZSBzaWduZXIga2V5IG1vcmUgdGhhbiBvbmNlLgAAAAAAEkR1cGxpY2F0ZVNpZ25lcktleQAAAAAAdQAAAEVgbWlncmF0ZWAgd2FzIGNhbGxlZCBvbiBzdG9yYWdlIGFscmVhZHkgYXQgdGhlIGNvZGUncyBzY2hlbWEgdmVyc2lvbi4AAAAAAAAPQWxyZWFkeU1pZ3JhdGVkAAAAAHYAAAAsQW4gYEFkZHJlc3NgIHNpZ25lciBuYW1lcyB0aGUgd2FsbGV0IGl0c2VsZi4AAAARU2VsZkFkZHJlc3NTaWduZXIAAAAAAAB3AAAAMmNsaWVudERhdGFKU09OIGV4Y2VlZHMgdGhlIDEwMjQgYnl0ZSBwYXJzZSBidWZmZXIuAAAAAAAWQ2xpZW50RGF0YUpzb25Ub29MYXJnZQAAAAAAeAAAAD9jbGllbnREYXRhSlNPTiBpcyBub3QgcGFyc2VhYmxlIEpTT04gb3IgbGFja3MgYSByZXF1aXJlZCBmaWVsZC4AAAAAGENsaWVudERhdGFKc29uUGFyc2VFcnJvcgAAAHkAAABEVGhlIGNsaWVudERhdGFKU09OIGNoYWxsZW5nZSBpcyBub3QgdGhlIGJhc2U2NHVybCBzaWduYXR1cmUgcGF5bG9hZC4AAAAgQ2xpZW50RGF0YUpzb25DaGFsbGVuZ2VJbmNvcnJlY3QAAAB6AAAALGNsaWVudERhdGFKU09OIGB0eXBlYCBpcyBub3QgIndlYmF1dGhuLmdldCIuAAAAE0ludmFsaWRXZWJBdXRoblR5cGUAAAAAewAAAENhdXRoZW50aWNhdG9yRGF0YSBpcyBzaG9ydGVyIHRoYW4gdGhlIFdlYkF1dGhuIG1pbmltdW0gb2YgMzcgYnl0ZXMuAAAAABhJbnZhbGlkQXV0aGVudGljYXRvckRhdGEAAAB8AAAAOVRoZSBhdXRoZW50aWNhdG9yIGRpZCBub3Qgc2V0IHRoZSBVc2VyIFByZXNlbnQgKFVQKSBmbGFnLgAAAAAAABRVc2VyUHJlc2VuY2VSZXF1aXJlZAAAAH0AAAAsYXV0aGVudGljYXRvckRhdGEgZXhjZWVkcyB0aGUgMTAyNCBieXRlIGNhcC4AAAAZQXV0aGVudGljYXRvckRhdGFUb29MYXJnZQAAAAAAAH4AAAAkTm8gdXBncmFkZSBpcyBhd2FpdGluZyBjb25maXJtYXRpb24uAAAAEVVwZ3JhZGVOb3RQZW5kaW5nAAAAAAAAggAAAEJUaGUgcGVuZGluZyB1cGdyYWRlIGhhcyBubyByb2xsYmFjayB0YXJnZXQgKHNlZSBgUGVuZGluZ1VwZ3JhZGVgKS4AAAAAABBOb1JvbGxiYWNrVGFyZ2V0AAAAgwAAADJUaGUgbmV3IGNvZGUncyBgY29uZmlybV91cGdyYWRlYCBzZWxmLXRlc3QgZmFpbGVkLgAAAAAAFVVwZ3JhZGVTZWxmVGVzdEZhaWxlZAAAAAAAAIQAAACCYGNvbmZpcm1fdXBncmFkZWAgd2FzIGNhbGxlZCBhZnRlciB0aGUgcGVuZGluZyB1cGdyYWRlJ3MgYGRlYWRsaW5lYC4KUm9sbCBpdCBiYWNrLCBvciB1cGdyYWRlIGFnYWluIHRvIHJlc3RhcnQgdGhlIGNvbmZpcm0gd2luZG93LgAAAAAADlVwZ3JhZGVFeHBpcmVkAAAAAACFAAAAZmByb2xsYmFja191cGdyYWRlYCB3YXMgY2FsbGVkIGFmdGVyIGBtaWdyYXRlYCBtb3ZlZCBzdG9yYWdlIHBhc3QgdGhlCnJvbGxiYWNrIHRhcmdldCdzIHNjaGVtYSB2ZXJzaW9uLgAAAAAAFlJvbGxiYWNrQWZ0ZXJNaWdyYXRpb24AAAAAAIYAAAAsQW4gYGV4ZWN1dGVgIGNhbGwgdGFyZ2V0cyB0aGUgd2FsbGV0IGl0c2VsZi4AAAAPRXhlY3V0ZVNlbGZDYWxsAAAAAIwAAAA6YGV4ZWN1dGVfaW50ZW50YCB3YXMgY2FsbGVkIGFmdGVyIHRoZSBpbnRlbnQncyBgZGVhZGxpbmVgLgAAAAAADUludGVudEV4cGlyZWQAAAAAAACNAAAAKFRoZSBpbnRlbnQncyBub25jZSB3YXMgYWxyZWFkeSBjb25zdW1lZC4AAAAPSW50ZW50Tm9uY2VVc2VkAAAAAI4AAABJVGhlIGZlZSB0b2tlbiBpcyBub3QgYWxsb3dlZCBieSB0aGUgd2FsbGV0J3MgYEZlZUNvbmZpZ2AsIG9yIGl0IGhhcwpub25lLgAAAAAAABJGZWVUb2tlbk5vdEFsbG93ZWQAAAAAAI8AAABAVGhlIGZlZSBpcyBuZWdhdGl2ZSBvciBhYm92ZSB0aGUgd2FsbGV0J3MgbWF4aW11bSBmb3IgaXRzIHRva2VuLgAAAApGZWVUb29IaWdoAAAAAACQAAAAIkEgYEZlZUNvbmZpZ2AgbWF4aW11bSBpcyBuZWdhdGl2ZS4AAAAAABBJbnZhbGlkRmVlQ29uZmlnAAAAkQAAADxUaGUgZGVhZGxpbmUgaXMgZnVydGhlciBhaGVhZCB0aGFuIGl0cyBub25jZSBjYW4gYmUgdHJhY2tlZC4AAAAUSW50ZW50RGVhZGxpbmVUb29GYXIAAACSAAAAWVRoZSB3YWxsZXQgbG9zdCBpdHMgZnJlZXplIHF1b3J1bSBhbmQgd2FzIGZyb3plbiBsZXNzIHRoYW4KYEZSRUVaRV9USU1FTE9DS2Agc2Vjb25kcyBhZ28uAAAAAAAAFEZyZWV6ZVRpbWVsb2NrQWN0aXZlAAAAlgAAADRgbWlncmF0ZWAgaGFzIG5vIHN0ZXAgZm9yIHRoZSBzdG9yZWQgc2NoZW1hIHZlcnNpb24uAAAAGFVuc3VwcG9ydGVkU2NoZW1hVmVyc2lvbgAAAJsAAAA6QSBsZWdhY3kgZW50cnkgaXMgbmVpdGhlciBhIHYxIG5vciBhIHByZS0xLjAgc2lnbmVyIHZhbHVlLgAAAAAAE0ludmFsaWRMZWdhY3lTaWduZXIAAAAAnAAAAFpBIGxlZ2FjeSBub24tZXhwaXJpbmcgZW50cnkgaGFzIGVtcHR5IGxpbWl0cy4gU2V0IGl0cyBsaW1pdHMgd2l0aApgdXBkYXRlX3NpZ25lcmAgaW5zdGVhZC4AAAAAABVBbWJpZ3VvdXNMZWdhY3lMaW1pdHMAAAAAAACd",
+        "AAAAAQAAAVJBIGNhbGwgc2lnbmVkIG9mZi1jaGFpbiBieSB0aGUgd2FsbGV0J3Mgc2lnbmVycywgZXhlY3V0YWJsZSBieSBhbnlvbmUKdGhyb3VnaCBgZXhlY3V0ZV9pbnRlbnRgIChlLmcuIGEgbGltaXQgb3JkZXIgb3Igc2NoZWR1bGVkIHBheW1lbnQgdGhhdCBhCmtlZXBlciBzdWJtaXRzIGxhdGVyKS4gVGhlIHNpZ25lcnMgc2lnbiBgZ2V0X2ludGVudF9oYXNoKGludGVudClgLgpgbm9uY2VgIGlzIGFueSB1bnVzZWQgdmFsdWU7IGBkZWFkbGluZWAgaXMgdGhlIGxhc3QgVU5JWCB0aW1lc3RhbXAsCmluY2x1c2l2ZSwgYXQgd2hpY2ggaXQgbWF5IGV4ZWN1dGUuIGBmZWVgIGlzIHNpZ25lZCB3aXRoIGl0LgAAAAAAAAAAAAZJbnRlbnQAAAAAAAYAAAAAAAAABGFyZ3MAAAPqAAAAAAAAAAAAAAAIY29udHJhY3QAAAATAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAAA2ZlZQAAAAfQAAAACUludGVudEZlZQAAAAAAAAAAAAAHZm5fbmFtZQAAAAARAAAAAAAAAAVub25jZQAAAAAAAAY=",
+        "AAAAAgAAARhGdWxsIHNpZ25lciBkZXNjcmlwdGlvbiB1c2VkIGJ5IGBfX2NvbnN0cnVjdG9yYCwgYGFkZF9zaWduZXJgIGFuZApgdXBkYXRlX3NpZ25lcmAuIFNlY3AyNTZyMSBjYXJyaWVzIHRoZSBXZWJBdXRobiBjcmVkZW50aWFsIGlkIChga2V5SWRgKQphbmQgYSBTRUMtMSBQLTI1NiBwdWJsaWMga2V5LCB1bmNvbXByZXNzZWQgKDY1IGJ5dGVzKSBvciBjb21wcmVzc2VkICgzMwpieXRlcykuIEFkZHJlc3MgZGVsZWdhdGVzIHRvIGFub3RoZXIgYWNjb3VudCwgbmV2ZXIgdGhlIHdhbGxldCBpdHNlbGYuAAAAAAAAAAZTaWduZXIAAAAAAAQAAAABAAAAAAAAAAZQb2xpY3kAAAAAAAUAAAATAAAH0AAAABBTaWduZXJFeHBpcmF0aW9uAAAH0AAAAAxTaWduZXJMaW1pdHMAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAfQAAAADVNpZ25lck9wdGlvbnMAAAAAAAABAAAAAAAAAAdFZDI1NTE5AAAAAAUAAAPuAAAAIAAAB9AAAAAQU2lnbmVyRXhwaXJhdGlvbgAAB9AAAAAMU2lnbmVyTGltaXRzAAAH0AAAAA1TaWduZXJTdG9yYWdlAAAAAAAH0AAAAA1TaWduZXJPcHRpb25zAAAAAAAAAQAAAAAAAAAJU2VjcDI1NnIxAAAAAAAABgAAAA4AAAAOAAAH0AAAABBTaWduZXJFeHBpcmF0aW9uAAAH0AAAAAxTaWduZXJMaW1pdHMAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAfQAAAADVNpZ25lck9wdGlvbnMAAAAAAAABAAAAAAAAAAdBZGRyZXNzAAAAAAUAAAATAAAH0AAAABBTaWduZXJFeHBpcmF0aW9uAAAH0AAAAAxTaWduZXJMaW1pdHMAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAfQAAAADVNpZ25lck9wdGlvbnMAAAA=",
+        "AAAAAQAAAQZPbmUgVFRMIGV4dGVuc2lvbiBydWxlLCBpbiBsZWRnZXJzOiBhbiBlbnRyeSB3aG9zZSByZW1haW5pbmcgVFRMIGlzCmJlbG93IGB0aHJlc2hvbGRgIGlzIGV4dGVuZGVkIHRvIGBleHRlbmRfdG9gIChib3RoIGNsYW1wZWQgdG8gdGhlCm5ldHdvcmsncyBgbWF4X3R0bGApOyBvdGhlcndpc2UgaXQgaXMgbGVmdCBhbG9uZSwgc28gYGV4dGVuZF90byAtCnRocmVzaG9sZGAgYm91bmRzIGhvdyBvZnRlbiB0aGUgZXh0ZW5zaW9uIGlzIGFjdHVhbGx5IHdyaXR0ZW4uAAAAAAAAAAAAB1R0bFJ1bGUAAAAAAgAAAAAAAAAJZXh0ZW5kX3RvAAAAAAAABAAAAAAAAAAJdGhyZXNob2xkAAAAAAAABA==",
+        "AAAAAgAAAOlPbmUgc3RlcCBvZiBhbiBgYXBwbHlfc2lnbmVyX2NoYW5nZXNgIGJhdGNoLiBFYWNoIHN0ZXAgaGFzIGV4YWN0bHkgdGhlCnNlbWFudGljcyBvZiB0aGUgbWF0Y2hpbmcgc2luZ2xlLXNpZ25lciBlbnRyeXBvaW50IChgYWRkX3NpZ25lcmAsCmB1cGRhdGVfc2lnbmVyYCwgYHJlbW92ZV9zaWduZXJgKSwgYXBwbGllZCBpbiBvcmRlciBhZ2FpbnN0IHRoZSBzdGF0ZSBsZWZ0CmJ5IHRoZSBwcmV2aW91cyBzdGVwLgAAAAAAAAAAAAAIU2lnbmVyT3AAAAADAAAAAQAAAAAAAAADQWRkAAAAAAEAAAfQAAAABlNpZ25lcgAAAAAAAQAAAAAAAAAGVXBkYXRlAAAAAAABAAAH0AAAAAZTaWduZXIAAAAAAAEAAAAAAAAABlJlbW92ZQAAAAAAAQAAB9AAAAAJU2lnbmVyS2V5AAAA",
+        "AAAAAQAAAIhXaGljaCB0b2tlbnMgdGhlIHdhbGxldCBwYXlzIGludGVudCBmZWVzIGluIChgc2V0X2ZlZV9jb25maWdgKSwgZWFjaAp3aXRoIHRoZSBtYXhpbXVtIGZlZSBwZXIgaW50ZW50LiBBIHdhbGxldCB3aXRob3V0IG9uZSBwYXlzIG5vIGZlZXMuAAAAAAAAAAlGZWVDb25maWcAAAAAAAABAAAAAAAAAAhtYXhfZmVlcwAAA+wAAAATAAAACw==",
+        "AAAAAgAAAC9UaGUgZmVlIGFuIGBJbnRlbnRgIHBheXMgaXRzIHN1Ym1pdHRlciwgaWYgYW55LgAAAAAAAAAACUludGVudEZlZQAAAAAAAAIAAAAAAAAAAAAAAAROb25lAAAAAQAAAAAAAAAFVG9rZW4AAAAAAAABAAAH0AAAAApGZWVQYXltZW50AAA=",
+        "AAAAAgAAAUJBIHNpZ25hdHVyZSBlbnRyeSBpbiB0aGUgc2lnbmF0dXJlcyBtYXAuIGBQb2xpY3lgIGNhcnJpZXMgbm8gc2lnbmF0dXJlCm1hdGVyaWFsOiBpbmNsdXNpb24gb2YgdGhlIHBvbGljeSBrZXkgYXV0aG9yaXplcyBhbiBvbi1jaGFpbiBgcG9saWN5X19gCmNoZWNrIGluc3RlYWQuIE5vciBkb2VzIGBBZGRyZXNzYDogdGhlIGFkZHJlc3MgbXVzdCBhdXRob3JpemUgdGhlCndhbGxldCdzIGBfX2NoZWNrX2F1dGhgIHdpdGggYGFyZ3MgPSBbc2lnbmF0dXJlIHBheWxvYWRdYCBpbiBpdHMgb3duCmF1dGhvcml6YXRpb24gZW50cnkgb2YgdGhlIHNhbWUgdHJhbnNhY3Rpb24uAAAAAAAAAAAACVNpZ25hdHVyZQAAAAAAAAQAAAAAAAAAAAAAAAZQb2xpY3kAAAAAAAEAAAAAAAAAB0VkMjU1MTkAAAAAAQAAA+4AAABAAAAAAQAAAAAAAAAJU2VjcDI1NnIxAAAAAAAAAQAAB9AAAAASU2VjcDI1NnIxU2lnbmF0dXJlAAAAAAAAAAAAAAAAAAdBZGRyZXNzAA==",
+        "AAAAAgAAAFlTdG9yYWdlIGtleSBpZGVudGlmeWluZyBhIHNpZ25lci4gU2VjcDI1NnIxIGNhcnJpZXMgdGhlIFdlYkF1dGhuCmNyZWRlbnRpYWwgaWQgKGBrZXlJZGApLgAAAAAAAAAAAAAJU2lnbmVyS2V5AAAAAAAABAAAAAEAAAAAAAAABlBvbGljeQAAAAAAAQAAABMAAAABAAAAAAAAAAdFZDI1NTE5AAAAAAEAAAPuAAAAIAAAAAEAAAAAAAAACVNlY3AyNTZyMQAAAAAAAAEAAAAOAAAAAQAAAAAAAAAHQWRkcmVzcwAAAAABAAAAEw==",
+        "AAAAAgAAAIdTdG9yZWQgc2lnbmVyIHZhbHVlLiBTZWNwMjU2cjEgY2FycmllcyB0aGUgU0VDLTEgdW5jb21wcmVzc2VkIHB1YmxpYyBrZXkKKHZhbGlkYXRlZCwgYW5kIGRlY29tcHJlc3NlZCBpZiBpdCB3YXMgcmVnaXN0ZXJlZCBjb21wcmVzc2VkKS4AAAAAAAAAAAlTaWduZXJWYWwAAAAAAAAEAAAAAQAAAAAAAAAGUG9saWN5AAAAAAACAAAH0AAAABBTaWduZXJFeHBpcmF0aW9uAAAH0AAAAAxTaWduZXJMaW1pdHMAAAABAAAAAAAAAAdFZDI1NTE5AAAAAAIAAAfQAAAAEFNpZ25lckV4cGlyYXRpb24AAAfQAAAADFNpZ25lckxpbWl0cwAAAAEAAAAAAAAACVNlY3AyNTZyMQAAAAAAAAMAAAPuAAAAQQAAB9AAAAAQU2lnbmVyRXhwaXJhdGlvbgAAB9AAAAAMU2lnbmVyTGltaXRzAAAAAQAAAAAAAAAHQWRkcmVzcwAAAAACAAAH0AAAABBTaWduZXJFeHBpcmF0aW9uAAAH0AAAAAxTaWduZXJMaW1pdHM=",
+        "AAAAAQAAAJdUaGUgd2FsbGV0J3MgcmVudCBzdHJhdGVneSAoYHNldF90dGxfY29uZmlnYCkuIGBhdXRoYCBhcHBsaWVzIHRvCmBfX2NoZWNrX2F1dGhgIGFuZCBgYnVtcGA7IGBtdXRhdGlvbmAgYXBwbGllcyB0byBldmVyeSB3YWxsZXQtYXV0aG9yaXplZApzdGF0ZSBjaGFuZ2UuAAAAAAAAAAAJVHRsQ29uZmlnAAAAAAAAAgAAAAAAAAAEYXV0aAAAB9AAAAALVHRsU2NoZWR1bGUAAAAAAAAAAAhtdXRhdGlvbgAAB9AAAAALVHRsU2NoZWR1bGUA",
+        "AAAAAQAAAIlBIGZlZSBpbiBhIFNFUC00MSBgdG9rZW5gLCBwYWlkIGJ5IHRoZSB3YWxsZXQgdG8gYHJlY2lwaWVudGAgKHRoZQpyZWxheWVyIHRoZSBpbnRlbnQgaXMgc2lnbmVkIGZvcikgdG8gcmVpbWJ1cnNlIHRoZSBuZXR3b3JrIGZlZSBpdCBwYWlkLgAAAAAAAAAAAAAKRmVlUGF5bWVudAAAAAAAAwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABM=",
         "AAAAAQAAANNUaGUgYF9fY2hlY2tfYXV0aGAgc2lnbmF0dXJlIG9iamVjdDogYSBtYXAgb2Ygc2lnbmVyIGtleXMgdG8gc2lnbmF0dXJlcy4KTWFwIG9yZGVyaW5nIGlzIHRoZSBob3N0J3MgU2NWYWwgb3JkZXJpbmcuIEVWRVJZIGVudHJ5IG11c3QgdmVyaWZ5IChwYXNzCjIgb2YgYF9fY2hlY2tfYXV0aGApIOKAlCBpbmNsdWRlIG9ubHkgc2lnbmF0dXJlcyB0aGF0IGFyZSBuZWVkZWQuAAAAAAAAAAAKU2lnbmF0dXJlcwAAAAAAAQAAAAAAAAABMAAAAAAAA+wAAAfQAAAACVNpZ25lcktleQAAAAAAB9AAAAAJU2lnbmF0dXJlAAAA",
-        "AAAAAQAABABSZXN0cmljdGlvbnMgb24gd2hpY2ggYXV0aCBjb250ZXh0cyBhIHNpZ25lciBtYXkgYXV0aG9yaXplLgoKLSBgTm9uZWA6IHVubGltaXRlZC4gVGhlIHNpZ25lciBjYW4gYXV0aG9yaXplIGFueXRoaW5nLCBpbmNsdWRpbmcKYENyZWF0ZUNvbnRyYWN0KmAgKGRlcGxveSkgY29udGV4dHMgYW5kIHRoaXMgd2FsbGV0J3Mgb3duIGFkbWluCmZ1bmN0aW9ucy4KLSBgU29tZShlbXB0eSBtYXApYDogTk8gcGVybWlzc2lvbnMgKGZhaWwtY2xvc2VkKS4gVGhlIHNpZ25lciBjYW4gYXV0aG9yaXplCm5vdGhpbmcgZXhjZXB0IHJlbW92aW5nIGl0c2VsZiAoc2VlIGJlbG93KS4gdjEgYnJlYWtpbmcgY2hhbmdlOiBwcmUtMS4wCmFuIGVtcHR5IG1hcCBtZWFudCB1bmxpbWl0ZWQsIGxlYXZpbmcgdHdvIHVubGltaXRlZCBlbmNvZGluZ3MgYW5kIG5vCiJub25lIiBlbmNvZGluZy4KLSBgU29tZSh7YWRkcmVzcyAtPiBOb25lfSlgOiB0aGUgc2lnbmVyIG1heSBhdXRob3JpemUgYW55IGludm9jYXRpb24gb2YKY29udHJhY3QgYGFkZHJlc3NgLCB3aXRoIG5vIGNvLXNpZ25lcnMgcmVxdWlyZWQuCi0gYFNvbWUoe2FkZHJlc3MgLT4gU29tZShba2V5c10pfSlgOiB0aGUgc2lnbmVyIG1heSBhdXRob3JpemUgaW52b2NhdGlvbnMKb2YgY29udHJhY3QgYGFkZHJlc3NgIG9ubHkgaWYgZXZlcnkgbGlzdGVkIGtleSBhbHNvIEFQUFJPVkVTLiBUaGUgbGlzdGVkCmtleXMgYXJlIHJlcXVpcmVkIENPLVNJR05FUlMuCgojIyBSZXF1aXJlZCBjby1zaWduZXJzIGFyZSBzY29wZS1pbmRlcGVuZGVudCBhcHByb3ZlcnMgKGF1ZGl0IEZJWC01KQoKQSByZXF1aXJlZCBjby1zaWduZXIncyBPV04gYFNpZ25lckxpbWl0c2AgZG8gTk9UIGNvbnN0cmFpbiBpdHMgY28tc2lnbmVyCnJvbGUg4oCUIGEga2V5J3MgbGltaXRzIGdvdmVybiBvbmx5IGl0cyBJTkRFUEVOREVOVCBhdXRob3JpdHkgKHdoZXRoZXIgaXQgY2FuCmNvdmVyIGEgY29udGV4dCBvbiBpdHMgb3duKS4gVGhpcyBpcyBzeW1tZXRyaWMgYWNyb3NzIGtleSBraW5kczoKCi0gAAAAAAAAAAxTaWduZXJMaW1pdHMAAAABAAAAAAAAAAEwAAAAAAAD6AAAA+wAAAATAAAD6AAAA+oAAAfQAAAACVNpZ25lcktleQAAAA==",
+        "AAAAAQAAAKdUaGUgYFR0bFJ1bGVgcyBhcHBsaWVkIG9uIG9uZSBraW5kIG9mIGNhbGwsIHBlciBjbGFzcyBvZiBlbnRyeS4KYHBlcnNpc3RlbnRgL2B0ZW1wb3JhcnlgIGNvdmVyIHNpZ25lciBlbnRyaWVzIG9mIHRoYXQgZHVyYWJpbGl0eSB0b2dldGhlcgp3aXRoIHRoZWlyIGNvbXBhbmlvbiBlbnRyaWVzLgAAAAAAAAAAC1R0bFNjaGVkdWxlAAAAAAMAAAAAAAAACGluc3RhbmNlAAAH0AAAAAdUdGxSdWxlAAAAAAAAAAAKcGVyc2lzdGVudAAAAAAH0AAAAAdUdGxSdWxlAAAAAAAAAAAJdGVtcG9yYXJ5AAAAAAAH0AAAAAdUdGxSdWxlAA==",
+        "AAAAAQAAAt1SZXN0cmljdGlvbnMgb24gd2hpY2ggYXV0aCBjb250ZXh0cyBhIHNpZ25lciBtYXkgYXV0aG9yaXplLgoKLSBgTm9uZWA6IHVubGltaXRlZC4gVGhlIHNpZ25lciBjYW4gYXV0aG9yaXplIGFueXRoaW5nLCBpbmNsdWRpbmcKYENyZWF0ZUNvbnRyYWN0KmAgKGRlcGxveSkgY29udGV4dHMgYW5kIHRoaXMgd2FsbGV0J3Mgb3duIGFkbWluCmZ1bmN0aW9ucy4KLSBgU29tZShlbXB0eSBtYXApYDogTk8gcGVybWlzc2lvbnMgKGZhaWwtY2xvc2VkKS4gVGhlIHNpZ25lciBjYW4gYXV0aG9yaXplCm5vdGhpbmcgZXhjZXB0IHJlbW92aW5nIGl0c2VsZiAoc2VlIGJlbG93KS4gdjEgYnJlYWtpbmcgY2hhbmdlOiBwcmUtMS4wCmFuIGVtcHR5IG1hcCBtZWFudCB1bmxpbWl0ZWQsIGxlYXZpbmcgdHdvIHVubGltaXRlZCBlbmNvZGluZ3MgYW5kIG5vCiJub25lIiBlbmNvZGluZy4KLSBgU29tZSh7YWRkcmVzcyAtPiBOb25lfSlgOiB0aGUgc2lnbmVyIG1heSBhdXRob3JpemUgYW55IGludm9jYXRpb24gb2YKY29udHJhY3QgYGFkZHJlc3NgLCB3aXRoIG5vIGNvLXNpZ25lcnMgcmVxdWlyZWQuCi0gYFNvbWUoe2FkZHJlc3MgLT4gU29tZShba2V5c10pfSlgOiB0aGUgc2lnbmVyIG1heSBhdXRob3JpemUgaW52b2NhdGlvbnMKb2YgY29udHJhY3QgYGFkZHJlc3NgIG9ubHkgaWYgZXZlcnkgbGlzdGVkIGtleSBhbHNvIEFQUFJPVkVTLiBUaGUgbGlzdGVkCmtleXMgYXJlIHJlcXVpcmVkIENPLVNJR05FUlMuAAAAAAAAAAAAAAxTaWduZXJMaW1pdHMAAAABAAAAAAAAAAEwAAAAAAAD6AAAA+wAAAATAAAD6AAAA+oAAAfQAAAACVNpZ25lcktleQAAAA==",
+        "AAAAAQAAAHtQZXItc2lnbmVyIHNldHRpbmdzIGJleW9uZCB0aGUgc3RvcmVkIGBTaWduZXJWYWxgLCBzdXBwbGllZCBhcyB0aGUgbGFzdApmaWVsZCBvZiBldmVyeSBgU2lnbmVyYC4gVGhlIGRlZmF1bHQgaXMgbm90IHN0b3JlZC4AAAAAAAAAAA1TaWduZXJPcHRpb25zAAAAAAAABAAAAHNUaGUgc2lnbmVyIG1heSBhdXRob3JpemUgdGhlIHdhbGxldCdzIG93biBgZnJlZXplYCBjYWxsIGJ5IGl0c2VsZiwKd2hhdGV2ZXIgaXRzIGBTaWduZXJMaW1pdHNgLCB3aGlsZSBpdCBpcyBhY3RpdmUuAAAAAApjYW5fZnJlZXplAAAAAAABAAAAx1RoZSBzaWduZXIgbWF5IGF1dGhvcml6ZSBhIFNFUC00NSBgd2ViX2F1dGhfdmVyaWZ5KGFyZ3MpYCBjYWxsIGZvcgp0aGlzIHdhbGxldCBieSBpdHNlbGYsIHdoYXRldmVyIGl0cyBgU2lnbmVyTGltaXRzYCwgaWYgdGhlIHdhbGxldCdzCmBXZWJBdXRoQ29uZmlnYCBsaXN0cyB0aGUgd2ViLWF1dGggY29udHJhY3QgZm9yIGl0cyBob21lIGRvbWFpbi4AAAAADGNhbl93ZWJfYXV0aAAAAAEAAABiRGlzcGxheSBtZXRhZGF0YSBmb3Igd2FsbGV0cyBhbmQgaW5kZXhlcnMgKGV2ZXJ5IGZpZWxkIG9wdGlvbmFsKS4KTmV2ZXIgZXZhbHVhdGVkIGJ5IHRoZSBjb250cmFjdC4AAAAAAAhtZXRhZGF0YQAAB9AAAAAOU2lnbmVyTWV0YWRhdGEAAAAAAK9PcHRpb25hbCB2YWxpZGl0eSBzdGFydCBhcyBhIFVOSVggdGltZXN0YW1wIGluIHNlY29uZHMsIGluY2x1c2l2ZS4KVW50aWwgdGhlbiB0aGUgc2lnbmVyIGlzIHNraXBwZWQgZm9yIGNvdmVyYWdlIGFuZCByZWplY3RlZAooYEVycm9yOjpTaWduZXJOb3RZZXRWYWxpZGApIGluIGEgc2lnbmF0dXJlcyBtYXAuAAAAAAt2YWxpZF9hZnRlcgAAAAPoAAAABg==",
         "AAAAAgAAAIRXaGljaCBkdXJhYmlsaXR5IGEgc2lnbmVyIGVudHJ5IGlzIHN0b3JlZCB1bmRlci4gQXQgbW9zdCBvbmUgZW50cnkgZXhpc3RzCnBlciBzaWduZXIga2V5OyBsb29rdXBzIGNoZWNrIFRlbXBvcmFyeSBiZWZvcmUgUGVyc2lzdGVudC4AAAAAAAAADVNpZ25lclN0b3JhZ2UAAAAAAAACAAAAAAAAAAAAAAAKUGVyc2lzdGVudAAAAAAAAAAAAAAAAAAJVGVtcG9yYXJ5AAAA",
-        "AAAAAQAAAY5PcHRpb25hbCBleHBpcmF0aW9uIGZvciBhIHNpZ25lciBhcyBhIFVOSVggdGltZXN0YW1wIGluIHNlY29uZHMsIElOQ0xVU0lWRToKdGhlIHNpZ25lciBpcyB2YWxpZCB3aGlsZSBgbGVkZ2VyIHRpbWVzdGFtcCA8PSBleHBpcmF0aW9uYCBhbmQgZXhwaXJlZCBvbmNlCmBsZWRnZXIgdGltZXN0YW1wID4gZXhwaXJhdGlvbmAuIGBOb25lYCBuZXZlciBleHBpcmVzLgoKdjEgYnJlYWtpbmcgY2hhbmdlOiB0aGlzIHdhcyBhIGxlZGdlciBzZXF1ZW5jZSBudW1iZXIgcHJlLTEuMC4gVGltZXN0YW1wcwpkb24ndCBkcmlmdCB3aXRoIGNoYW5nZXMgdG8gbGVkZ2VyIGNsb3NlIHRpbWUgKGUuZy4gQ0FQLTAwNzAgZHluYW1pYwp0aW1pbmcpLCB3aGljaCBsZWRnZXItc2VxdWVuY2UgZXhwaXJhdGlvbnMgZGlkLgAAAAAAAAAAABBTaWduZXJFeHBpcmF0aW9uAAAAAQAAAAAAAAABMAAAAAAAA+gAAAAG",
+        "AAAAAgAAAEhPbmUgc2V0dGluZyBgX19jb25zdHJ1Y3RvcmAgYXBwbGllcywgdmFsaWRhdGVkIGV4YWN0bHkgYXMgYnkgaXRzIHNldHRlci4AAAAAAAAADVdhbGxldFNldHRpbmcAAAAAAAAGAAAAAQAAABdBcyBgc2V0X2ZyZWV6ZV9xdW9ydW1gLgAAAAAMRnJlZXplUXVvcnVtAAAAAQAAAAQAAAABAAAAFEFzIGBzZXRfdHRsX2NvbmZpZ2AuAAAACVR0bENvbmZpZwAAAAAAAAEAAAfQAAAACVR0bENvbmZpZwAAAAAAAAEAAABAQXMgYHNldF9pbmhlcml0YW5jZWA7IHRoZSBiZW5lZmljaWFyeSBtdXN0IGJlIGFuIGluaXRpYWwgc2lnbmVyLgAAAAtJbmhlcml0YW5jZQAAAAABAAAH0AAAABFJbmhlcml0YW5jZUNvbmZpZwAAAAAAAAEAAAAUQXMgYHNldF9mZWVfY29uZmlnYC4AAAAJRmVlQ29uZmlnAAAAAAAAAQAAB9AAAAAJRmVlQ29uZmlnAAAAAAAAAQAAABlBcyBgc2V0X3NpZ25lcl9yZWdpc3RyeWAuAAAAAAAADlNpZ25lclJlZ2lzdHJ5AAAAAAABAAAAEwAAAAEAAAAZQXMgYHNldF93ZWJfYXV0aF9jb25maWdgLgAAAAAAAA1XZWJBdXRoQ29uZmlnAAAAAAAAAQAAB9AAAAANV2ViQXV0aENvbmZpZwAAAA==",
+        "AAAAAQAAAJdUaGUgU0VQLTQ1IHdlYi1hdXRoIGNvbnRyYWN0cyBhIGBjYW5fd2ViX2F1dGhgIHNpZ25lciBtYXkgbG9nIGluIHRvCihgc2V0X3dlYl9hdXRoX2NvbmZpZ2ApLCBlYWNoIHdpdGggdGhlIGhvbWUgZG9tYWluIG9mIHRoZSBhbmNob3IgdGhhdApwdWJsaXNoZXMgaXQuAAAAAAAAAAANV2ViQXV0aENvbmZpZwAAAAAAAAEAAAAAAAAACWNvbnRyYWN0cwAAAAAAA+wAAAATAAAAEA==",
+        "AAAAAQAAARRBbiBgdXBncmFkZWAgYXdhaXRpbmcgYGNvbmZpcm1fdXBncmFkZWAgKGBnZXRfcGVuZGluZ191cGdyYWRlYCkuIGBoYXNoYAppcyB0aGUgd2FzbSBub3cgcnVubmluZzsgYHJvbGxiYWNrX3VwZ3JhZGVgIHJldmVydHMgdG8gYHJvbGxiYWNrX2hhc2hgLAp0aGUgbGFzdCBjb25maXJtZWQgd2FzbSwgd2hpbGUgc3RvcmFnZSBpcyBzdGlsbCBhdCBgc2NoZW1hX3ZlcnNpb25gLgpgZGVhZGxpbmVgIGVuZHMgdGhlIGNvbmZpcm0gd2luZG93OyBlYWNoIGB1cGdyYWRlYCByZXN0YXJ0cyBpdC4AAAAAAAAADlBlbmRpbmdVcGdyYWRlAAAAAAAEAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAABGhhc2gAAAPuAAAAIAAAAAAAAAANcm9sbGJhY2tfaGFzaAAAAAAAA+gAAAPuAAAAIAAAAAAAAAAOc2NoZW1hX3ZlcnNpb24AAAAAAAQ=",
+        "AAAAAQAAAIpVc2FnZSByZWNvcmQgb2YgYSBzaWduZXIgKGBnZXRfc2lnbmVyX2FjdGl2aXR5YCksIHVwZGF0ZWQgd2hlbiBpdCBzaWducwphIHN1Y2Nlc3NmdWwgYF9fY2hlY2tfYXV0aGAsIGF0IG1vc3Qgb25jZSBwZXIgYEFDVElWSVRZX0lOVEVSVkFMYC4AAAAAAAAAAAAOU2lnbmVyQWN0aXZpdHkAAAAAAAIAAACWVU5JWCB0aW1lc3RhbXAgaW4gc2Vjb25kcyBvZiB0aGUgbGFzdCByZWNvcmRlZCB1c2UsIGF0IG1vc3QKYEFDVElWSVRZX0lOVEVSVkFMYCBiZWhpbmQgdGhlIGxhc3Qgc3VjY2Vzc2Z1bCBvbmU7IGAwYCBpZiB0aGUgc2lnbmVyCmhhcyBuZXZlciBiZWVuIHVzZWQuAAAAAAAJbGFzdF91c2VkAAAAAAAABgAAAH1OdW1iZXIgb2YgcmVjb3JkZWQgdXNlcyAoc2F0dXJhdGluZyk6IHN1Y2Nlc3NmdWwgYXV0aG9yaXphdGlvbnMgYXQKbGVhc3QgYEFDVElWSVRZX0lOVEVSVkFMYCBhZnRlciB0aGUgcHJldmlvdXMgcmVjb3JkZWQgb25lLgAAAAAAAAl1c2VfY291bnQAAAAAAAAE",
+        "AAAAAQAAALdIdW1hbi1yZWFkYWJsZSBkZXNjcmlwdGlvbiBvZiBhIHNpZ25lciAoYFNpZ25lck9wdGlvbnM6Om1ldGFkYXRhYCksIHNvCmNsaWVudHMgYW5kIGluZGV4ZXJzIGNhbiByZW5kZXIgIkFsaWNlJ3MgaVBob25lIiBpbnN0ZWFkIG9mIGEgY3JlZGVudGlhbAppZC4gT25seSB0aGUgbGFiZWwncyBsZW5ndGggaXMgY2hlY2tlZC4AAAAAAAAAAA5TaWduZXJNZXRhZGF0YQAAAAAAAwAAAEBXaGVuIHRoZSBjcmVkZW50aWFsIHdhcyBjcmVhdGVkLCBhcyBhIFVOSVggdGltZXN0YW1wIGluIHNlY29uZHMuAAAACmNyZWF0ZWRfYXQAAAAAA+gAAAAGAAAAQURldmljZS10eXBlIHRhZywgZS5nLiBgaXBob25lYCwgYGFuZHJvaWRgLCBgeXViaWtleWAgb3IgYGxlZGdlcmAuAAAAAAAABmRldmljZQAAAAAD6AAAABEAAABJRGlzcGxheSBuYW1lLCBhdCBtb3N0IGBNQVhfU0lHTkVSX0xBQkVMX0xFTmAgYnl0ZXMgKFVURi04IGJ5CmNvbnZlbnRpb24pLgAAAAAAAAVsYWJlbAAAAAAAA+gAAAAQ",
+        "AAAAAQAAAOtXYWxsZXQgc2V0dGluZ3MgYXBwbGllZCBieSBgX19jb25zdHJ1Y3RvcmAsIHNvIGEgd2FsbGV0IGlzIGJvcm4KY29uZmlndXJlZCBpbnN0ZWFkIG9mIG5lZWRpbmcgYW4gYXV0aG9yaXplZCBmb2xsb3ctdXAgcGVyIHNldHRpbmcuClNldHRpbmdzIGFyZSBhcHBsaWVkIGluIG9yZGVyLCBhcyBzdWNjZXNzaXZlIHNldHRlciBjYWxscyB3b3VsZCBiZTsgb25lCm5vdCBsaXN0ZWQgc3RheXMgYXQgaXRzIGRlZmF1bHQuAAAAAAAAAAAOV2FsbGV0U2V0dGluZ3MAAAAAAAEAAAAAAAAAATAAAAAAAAPqAAAH0AAAAA1XYWxsZXRTZXR0aW5nAAAA",
+        "AAAAAQAAAJVBIGxlZGdlciBhbmQgdGhlIFVOSVggdGltZXN0YW1wIGl0IGNsb3NlZCBhdCwgc3VwcGxpZWQgdG8KYG1pZ3JhdGVfbGVnYWN5X3NpZ25lcnNgIHRvIGNvbnZlcnQgcHJlLTEuMCBsZWRnZXItc2VxdWVuY2UgZXhwaXJhdGlvbnMKaW50byB2MSB0aW1lc3RhbXBzLgAAAAAAAAAAAAAPTGVkZ2VyUmVmZXJlbmNlAAAAAAIAAAAAAAAACHNlcXVlbmNlAAAABAAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAM5PcHRpb25hbCBleHBpcmF0aW9uIGZvciBhIHNpZ25lciBhcyBhIFVOSVggdGltZXN0YW1wIGluIHNlY29uZHMsIElOQ0xVU0lWRToKdGhlIHNpZ25lciBpcyB2YWxpZCB3aGlsZSBgbGVkZ2VyIHRpbWVzdGFtcCA8PSBleHBpcmF0aW9uYCBhbmQgZXhwaXJlZCBvbmNlCmBsZWRnZXIgdGltZXN0YW1wID4gZXhwaXJhdGlvbmAuIGBOb25lYCBuZXZlciBleHBpcmVzLgAAAAAAAAAAABBTaWduZXJFeHBpcmF0aW9uAAAAAQAAAAAAAAABMAAAAAAAA+gAAAAG",
+        "AAAAAQAAAPZBIHN1YmFjY291bnQgdG8gZGVwbG95IHdpdGggYGNyZWF0ZV9zdWJhY2NvdW50YDogdGhlIHdhc20gaXQgcnVucyBhbmQgaXRzCnNpZ25lcnMgYmVzaWRlcyB0aGUgcGFyZW50LCBlLmcuIGEgc3BlbmRpbmcga2V5IGxpbWl0ZWQgdG8gb25lIHRva2VuLgpUaGUgcGFyZW50IHdhbGxldCBpcyBhZGRlZCBmaXJzdCwgYXMgYW4gdW5saW1pdGVkIGR1cmFibGUgYEFkZHJlc3NgCnNpZ25lciwgc28gYHNpZ25lcnNgIG1heSBiZSBlbXB0eS4AAAAAAAAAAAAQU3ViYWNjb3VudENvbmZpZwAAAAIAAAAAAAAAB3NpZ25lcnMAAAAD6gAAB9AAAAAGU2lnbmVyAAAAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAg",
+        "AAAAAQAAAQ9Jbmhlcml0YW5jZSAoImRlYWQgbWFuJ3Mgc3dpdGNoIikgY29uZmlndXJhdGlvbiwgc2V0IHdpdGgKYHNldF9pbmhlcml0YW5jZWAuCgpgYmVuZWZpY2lhcnlgIGlzIGEgc3RvcmVkIHNpZ25lciB0aGF0IHN0YXlzIGRvcm1hbnQgd2hpbGUgdGhlIG93bmVyIGlzCmFjdGl2ZSwgYW5kIGJlY29tZXMgYW4gdW5saW1pdGVkIGFkbWluIG9uY2Ugbm8gb3duZXIgYWN0aXZpdHkgaGFzIGJlZW4KcmVjb3JkZWQgZm9yIG1vcmUgdGhhbiBgaW5hY3Rpdml0eV9wZXJpb2RgIHNlY29uZHMuAAAAAAAAAAARSW5oZXJpdGFuY2VDb25maWcAAAAAAAACAAAAAAAAAAtiZW5lZmljaWFyeQAAAAfQAAAACVNpZ25lcktleQAAAAAAAAAAAAARaW5hY3Rpdml0eV9wZXJpb2QAAAAAAAAG",
         "AAAAAQAAAMhBIFdlYkF1dGhuIGFzc2VydGlvbiBvdmVyIHRoZSBTb3JvYmFuIGF1dGhvcml6YXRpb24gcGF5bG9hZC4gVGhlIHNpZ25lZAptZXNzYWdlIGlzIGBhdXRoZW50aWNhdG9yX2RhdGEgfHwgc2hhMjU2KGNsaWVudF9kYXRhX2pzb24pYCBhbmQgdGhlCnBheWxvYWQgYmluZGluZyBsaXZlcyBpbiBjbGllbnREYXRhSlNPTidzIGBjaGFsbGVuZ2VgIGZpZWxkLgAAAAAAAAASU2VjcDI1NnIxU2lnbmF0dXJlAAAAAAADAAAAAAAAABJhdXRoZW50aWNhdG9yX2RhdGEAAAAAAA4AAAAAAAAAEGNsaWVudF9kYXRhX2pzb24AAAAOAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQA==",
-        "AAAABQAAASBUaGUgY29udHJhY3QncyB3YXNtIHdhcyByZXBsYWNlZCB2aWEgYHVwZ3JhZGVgLiBgb2xkX2hhc2hgIGlzIGBOb25lYCBvbiBhCndhbGxldCdzIGZpcnN0LWV2ZXIgdXBncmFkZTogdGhlIGhvc3QgZXhwb3NlcyBubyB3YXkgZm9yIGEgY29udHJhY3QgdG8KcmVhZCBpdHMgb3duIGV4ZWN1dGFibGUgaGFzaCwgc28gdGhlIHdhbGxldCBjYWNoZXMgdGhlIGhhc2ggaW4gaW5zdGFuY2UKc3RvcmFnZSBhdCBlYWNoIHVwZ3JhZGUgYW5kIHRoZSBnZW5lc2lzIGhhc2ggaXMgdW5rbm93YWJsZSBpbi1jb250cmFjdC4AAAAAAAAACFVwZ3JhZGVkAAAAAQAAAAh1cGdyYWRlZAAAAAIAAAAAAAAACG9sZF9oYXNoAAAD6AAAA+4AAAAgAAAAAAAAAAAAAAAIbmV3X2hhc2gAAAPuAAAAIAAAAAAAAAAC",
-        "AAAABQAAADlBIHNpZ25lciB3YXMgYWRkZWQgKHZpYSBgX19jb25zdHJ1Y3RvcmAgb3IgYGFkZF9zaWduZXJgKS4AAAAAAAAAAAAAC1NpZ25lckFkZGVkAAAAAAEAAAAMc2lnbmVyX2FkZGVkAAAAAwAAAAAAAAADa2V5AAAAB9AAAAAJU2lnbmVyS2V5AAAAAAAAAQAAAAAAAAADdmFsAAAAB9AAAAAJU2lnbmVyVmFsAAAAAAAAAAAAAAAAAAAHc3RvcmFnZQAAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAAAAAAAAg==",
-        "AAAABQAAAGFBIHNpZ25lciB3YXMgcmVtb3ZlZCB2aWEgYHJlbW92ZV9zaWduZXJgLiBgc3RvcmFnZWAgaXMgdGhlIGR1cmFiaWxpdHkgdGhlCmVudHJ5IHdhcyByZW1vdmVkIGZyb20uAAAAAAAAAAAAAA1TaWduZXJSZW1vdmVkAAAAAAAAAQAAAA5zaWduZXJfcmVtb3ZlZAAAAAAAAgAAAAAAAAADa2V5AAAAB9AAAAAJU2lnbmVyS2V5AAAAAAAAAQAAAAAAAAAHc3RvcmFnZQAAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAAAAAAAAg==",
-        "AAAABQAAADRBbiBleGlzdGluZyBzaWduZXIgd2FzIG1vZGlmaWVkIHZpYSBgdXBkYXRlX3NpZ25lcmAuAAAAAAAAAA1TaWduZXJVcGRhdGVkAAAAAAAAAQAAAA5zaWduZXJfdXBkYXRlZAAAAAAABAAAAAAAAAADa2V5AAAAB9AAAAAJU2lnbmVyS2V5AAAAAAAAAQAAAAAAAAADdmFsAAAAB9AAAAAJU2lnbmVyVmFsAAAAAAAAAAAAAAAAAAAHc3RvcmFnZQAAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAAAAAAAAAAAAAtvbGRfc3RvcmFnZQAAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAAAAAAAAg==" ]),
+        "AAAABQAAAD5BbiBpbnRlbnQncyBmZWUgd2FzIHBhaWQgdG8gaXRzIHJlY2lwaWVudCBieSBgZXhlY3V0ZV9pbnRlbnRgLgAAAAAAAAAAAAdGZWVQYWlkAAAAAAEAAAAIZmVlX3BhaWQAAAAEAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAP1UaGUgY29udHJhY3QncyB3YXNtIHdhcyByZXBsYWNlZCB2aWEgYHVwZ3JhZGVgLiBUaGUgd2FsbGV0IGNhY2hlcyBpdHMKaGFzaCBpbiBpbnN0YW5jZSBzdG9yYWdlIGF0IGNvbnN0cnVjdGlvbiBhbmQgYXQgZWFjaCB1cGdyYWRlOyBgb2xkX2hhc2hgCmlzIGBOb25lYCBvbmx5IG9uIHRoZSBmaXJzdCB1cGdyYWRlIG9mIGEgd2FsbGV0IGRlcGxveWVkIGJ5IG9sZGVyIGNvZGUsCndoaWNoIG5ldmVyIHJlY29yZGVkIGl0cyBnZW5lc2lzIGhhc2guAAAAAAAAAAAAAAhVcGdyYWRlZAAAAAEAAAAIdXBncmFkZWQAAAACAAAAAAAAAAhvbGRfaGFzaAAAA+gAAAPuAAAAIAAAAAAAAAAAAAAACG5ld19oYXNoAAAD7gAAACAAAAAAAAAAAg==",
+        "AAAABQAAAFpBIHNpZ25lciB3YXMgYWRkZWQgKHZpYSBgX19jb25zdHJ1Y3RvcmAsIGBhZGRfc2lnbmVyYCBvciBhbgpgYXBwbHlfc2lnbmVyX2NoYW5nZXNgIGJhdGNoKS4AAAAAAAAAAAALU2lnbmVyQWRkZWQAAAAAAQAAAAxzaWduZXJfYWRkZWQAAAAEAAAAAAAAAANrZXkAAAAH0AAAAAlTaWduZXJLZXkAAAAAAAABAAAAAAAAAAN2YWwAAAAH0AAAAAlTaWduZXJWYWwAAAAAAAAAAAAAAAAAAAdzdG9yYWdlAAAAB9AAAAANU2lnbmVyU3RvcmFnZQAAAAAAAAAAAAAAAAAAB29wdGlvbnMAAAAH0AAAAA1TaWduZXJPcHRpb25zAAAAAAAAAAAAAAI=",
+        "AAAABQAAAI1UaGUgd2FsbGV0IHdhcyBmcm96ZW4gdmlhIGBmcmVlemVgOiB1bnRpbCBgdW5mcmVlemVgLCBvbmx5IHVuZnJlZXppbmcgYW5kCnNpZ25lciBtYW5hZ2VtZW50IGNhbiBiZSBhdXRob3JpemVkLCBhbmQgb25seSBieSB0aGUgZnJlZXplIHF1b3J1bS4AAAAAAAAAAAAADFdhbGxldEZyb3plbgAAAAEAAAANd2FsbGV0X2Zyb3plbgAAAAAAAAAAAAAC",
+        "AAAABQAAAIZBIHNpZ25lciB3YXMgcmVtb3ZlZCAodmlhIGByZW1vdmVfc2lnbmVyYCBvciBhbiBgYXBwbHlfc2lnbmVyX2NoYW5nZXNgCmJhdGNoKS4gYHN0b3JhZ2VgIGlzIHRoZSBkdXJhYmlsaXR5IHRoZSBlbnRyeSB3YXMgcmVtb3ZlZCBmcm9tLgAAAAAAAAAAAA1TaWduZXJSZW1vdmVkAAAAAAAAAQAAAA5zaWduZXJfcmVtb3ZlZAAAAAAAAgAAAAAAAAADa2V5AAAAB9AAAAAJU2lnbmVyS2V5AAAAAAAAAQAAAAAAAAAHc3RvcmFnZQAAAAfQAAAADVNpZ25lclN0b3JhZ2UAAAAAAAAAAAAAAg==",
+        "AAAABQAAADZBIHN1c3BlbmRlZCBzaWduZXIgd2FzIHJlaW5zdGF0ZWQgdmlhIGByZXN1bWVfc2lnbmVyYC4AAAAAAAAAAAANU2lnbmVyUmVzdW1lZAAAAAAAAAEAAAAOc2lnbmVyX3Jlc3VtZWQAAAAAAAEAAAAAAAAAA2tleQAAAAfQAAAACVNpZ25lcktleQAAAAAAAAEAAAAC",
+        "AAAABQAAAFlBbiBleGlzdGluZyBzaWduZXIgd2FzIG1vZGlmaWVkICh2aWEgYHVwZGF0ZV9zaWduZXJgIG9yIGFuCmBhcHBseV9zaWduZXJfY2hhbmdlc2AgYmF0Y2gpLgAAAAAAAAAAAAANU2lnbmVyVXBkYXRlZAAAAAAAAAEAAAAOc2lnbmVyX3VwZGF0ZWQAAAAAAAUAAAAAAAAAA2tleQAAAAfQAAAACVNpZ25lcktleQAAAAAAAAEAAAAAAAAAA3ZhbAAAAAfQAAAACVNpZ25lclZhbAAAAAAAAAAAAAAAAAAAB3N0b3JhZ2UAAAAH0AAAAA1TaWduZXJTdG9yYWdlAAAAAAAAAAAAAAAAAAALb2xkX3N0b3JhZ2UAAAAH0AAAAA1TaWduZXJTdG9yYWdlAAAAAAAAAAAAAAAAAAAHb3B0aW9ucwAAAAfQAAAADVNpZ25lck9wdGlvbnMAAAAAAAAAAAAAAg==",
+        "AAAABQAAAEVBIHNpZ25lZCBpbnRlbnQgd2FzIGV4ZWN1dGVkIHZpYSBgZXhlY3V0ZV9pbnRlbnRgLCBjb25zdW1pbmcgYG5vbmNlYC4AAAAAAAAAAAAADkludGVudEV4ZWN1dGVkAAAAAAABAAAAD2ludGVudF9leGVjdXRlZAAAAAADAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAAAAAAAAhjb250cmFjdAAAABMAAAAAAAAAAAAAAAdmbl9uYW1lAAAAABEAAAAAAAAAAg==",
+        "AAAABQAAAEtUaGUgd2FsbGV0J3Mgc3RvcmFnZSB3YXMgbWlncmF0ZWQgb25lIHNjaGVtYSB2ZXJzaW9uIGZvcndhcmQgdmlhCmBtaWdyYXRlYC4AAAAAAAAAAA5TY2hlbWFNaWdyYXRlZAAAAAAAAQAAAA9zY2hlbWFfbWlncmF0ZWQAAAAAAgAAAAAAAAAMZnJvbV92ZXJzaW9uAAAABAAAAAAAAAAAAAAACnRvX3ZlcnNpb24AAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAACdUaGUgd2FsbGV0IHdhcyB1bmZyb3plbiB2aWEgYHVuZnJlZXplYC4AAAAAAAAAAA5XYWxsZXRVbmZyb3plbgAAAAAAAQAAAA93YWxsZXRfdW5mcm96ZW4AAAAAAAAAAAI=",
+        "AAAABQAAADhBbiBpbnRlbnQgbm9uY2Ugd2FzIGNvbnN1bWVkIHVudXNlZCB2aWEgYGNhbmNlbF9pbnRlbnRgLgAAAAAAAAAPSW50ZW50Q2FuY2VsbGVkAAAAAAEAAAAQaW50ZW50X2NhbmNlbGxlZAAAAAEAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAINFdmVyeSBzZXNzaW9uIHNpZ25lciBhZGRlZCBiZWZvcmUgdGhpcyBjYWxsIHdhcyByZXZva2VkIHZpYQpgcmV2b2tlX3Nlc3Npb25zYC4gYGdlbmVyYXRpb25gIGlzIHRoZSB3YWxsZXQncyBuZXcgc2Vzc2lvbiBnZW5lcmF0aW9uLgAAAAAAAAAAD1Nlc3Npb25zUmV2b2tlZAAAAAABAAAAEHNlc3Npb25zX3Jldm9rZWQAAAABAAAAAAAAAApnZW5lcmF0aW9uAAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAIBBIHN0b3JlZCBzaWduZXIgd2FzIHN1c3BlbmRlZCB2aWEgYHN1c3BlbmRfc2lnbmVyYDogaXQgc3RheXMgc3RvcmVkIHdpdGgKaXRzIGNvbmZpZ3VyYXRpb24gaW50YWN0IGJ1dCBjYW5ub3Qgc2lnbiB1bnRpbCByZXN1bWVkLgAAAAAAAAAPU2lnbmVyU3VzcGVuZGVkAAAAAAEAAAAQc2lnbmVyX3N1c3BlbmRlZAAAAAEAAAAAAAAAA2tleQAAAAfQAAAACVNpZ25lcktleQAAAAAAAAEAAAAC",
+        "AAAABQAAAFtUaGUgcGVuZGluZyB1cGdyYWRlIHRvIGBoYXNoYCBwYXNzZWQgaXRzIHNlbGYtdGVzdCBhbmQgd2FzIGNvbmZpcm1lZCB2aWEKYGNvbmZpcm1fdXBncmFkZWAuAAAAAAAAAAAQVXBncmFkZUNvbmZpcm1lZAAAAAEAAAARdXBncmFkZV9jb25maXJtZWQAAAAAAAABAAAAAAAAAARoYXNoAAAD7gAAACAAAAAAAAAAAg==",
+        "AAAABQAAAIdBIHN1YmFjY291bnQgd2FzIGRlcGxveWVkIGJ5IGBjcmVhdGVfc3ViYWNjb3VudGAuIFRoZSBzdWJhY2NvdW50IGl0c2VsZgphbm5vdW5jZXMgaXRzIHNpZ25lcnMsIHRoZSBwYXJlbnQgaW5jbHVkZWQsIHdpdGggYFNpZ25lckFkZGVkYC4AAAAAAAAAABFTdWJhY2NvdW50Q3JlYXRlZAAAAAAAAAEAAAASc3ViYWNjb3VudF9jcmVhdGVkAAAAAAACAAAAAAAAAApzdWJhY2NvdW50AAAAAAATAAAAAQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAAAg==",
+        "AAAABQAAAG5BIHBlbmRpbmcgdXBncmFkZSB3YXMgcmV2ZXJ0ZWQgdmlhIGByb2xsYmFja191cGdyYWRlYDogdGhlIHdhbGxldCBydW5zCmB0b19oYXNoYCBhZ2FpbiBpbnN0ZWFkIG9mIGBmcm9tX2hhc2hgLgAAAAAAAAAAABFVcGdyYWRlUm9sbGVkQmFjawAAAAAAAAEAAAATdXBncmFkZV9yb2xsZWRfYmFjawAAAAACAAAAAAAAAAlmcm9tX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAAAAAAB3RvX2hhc2gAAAAD7gAAACAAAAAAAAAAAg==",
+        "AAAABQAAAHJUaGUgd2FsbGV0J3MgYWRtaW4vZHVyYWJsZSBzaWduZXIgY291bnRlcnMgd2VyZSByZWNvbmNpbGVkIHZpYQpgcmVjb25jaWxlX2NvdW50ZXJzYC4gQ2FycmllcyB0aGUgcmVzdWx0aW5nIGNvdW50cy4AAAAAAAAAAAASQ291bnRlcnNSZWNvbmNpbGVkAAAAAAABAAAAE2NvdW50ZXJzX3JlY29uY2lsZWQAAAAAAgAAAAAAAAALYWRtaW5fY291bnQAAAAABAAAAAAAAAAAAAAADWR1cmFibGVfY291bnQAAAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAGNUaGUgd2FsbGV0J3MgaW5oZXJpdGFuY2UgY29uZmlndXJhdGlvbiB3YXMgc2V0LCByZXBsYWNlZCBvciAoYE5vbmVgKQpjbGVhcmVkIHZpYSBgc2V0X2luaGVyaXRhbmNlYC4AAAAAAAAAABJJbmhlcml0YW5jZVVwZGF0ZWQAAAAAAAEAAAATaW5oZXJpdGFuY2VfdXBkYXRlZAAAAAABAAAAAAAAAAZjb25maWcAAAAAA+gAAAfQAAAAEUluaGVyaXRhbmNlQ29uZmlnAAAAAAAAAAAAAAI=",
+        "AAAABQAAALxUaGUgd2FsbGV0J3Mgc2lnbmVyIHJlZ2lzdHJ5IHJlamVjdGVkIGEgcmVwb3J0IG9mIGBrZXlgOiBpdHMKcmVnaXN0cmF0aW9uIChgcmVnaXN0ZXJlZGApIG9yIHVucmVnaXN0cmF0aW9uLiBUaGUgc2lnbmVyIGNoYW5nZSBpdHNlbGYKd2VudCB0aHJvdWdoOyB0aGUgcmVnaXN0cnkgZW50cnkgaXMgc3RhbGUgdW50aWwgc3luY2VkLgAAAAAAAAAUU2lnbmVyUmVnaXN0cnlGYWlsZWQAAAABAAAAFnNpZ25lcl9yZWdpc3RyeV9mYWlsZWQAAAAAAAMAAAAAAAAAA2tleQAAAAfQAAAACVNpZ25lcktleQAAAAAAAAEAAAAAAAAACHJlZ2lzdHJ5AAAAEwAAAAAAAAAAAAAACnJlZ2lzdGVyZWQAAAAAAAEAAAAAAAAAAg==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    upgrade: this.txFromJSON<Result<void>>,
+    bump: this.txFromJSON<null>,
+        freeze: this.txFromJSON<Result<void>>,
+        execute: this.txFromJSON<Result<Array<any>>>,
+        migrate: this.txFromJSON<Result<void>>,
+        upgrade: this.txFromJSON<Result<void>>,
+        unfreeze: this.txFromJSON<Result<void>>,
+        is_frozen: this.txFromJSON<boolean>,
         add_signer: this.txFromJSON<Result<void>>,
         get_signer: this.txFromJSON<Option<SignerVal>>,
+        cancel_intent: this.txFromJSON<Result<void>>,
+        is_subaccount: this.txFromJSON<boolean>,
+        prune_expired: this.txFromJSON<null>,
         remove_signer: this.txFromJSON<Result<void>>,
-        update_signer: this.txFromJSON<Result<void>>
+        resume_signer: this.txFromJSON<Result<void>>,
+        update_signer: this.txFromJSON<Result<void>>,
+        execute_intent: this.txFromJSON<Result<any>>,
+        get_fee_config: this.txFromJSON<Option<FeeConfig>>,
+        get_ttl_config: this.txFromJSON<Option<TtlConfig>>,
+        set_fee_config: this.txFromJSON<Result<void>>,
+        set_ttl_config: this.txFromJSON<Result<void>>,
+        suspend_signer: this.txFromJSON<Result<void>>,
+        confirm_upgrade: this.txFromJSON<Result<void>>,
+        get_inheritance: this.txFromJSON<Option<InheritanceConfig>>,
+        get_intent_hash: this.txFromJSON<Buffer>,
+        get_last_active: this.txFromJSON<Option<u64>>,
+        revoke_sessions: this.txFromJSON<Result<void>>,
+        set_inheritance: this.txFromJSON<Result<void>>,
+        rollback_upgrade: this.txFromJSON<Result<void>>,
+        create_subaccount: this.txFromJSON<Result<string>>,
+        get_freeze_quorum: this.txFromJSON<u32>,
+        set_freeze_quorum: this.txFromJSON<Result<void>>,
+        get_schema_version: this.txFromJSON<u32>,
+        get_signature_hash: this.txFromJSON<Buffer>,
+        get_signer_options: this.txFromJSON<Option<SignerOptions>>,
+        is_valid_signature: this.txFromJSON<boolean>,
+        reconcile_counters: this.txFromJSON<Result<void>>,
+        get_pending_upgrade: this.txFromJSON<Option<PendingUpgrade>>,
+        get_signer_activity: this.txFromJSON<Option<SignerActivity>>,
+        get_signer_registry: this.txFromJSON<Option<string>>,
+        get_web_auth_config: this.txFromJSON<Option<WebAuthConfig>>,
+        is_signer_suspended: this.txFromJSON<Option<boolean>>,
+        set_signer_registry: this.txFromJSON<Result<void>>,
+        set_web_auth_config: this.txFromJSON<Result<void>>,
+        apply_signer_changes: this.txFromJSON<Result<void>>,
+        get_upgrade_guardian: this.txFromJSON<Option<string>>,
+        is_intent_nonce_used: this.txFromJSON<boolean>,
+        set_upgrade_guardian: this.txFromJSON<Result<void>>,
+        get_session_generation: this.txFromJSON<u32>,
+        migrate_legacy_signers: this.txFromJSON<Result<void>>,
+        get_last_owner_activity: this.txFromJSON<Option<u64>>
   }
 }
\ No newline at end of file
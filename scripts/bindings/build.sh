@@ -8,9 +8,19 @@
 # copied into the package — the B1 packaging (package.json, tsconfig, dist build)
 # is preserved. NEVER hand-edit the generated bindings; add post-gen steps here.
 #
-# Usage: bash scripts/bindings/build.sh
+# Between releases, `--wasm <path>` regenerates from a locally built WASM
+# instead (e.g. contracts/target/wasm32v1-none/release/smart_wallet.wasm), so a
+# contract interface change ships with matching bindings. Re-pin the canonical
+# hash once that WASM is uploaded, and regenerate without `--wasm`.
+#
+# Usage: bash scripts/bindings/build.sh [--wasm <path>]
 set -euo pipefail
 
+LOCAL_WASM=""
+if [ "${1:-}" = "--wasm" ]; then
+  LOCAL_WASM="${2:?--wasm needs a path}"
+fi
+
 # Canonical smart-wallet WASM hash — keep in sync with the deployments manifest
 # (docs/deployments-testnet-2026-07-11.md). Pinned Stellar CLI: 27.0.0.
 CANONICAL_HASH="fdefad64b96837147e1c333e51f537b696eab925e9f147e63d597c04e3c903f0"
@@ -23,14 +33,19 @@ PKG_README="$ROOT/packages/passkey-kit-sdk/README.md"
 TMP="$(mktemp -d)"
 trap 'rm -rf "$TMP"' EXIT
 
-echo "Fetching canonical WASM ${CANONICAL_HASH} from ${NETWORK}…"
-stellar contract fetch --wasm-hash "$CANONICAL_HASH" --network "$NETWORK" \
-  --out-file "$TMP/smart-wallet.wasm"
+if [ -n "$LOCAL_WASM" ]; then
+  echo "Using local WASM ${LOCAL_WASM} (not the canonical ${CANONICAL_HASH})…"
+  cp "$LOCAL_WASM" "$TMP/smart-wallet.wasm"
+else
+  echo "Fetching canonical WASM ${CANONICAL_HASH} from ${NETWORK}…"
+  stellar contract fetch --wasm-hash "$CANONICAL_HASH" --network "$NETWORK" \
+    --out-file "$TMP/smart-wallet.wasm"
 
-ACTUAL="$(shasum -a 256 "$TMP/smart-wallet.wasm" | awk '{print $1}')"
-if [ "$ACTUAL" != "$CANONICAL_HASH" ]; then
-  echo "✗ Fetched WASM hash ($ACTUAL) != canonical ($CANONICAL_HASH)" >&2
-  exit 1
+  ACTUAL="$(shasum -a 256 "$TMP/smart-wallet.wasm" | awk '{print $1}')"
+  if [ "$ACTUAL" != "$CANONICAL_HASH" ]; then
+    echo "✗ Fetched WASM hash ($ACTUAL) != canonical ($CANONICAL_HASH)" >&2
+    exit 1
+  fi
 fi
 
 echo "Generating TypeScript bindings…"
@@ -44,5 +59,9 @@ echo "Applying post-gen patches (copy generated spec, preserve packaging)…"
 cp "$TMP/pks-gen/src/index.ts" "$PKG_SRC"
 cp "$TMP/pks-gen/README.md" "$PKG_README"
 
-echo "✓ Regenerated passkey-kit-sdk from canonical WASM ${CANONICAL_HASH}"
+if [ -n "$LOCAL_WASM" ]; then
+  echo "✓ Regenerated passkey-kit-sdk from local WASM ${LOCAL_WASM}"
+else
+  echo "✓ Regenerated passkey-kit-sdk from canonical WASM ${CANONICAL_HASH}"
+fi
 echo "  Run 'pnpm --filter passkey-kit-sdk run build' to rebuild dist."
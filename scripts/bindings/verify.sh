@@ -7,9 +7,17 @@
 # ContractSpec base64 array (the semantic content — CLI formatting/comments are
 # ignored) against the committed bindings. Fails the build/release on drift.
 #
-# Usage: bash scripts/bindings/verify.sh
+# `--wasm <path>` checks against a locally built WASM instead, for bindings
+# regenerated ahead of a release (see build.sh).
+#
+# Usage: bash scripts/bindings/verify.sh [--wasm <path>]
 set -euo pipefail
 
+LOCAL_WASM=""
+if [ "${1:-}" = "--wasm" ]; then
+  LOCAL_WASM="${2:?--wasm needs a path}"
+fi
+
 CANONICAL_HASH="fdefad64b96837147e1c333e51f537b696eab925e9f147e63d597c04e3c903f0"
 NETWORK="testnet"
 
@@ -19,15 +27,20 @@ PKG_SRC="$ROOT/packages/passkey-kit-sdk/src/index.ts"
 TMP="$(mktemp -d)"
 trap 'rm -rf "$TMP"' EXIT
 
-echo "verify:bindings — canonical WASM ${CANONICAL_HASH} (${NETWORK})"
+if [ -n "$LOCAL_WASM" ]; then
+  echo "verify:bindings — local WASM ${LOCAL_WASM}"
+  cp "$LOCAL_WASM" "$TMP/smart-wallet.wasm"
+else
+  echo "verify:bindings — canonical WASM ${CANONICAL_HASH} (${NETWORK})"
 
-stellar contract fetch --wasm-hash "$CANONICAL_HASH" --network "$NETWORK" \
-  --out-file "$TMP/smart-wallet.wasm"
+  stellar contract fetch --wasm-hash "$CANONICAL_HASH" --network "$NETWORK" \
+    --out-file "$TMP/smart-wallet.wasm"
 
-ACTUAL="$(shasum -a 256 "$TMP/smart-wallet.wasm" | awk '{print $1}')"
-if [ "$ACTUAL" != "$CANONICAL_HASH" ]; then
-  echo "✗ Fetched WASM hash ($ACTUAL) != canonical ($CANONICAL_HASH)" >&2
-  exit 1
+  ACTUAL="$(shasum -a 256 "$TMP/smart-wallet.wasm" | awk '{print $1}')"
+  if [ "$ACTUAL" != "$CANONICAL_HASH" ]; then
+    echo "✗ Fetched WASM hash ($ACTUAL) != canonical ($CANONICAL_HASH)" >&2
+    exit 1
+  fi
 fi
 
 stellar contract bindings typescript --wasm "$TMP/smart-wallet.wasm" \
@@ -42,7 +55,7 @@ extract_spec() {
 if diff -u \
   <(extract_spec "$PKG_SRC") \
   <(extract_spec "$TMP/pks-gen/src/index.ts"); then
-  echo "✓ Committed bindings match canonical WASM ${CANONICAL_HASH}"
+  echo "✓ Committed bindings match ${LOCAL_WASM:-canonical WASM ${CANONICAL_HASH}}"
 else
   echo "" >&2
   echo "✗ Bindings DRIFT from the canonical WASM." >&2
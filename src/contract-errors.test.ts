@@ -35,6 +35,11 @@ describe("decodeContractError", () => {
     [122, "ClientDataJsonChallengeIncorrect"],
     [123, "InvalidWebAuthnType"],
     [125, "UserPresenceRequired"],
+    [112, "WalletFrozen"],
+    [130, "UpgradeNotPending"],
+    [142, "IntentNonceUsed"],
+    [119, "SelfAddressSigner"],
+    [150, "FreezeTimelockActive"],
   ])("decodes v1 #%i as %s", (code, name) => {
     const error = decodeContractError(`Error(Contract, #${code})`);
     expect(error?.contractCode).toBe(code);
@@ -9,7 +9,7 @@
  * `Errors` map; `contract-errors.test.ts` asserts this registry stays in sync
  * with it, so a bindings regen surfaces any drift.
  *
- * The v1 contract deliberately renumbered its error space to 100-129 so it is
+ * The v1 contract deliberately renumbered its error space to 100-159 so it is
  * disjoint from the legacy (pre-1.0) 1-9 range: a decoded code < 100 means the
  * client is talking to a legacy wallet. Both ranges are kept here so errors
  * from legacy deployed wallets still decode (family `SmartWalletLegacy`).
@@ -57,16 +57,29 @@ export const CONTRACT_ERROR_REGISTRY: Readonly<
   Record<number, ContractErrorInfo>
 > = Object.freeze(
   Object.fromEntries([
-    // --- v1 SmartWallet (100-129) — source of truth in the bindings Errors map ---
+    // --- v1 SmartWallet (100-159) — source of truth in the bindings Errors map ---
     // 100-109: signer storage / management
     entry(100, "SignerNotFound", "SmartWallet", "The requested signer does not exist on this smart wallet."),
     entry(101, "SignerAlreadyExists", "SmartWallet", "add_signer was called with a signer key that already exists."),
-    entry(102, "SignerExpired", "SmartWallet", "The signer's expiration timestamp is in the past."),
+    entry(102, "SignerExpired", "SmartWallet", "The signer's expiration timestamp is in the past, or it is a session signer revoked by revoke_sessions."),
     entry(103, "LastAdminSigner", "SmartWallet", "This change would remove or demote the wallet's last durable admin signer (persistent, non-expiring, independently admin-capable) and permanently lock the wallet's admin surface; add or promote a replacement admin signer first."),
-    entry(104, "LastSigner", "SmartWallet", "The operation would leave the wallet without any durable (Persistent, non-expiring) signer — thrown by remove_signer, update_signer demotions, and a __constructor with a non-durable first signer; keep or add a Persistent, non-expiring signer."),
-    // 110-119: auth (__check_auth)
+    entry(104, "LastSigner", "SmartWallet", "The operation would leave the wallet without any durable (Persistent, non-expiring) signer — thrown by remove_signer, update_signer demotions, and a __constructor whose initial signer set holds none; keep or add a Persistent, non-expiring signer."),
+    entry(105, "InvalidPublicKey", "SmartWallet", "A Secp256r1 signer's public key is not a valid SEC-1 encoded P-256 point."),
+    entry(106, "SignerNotYetValid", "SmartWallet", "The signer's valid_after start time has not been reached yet."),
+    entry(107, "SignerSuspended", "SmartWallet", "The signer is suspended and cannot sign until it is resumed."),
+    entry(108, "BeneficiaryDormant", "SmartWallet", "The signer is the inheritance beneficiary and the owner has not been inactive for the configured period yet."),
+    entry(109, "InvalidInactivityPeriod", "SmartWallet", "set_inheritance was called with an inactivity period of zero."),
+    // 110-119: auth (__check_auth) and wallet settings
     entry(110, "MissingContext", "SmartWallet", "No signer in the signatures map is permitted to authorize one of the requested auth contexts."),
     entry(111, "SignatureKeyValueMismatch", "SmartWallet", "A signature's variant does not match the stored signer it claims to be for."),
+    entry(112, "WalletFrozen", "SmartWallet", "The wallet is frozen and a requested auth context is neither unfreeze nor signer management."),
+    entry(113, "FreezeQuorumNotMet", "SmartWallet", "The wallet is frozen and the signatures map holds fewer active durable admin signers than the freeze quorum."),
+    entry(114, "InvalidFreezeQuorum", "SmartWallet", "set_freeze_quorum was called with a quorum of zero."),
+    entry(115, "InvalidSignerMetadata", "SmartWallet", "A signer's metadata label is longer than the maximum label length."),
+    entry(116, "InvalidTtlConfig", "SmartWallet", "A TtlConfig rule has a threshold above its extend_to."),
+    entry(117, "DuplicateSignerKey", "SmartWallet", "reconcile_counters was given the same signer key more than once."),
+    entry(118, "AlreadyMigrated", "SmartWallet", "migrate was called on a wallet already at the running code's schema version."),
+    entry(119, "SelfAddressSigner", "SmartWallet", "An Address signer names the wallet itself."),
     // 120-129: WebAuthn (secp256r1) verification
     entry(120, "ClientDataJsonTooLarge", "SmartWallet", "clientDataJSON exceeds the 1024-byte parse buffer."),
     entry(121, "ClientDataJsonParseError", "SmartWallet", "clientDataJSON is not parseable JSON (or is missing required fields)."),
@@ -75,6 +88,25 @@ export const CONTRACT_ERROR_REGISTRY: Readonly<
     entry(124, "InvalidAuthenticatorData", "SmartWallet", "authenticatorData is shorter than the WebAuthn minimum of 37 bytes."),
     entry(125, "UserPresenceRequired", "SmartWallet", "The authenticator did not set the User Present (UP) flag."),
     entry(126, "AuthenticatorDataTooLarge", "SmartWallet", "authenticatorData exceeds the 1024-byte cap."),
+    // 130-139: upgrades
+    entry(130, "UpgradeNotPending", "SmartWallet", "confirm_upgrade or rollback_upgrade was called with no upgrade awaiting confirmation."),
+    entry(131, "NoRollbackTarget", "SmartWallet", "The pending upgrade has no rollback target: the wallet's previous wasm hash was unknown."),
+    entry(132, "UpgradeSelfTestFailed", "SmartWallet", "The new code's confirm_upgrade self-test failed; run migrate first if the schema version changed."),
+    entry(133, "UpgradeExpired", "SmartWallet", "confirm_upgrade was called after the pending upgrade's deadline; roll it back or upgrade again."),
+    entry(134, "RollbackAfterMigration", "SmartWallet", "rollback_upgrade was called after migrate moved storage past the previous code's schema version."),
+    // 140-149: execution (execute, intents, fees)
+    entry(140, "ExecuteSelfCall", "SmartWallet", "An execute call targets the wallet itself."),
+    entry(141, "IntentExpired", "SmartWallet", "execute_intent was called after the intent's deadline."),
+    entry(142, "IntentNonceUsed", "SmartWallet", "The intent's nonce was already consumed by execute_intent or cancel_intent."),
+    entry(143, "FeeTokenNotAllowed", "SmartWallet", "The intent's fee is in a token the wallet's FeeConfig does not allow."),
+    entry(144, "FeeTooHigh", "SmartWallet", "The intent's fee is negative or above the wallet's maximum for its token."),
+    entry(145, "InvalidFeeConfig", "SmartWallet", "A FeeConfig maximum is negative."),
+    entry(146, "IntentDeadlineTooFar", "SmartWallet", "The intent's deadline is further ahead than its nonce can be tracked (the network's maximum entry TTL, one second per ledger)."),
+    // 150-159: freeze timelock and storage migration (110-119 was full)
+    entry(150, "FreezeTimelockActive", "SmartWallet", "The wallet is frozen, has fewer durable admins than its freeze quorum, and the freeze timelock has not passed."),
+    entry(155, "UnsupportedSchemaVersion", "SmartWallet", "migrate found a stored schema version this code has no migration step for."),
+    entry(156, "InvalidLegacySigner", "SmartWallet", "migrate_legacy_signers was given an entry that is neither a v1 nor a pre-1.0 signer value."),
+    entry(157, "AmbiguousLegacyLimits", "SmartWallet", "migrate_legacy_signers was given a non-expiring entry with empty limits; set its limits with update_signer instead."),
 
     // --- Legacy (pre-1.0) 1-9 — kept so errors from legacy deployed wallets decode ---
     entry(1, "NotFound", "SmartWalletLegacy", "[legacy] The specified signer was not found."),
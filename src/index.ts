@@ -20,7 +20,11 @@ export {
 export { SACClient, buildTokenTransferHostFunction } from "./sac.js";
 
 // Generated contract client
-export { Client as PasskeyClient } from "passkey-kit-sdk";
+export {
+  Client as PasskeyClient,
+  type WalletSetting,
+  type WalletSettings,
+} from "passkey-kit-sdk";
 
 // Signer abstraction
 export {
@@ -104,6 +108,7 @@ export {
 // Crypto / derivation helpers
 export {
   deriveContractAddress,
+  deriveFactoryContractAddress,
   extractPublicKeyFromAttestation,
   compactSignature,
   generateChallenge,
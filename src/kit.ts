@@ -21,7 +21,7 @@ import {
   type AuthenticatorSelectionCriteria,
 } from "@simplewebauthn/browser";
 import type { AssembledTransaction } from "@stellar/stellar-sdk/contract";
-import { Client as PasskeyClient } from "passkey-kit-sdk";
+import { Client as PasskeyClient, type WalletSettings } from "passkey-kit-sdk";
 import base64url from "./base64url.js";
 import {
   SignerKey,
@@ -70,6 +70,13 @@ export interface PasskeyKitConfig {
    * derived wallet addresses.
    */
   deploySource?: string;
+  /**
+   * `wallet-factory` contract id (`C…`). When set, {@link PasskeyKit.createWallet}
+   * deploys through the factory — its pinned WASM, at an address bound to the
+   * passkey's keyId and public key, so it cannot be front-run — instead of
+   * through the shared deployer. The deployer still pays the fee.
+   */
+  walletFactory?: string;
   /** Transaction timeout, in seconds (default 30). */
   timeoutInSeconds?: number;
   /** Optional passkey-record storage adapter (see `passkey-kit/storage`). */
@@ -81,6 +88,8 @@ export interface PasskeyKitConfig {
 /** Options for {@link PasskeyKit.createKey}/{@link PasskeyKit.createWallet}. */
 export interface CreateOptions {
   authenticatorSelection?: AuthenticatorSelectionCriteria;
+  /** Wallet settings applied by the deploy's `__constructor`. */
+  settings?: WalletSettings;
 }
 
 /** Options for {@link PasskeyKit.connectWallet}. */
@@ -173,6 +182,7 @@ export class PasskeyKit {
       walletWasmHash: this.walletWasmHash,
       deployerKeypair,
       timeoutInSeconds: this.timeoutInSeconds,
+      walletFactory: config.walletFactory,
     });
   }
 
@@ -207,8 +217,8 @@ export class PasskeyKit {
 
   /**
    * Register a passkey and deploy a smart wallet initialized with it as the
-   * first signer. Returns the signed deploy transaction (submit it via
-   * `PasskeyServer`).
+   * first signer — through the `walletFactory` if one is configured. Returns
+   * the signed deploy transaction (submit it via `PasskeyServer`).
    */
   async createWallet(
     appName: string,
@@ -217,11 +227,29 @@ export class PasskeyKit {
   ): Promise<CreateWalletResult> {
     const created = await this.createKey(appName, userName, options);
 
-    const deployTx = await this.submissionManager.buildDeployTransaction(
-      created.keyIdBuffer,
-      created.publicKey
-    );
-    const contractId = deployTx.result.options.contractId;
+    let contractId: string;
+    let signedTx: string;
+    if (this.submissionManager.walletFactory) {
+      const deployTx = await this.submissionManager.buildFactoryDeployTransaction(
+        created.keyIdBuffer,
+        created.publicKey,
+        options?.settings
+      );
+      contractId = this.submissionManager.deriveFactoryWalletAddress(
+        created.keyIdBuffer,
+        created.publicKey,
+        options?.settings
+      );
+      signedTx = await this.submissionManager.signDeploy(deployTx);
+    } else {
+      const deployTx = await this.submissionManager.buildDeployTransaction(
+        created.keyIdBuffer,
+        created.publicKey,
+        options?.settings
+      );
+      contractId = deployTx.result.options.contractId;
+      signedTx = await this.submissionManager.signDeploy(deployTx);
+    }
 
     this.wallet = new PasskeyClient({
       contractId,
@@ -230,8 +258,6 @@ export class PasskeyKit {
     });
     this.keyId = created.keyId;
 
-    const signedTx = await this.submissionManager.signDeploy(deployTx);
-
     await this.credentialManager.rememberPasskey({
       keyId: created.keyId,
       publicKey: created.publicKey,
@@ -1,6 +1,12 @@
 /**
  * Smart-wallet deployment operations.
  *
+ * A wallet is deployed either by the shared deployer account (the default,
+ * addressed by keyId alone) or through a `wallet-factory` contract
+ * (`config.walletFactory`), which pins the wallet WASM and binds the address
+ * to the passkey's keyId and public key and to the wallet's settings, so it
+ * cannot be front-run.
+ *
  * The deployer keypair is now configurable (`config.deploySource`) instead of a
  * hard-coded `Keypair.fromRawEd25519Seed(hash("kalepail"))`. The default still
  * derives from {@link DEFAULT_DEPLOYER_SEED} = `"kalepail"` so contract-id
@@ -9,14 +15,18 @@
  * @packageDocumentation
  */
 
-import { Keypair, hash } from "@stellar/stellar-sdk";
+import { Keypair, hash, xdr } from "@stellar/stellar-sdk";
+import { Client as ContractClient } from "@stellar/stellar-sdk/contract";
 import type { AssembledTransaction } from "@stellar/stellar-sdk/contract";
 import {
   Client as PasskeyClient,
   type Signer as SDKSigner,
+  type WalletSettings,
 } from "passkey-kit-sdk";
 import { DEFAULT_DEPLOYER_SEED } from "../constants.js";
-import { deriveContractAddress } from "../utils.js";
+import { DEFAULT_SIGNER_OPTIONS } from "./wallet-ops.js";
+import { deriveContractAddress, deriveFactoryContractAddress } from "../utils.js";
+import { walletSpec } from "../indexer/codec.js";
 import { ConfigurationError, PasskeyKitErrorCode } from "../errors.js";
 
 /**
@@ -54,7 +64,9 @@ export function deriveWalletAddress(
 
 /**
  * Build the smart-wallet deploy transaction, initializing it with the passkey as
- * the first (unlimited, persistent) Secp256r1 signer via `__constructor`.
+ * its only (unlimited, persistent) Secp256r1 signer via `__constructor`.
+ * `settings` (freeze quorum, TTL config, inheritance, fees, signer registry)
+ * are applied in the same call; omitted, the wallet starts with the defaults.
  *
  * The returned {@link AssembledTransaction} still needs to be signed by the
  * deployer keypair (the fee source) before submission.
@@ -68,7 +80,8 @@ export async function buildDeployTransaction(
     timeoutInSeconds: number;
   },
   keyId: Buffer,
-  publicKey: Uint8Array
+  publicKey: Uint8Array,
+  settings?: WalletSettings
 ): Promise<AssembledTransaction<PasskeyClient>> {
   const signer: SDKSigner = {
     tag: "Secp256r1",
@@ -78,11 +91,12 @@ export async function buildDeployTransaction(
       [undefined], // SignerExpiration: none
       [undefined], // SignerLimits: unlimited
       { tag: "Persistent", values: undefined },
+      DEFAULT_SIGNER_OPTIONS,
     ],
   };
 
   return PasskeyClient.deploy(
-    { signer },
+    { signers: [signer], settings },
     {
       rpcUrl: deps.rpcUrl,
       wasmHash: deps.walletWasmHash,
@@ -93,3 +107,82 @@ export async function buildDeployTransaction(
     }
   );
 }
+
+/** The `wallet-factory` methods the kit calls, on a spec-driven client. */
+interface WalletFactoryClient {
+  deploy(
+    args: { key_id: Buffer; public_key: Buffer; settings: WalletSettings | undefined },
+    options?: { timeoutInSeconds?: number }
+  ): Promise<AssembledTransaction<string>>;
+}
+
+/** The `Option<WalletSettings>` type def, for encoding the factory salt. */
+const WALLET_SETTINGS_OPTION = xdr.ScSpecTypeDef.scSpecTypeOption(
+  new xdr.ScSpecTypeOption({
+    valueType: xdr.ScSpecTypeDef.scSpecTypeUdt(
+      new xdr.ScSpecTypeUdt({ name: "WalletSettings" })
+    ),
+  })
+);
+
+/**
+ * Derive the address a `wallet-factory` deploys a passkey's wallet at with
+ * `settings`. Other settings give another address.
+ */
+export function deriveFactoryWalletAddress(
+  deps: { networkPassphrase: string; walletFactory: string },
+  keyId: Buffer,
+  publicKey: Uint8Array,
+  settings?: WalletSettings
+): string {
+  return deriveFactoryContractAddress(
+    keyId,
+    publicKey,
+    deps.walletFactory,
+    deps.networkPassphrase,
+    walletSpec().nativeToScVal(settings, WALLET_SETTINGS_OPTION)
+  );
+}
+
+/**
+ * Build a `wallet-factory` `deploy(key_id, public_key, settings)` call. The
+ * factory makes the passkey the wallet's only (unlimited, persistent)
+ * Secp256r1 signer and deploys its pinned WASM at
+ * {@link deriveFactoryWalletAddress}; `walletWasmHash` is not used. The
+ * client is built from the factory's on-chain spec.
+ *
+ * `settings` are part of the address ({@link deriveFactoryWalletAddress}), so
+ * whoever submits the deploy first can only deploy the wallet with these
+ * settings.
+ *
+ * The returned {@link AssembledTransaction} still needs to be signed by the
+ * deployer keypair (the fee source) before submission.
+ */
+export async function buildFactoryDeployTransaction(
+  deps: {
+    rpcUrl: string;
+    networkPassphrase: string;
+    walletFactory: string;
+    deployerPublicKey: string;
+    timeoutInSeconds: number;
+  },
+  keyId: Buffer,
+  publicKey: Uint8Array,
+  settings?: WalletSettings
+): Promise<AssembledTransaction<string>> {
+  const factory = (await ContractClient.from({
+    contractId: deps.walletFactory,
+    rpcUrl: deps.rpcUrl,
+    networkPassphrase: deps.networkPassphrase,
+    publicKey: deps.deployerPublicKey,
+  })) as unknown as WalletFactoryClient;
+
+  return factory.deploy(
+    {
+      key_id: Buffer.from(keyId),
+      public_key: Buffer.from(publicKey),
+      settings,
+    },
+    { timeoutInSeconds: deps.timeoutInSeconds }
+  );
+}
@@ -26,6 +26,7 @@ import {
   type SignerExpiration,
   type SignerKey as SDKSignerKey,
   type SignerLimits as SDKSignerLimits,
+  type SignerOptions,
   type SignerVal,
 } from "passkey-kit-sdk";
 import base64url from "../base64url.js";
@@ -45,6 +46,19 @@ function toSignerExpiration(expiration?: number): SignerExpiration {
   return [expiration == null ? undefined : BigInt(expiration)];
 }
 
+/**
+ * `SignerOptions` with every field unset: no start time, no freeze or web-auth
+ * permission, no metadata. The contract treats it as "no options" and stores
+ * nothing. `update_signer` replaces a signer's options together with its value,
+ * so an update built with these clears any options the signer had.
+ */
+export const DEFAULT_SIGNER_OPTIONS: SignerOptions = {
+  valid_after: undefined,
+  can_freeze: false,
+  can_web_auth: false,
+  metadata: { label: undefined, created_at: undefined, device: undefined },
+};
+
 /** Which write the builder targets. */
 type SignerFn = "add_signer" | "update_signer";
 
@@ -97,6 +111,7 @@ function buildSecp256r1Signer(
       toSignerExpiration(expiration),
       toContractSignerLimits(limits),
       { tag: store, values: undefined },
+      DEFAULT_SIGNER_OPTIONS,
     ],
   };
 }
@@ -114,6 +129,7 @@ function buildEd25519Signer(
       toSignerExpiration(expiration),
       toContractSignerLimits(limits),
       { tag: store, values: undefined },
+      DEFAULT_SIGNER_OPTIONS,
     ],
   };
 }
@@ -131,6 +147,7 @@ function buildPolicySigner(
       toSignerExpiration(expiration),
       toContractSignerLimits(limits),
       { tag: store, values: undefined },
+      DEFAULT_SIGNER_OPTIONS,
     ],
   };
 }
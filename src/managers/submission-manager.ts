@@ -14,9 +14,11 @@ import { TransactionBuilder } from "@stellar/stellar-sdk";
 import type { Keypair, Transaction } from "@stellar/stellar-sdk";
 import type { AssembledTransaction } from "@stellar/stellar-sdk/contract";
 import type { Server } from "@stellar/stellar-sdk/rpc";
-import type { Client as PasskeyClient } from "passkey-kit-sdk";
+import type { Client as PasskeyClient, WalletSettings } from "passkey-kit-sdk";
 import {
   buildDeployTransaction as buildDeployTransactionOp,
+  buildFactoryDeployTransaction as buildFactoryDeployTransactionOp,
+  deriveFactoryWalletAddress as deriveFactoryWalletAddressOp,
   deriveWalletAddress as deriveWalletAddressOp,
 } from "../kit/deploy-ops.js";
 import {
@@ -31,6 +33,8 @@ export interface SubmissionManagerDeps {
   walletWasmHash: string;
   deployerKeypair: Keypair;
   timeoutInSeconds: number;
+  /** `wallet-factory` contract id; when set, wallets deploy through it. */
+  walletFactory?: string;
 }
 
 export class SubmissionManager {
@@ -55,7 +59,8 @@ export class SubmissionManager {
   /** Build the wallet deploy transaction (initial Secp256r1 signer). */
   buildDeployTransaction(
     keyId: Buffer,
-    publicKey: Uint8Array
+    publicKey: Uint8Array,
+    settings?: WalletSettings
   ): Promise<AssembledTransaction<PasskeyClient>> {
     return buildDeployTransactionOp(
       {
@@ -66,10 +71,60 @@ export class SubmissionManager {
         timeoutInSeconds: this.deps.timeoutInSeconds,
       },
       keyId,
-      publicKey
+      publicKey,
+      settings
     );
   }
 
+  /** The `wallet-factory` contract id wallets deploy through, if any. */
+  get walletFactory(): string | undefined {
+    return this.deps.walletFactory;
+  }
+
+  /** Derive the address the configured factory deploys a passkey's wallet at. */
+  deriveFactoryWalletAddress(
+    keyId: Buffer,
+    publicKey: Uint8Array,
+    settings?: WalletSettings
+  ): string {
+    return deriveFactoryWalletAddressOp(
+      {
+        networkPassphrase: this.deps.networkPassphrase,
+        walletFactory: this.requireFactory(),
+      },
+      keyId,
+      publicKey,
+      settings
+    );
+  }
+
+  /** Build the factory `deploy` transaction (initial Secp256r1 signer). */
+  buildFactoryDeployTransaction(
+    keyId: Buffer,
+    publicKey: Uint8Array,
+    settings?: WalletSettings
+  ): Promise<AssembledTransaction<string>> {
+    return buildFactoryDeployTransactionOp(
+      {
+        rpcUrl: this.deps.rpcUrl,
+        networkPassphrase: this.deps.networkPassphrase,
+        walletFactory: this.requireFactory(),
+        deployerPublicKey: this.deployerPublicKey,
+        timeoutInSeconds: this.deps.timeoutInSeconds,
+      },
+      keyId,
+      publicKey,
+      settings
+    );
+  }
+
+  private requireFactory(): string {
+    if (!this.deps.walletFactory) {
+      throw new Error("no walletFactory configured");
+    }
+    return this.deps.walletFactory;
+  }
+
   /**
    * Sign a deploy transaction with the deployer keypair (the fee source) and
    * return the signed transaction XDR.
@@ -84,7 +139,7 @@ export class SubmissionManager {
    * `SorobanTransactionData` (→ txMalformed), so set the fee field surgically on
    * the envelope and rebuild.
    */
-  async signDeploy(tx: AssembledTransaction<PasskeyClient>): Promise<string> {
+  async signDeploy<T>(tx: AssembledTransaction<T>): Promise<string> {
     if (!tx.built) {
       throw new Error("deploy transaction has not been built/simulated");
     }
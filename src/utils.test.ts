@@ -1,8 +1,10 @@
 import { describe, expect, it } from "vitest";
+import { xdr } from "@stellar/stellar-sdk";
 import base64url from "./base64url.js";
 import {
   compactSignature,
   deriveContractAddress,
+  deriveFactoryContractAddress,
   extractPublicKeyFromAttestation,
   generateChallenge,
   isOnP256Curve,
@@ -46,6 +48,44 @@ describe("deriveContractAddress", () => {
   });
 });
 
+// Golden vector pinning the wallet-factory derivation (deployer = the factory,
+// salt = sha256(XDR((keyId, publicKey, settings)))), with no settings. The
+// public key is the P-256 generator point; the factory id is 32 bytes of 0x11.
+const FACTORY = "CAIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRDB3V";
+const P256_G = Buffer.from(
+  "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296" +
+    "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+  "hex"
+);
+
+describe("deriveFactoryContractAddress", () => {
+  const keyId = Buffer.from("0123456789abcdef0123456789abcdef", "hex");
+
+  it("derives the pinned factory wallet address", () => {
+    expect(deriveFactoryContractAddress(keyId, P256_G, FACTORY, TESTNET)).toBe(
+      "CAZMRMORKPTNIEHFBZV3Q5XFOCRP7CDTJOXXJUTHELECFQAUO5CTES6V"
+    );
+  });
+
+  it("commits to the settings", () => {
+    const settings = xdr.ScVal.scvVec([xdr.ScVal.scvVec([])]);
+    expect(
+      deriveFactoryContractAddress(keyId, P256_G, FACTORY, TESTNET, settings)
+    ).not.toBe(deriveFactoryContractAddress(keyId, P256_G, FACTORY, TESTNET));
+  });
+
+  it("commits to the public key as well as the keyId", () => {
+    const otherKey = Buffer.from(P256_G);
+    otherKey[64] ^= 1;
+    expect(deriveFactoryContractAddress(keyId, otherKey, FACTORY, TESTNET)).not.toBe(
+      deriveFactoryContractAddress(keyId, P256_G, FACTORY, TESTNET)
+    );
+    expect(deriveFactoryContractAddress(keyId, P256_G, FACTORY, TESTNET)).not.toBe(
+      deriveContractAddress(keyId, DEPLOYER, TESTNET)
+    );
+  });
+});
+
 /** Generate a real P-256 keypair and return its raw uncompressed public key. */
 async function generateRawP256(): Promise<Buffer> {
   const keyPair = await crypto.subtle.generateKey(
@@ -44,14 +44,59 @@ export function deriveContractAddress(
   keyId: Buffer,
   deployerPublicKey: string,
   networkPassphrase: string
+): string {
+  return contractAddress(deployerPublicKey, hash(keyId), networkPassphrase);
+}
+
+/**
+ * Derive the address `wallet-factory` deploys a passkey's wallet at.
+ *
+ * The factory is the deployer and
+ * `salt = sha256(XDR((keyId, publicKey, settings)))` (an `ScVal` vector of
+ * two byte strings and the `Option<WalletSettings>` ScVal), so the address
+ * commits to the passkey and to the settings the wallet is deployed with. It
+ * matches the factory's `get_wallet_address(key_id, public_key, settings)`
+ * and is pinned with a golden vector in `utils.test.ts`.
+ *
+ * @param keyId - The raw credential id bytes
+ * @param publicKey - The passkey's public key, as passed to the factory
+ * @param factoryContractId - The factory's `C…` contract id
+ * @param networkPassphrase - The network passphrase
+ * @param settings - The encoded `Option<WalletSettings>`; `scvVoid` (the
+ *   default) for none
+ * @returns The derived contract address (`C…`)
+ */
+export function deriveFactoryContractAddress(
+  keyId: Buffer,
+  publicKey: Uint8Array,
+  factoryContractId: string,
+  networkPassphrase: string,
+  settings: xdr.ScVal = xdr.ScVal.scvVoid()
+): string {
+  const salt = hash(
+    xdr.ScVal.scvVec([
+      xdr.ScVal.scvBytes(Buffer.from(keyId)),
+      xdr.ScVal.scvBytes(Buffer.from(publicKey)),
+      settings,
+    ]).toXDR()
+  );
+
+  return contractAddress(factoryContractId, salt, networkPassphrase);
+}
+
+/** The contract id `deployer` (a `G…` or `C…` address) deploys with `salt`. */
+function contractAddress(
+  deployer: string,
+  salt: Buffer,
+  networkPassphrase: string
 ): string {
   const preimage = xdr.HashIdPreimage.envelopeTypeContractId(
     new xdr.HashIdPreimageContractId({
       networkId: hash(Buffer.from(networkPassphrase)),
       contractIdPreimage: xdr.ContractIdPreimage.contractIdPreimageFromAddress(
         new xdr.ContractIdPreimageFromAddress({
-          address: Address.fromString(deployerPublicKey).toScAddress(),
-          salt: hash(keyId),
+          address: Address.fromString(deployer).toScAddress(),
+          salt,
         })
       ),
     })